@@ -1,24 +1,79 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::io;
-use std::path::PathBuf;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::i18n::Language;
+pub mod runner;
+
+use crate::i18n::{sys_msg, Language, Msg};
 
 /// Text reveal speed for dialog messages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextSpeed {
+    Slow,
+    #[default]
     Normal,
     Fast,
     Instant,
 }
 
-impl Default for TextSpeed {
-    fn default() -> Self {
-        Self::Normal
+/// How chat messages are laid out in the TUI.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatLayout {
+    /// Speaker name inline before each message (`Elara: ...`)
+    #[default]
+    Inline,
+    /// Speaker initials in a left gutter, message bodies in an aligned column
+    Gutter,
+}
+
+/// How separators between play sessions are shown in the chat backlog.
+/// The message log always keeps every separator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionMarkers {
+    /// One separator per session
+    #[default]
+    All,
+    /// Back-to-back separators with nothing said in between merge into one
+    Collapsed,
+    /// No separators at all
+    Hidden,
+}
+
+/// Colour theme for the chat. `Signal` is unlocked by discovering endings
+/// (see `EndingsLedger::unlocked_themes`); the others are always available.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Classic,
+    /// Brighter colours throughout, for low-contrast displays
+    HighContrast,
+    /// The terminal's own foreground colour only, for light backgrounds
+    Monochrome,
+    /// Unlocked by seeing every ending
+    Signal,
+}
+
+/// Which frontend drives the game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiMode {
+    /// Full-screen ratatui interface
+    #[default]
+    Tui,
+    /// Plain scrolling text with numbered choices
+    Classic,
+}
+
+/// Parse a frontend name from a CLI argument string
+pub fn parse_ui_mode(s: &str) -> Option<UiMode> {
+    match s.to_lowercase().as_str() {
+        "tui" => Some(UiMode::Tui),
+        "classic" | "line" => Some(UiMode::Classic),
+        _ => None,
     }
 }
 
@@ -31,6 +86,62 @@ pub struct GameSettings {
     pub waiting_times_enabled: bool,
     #[serde(default = "default_true")]
     pub automatic_dialogs_enabled: bool,
+    /// Holding Space reveals messages and skips the pauses between them.
+    #[serde(default = "default_true")]
+    pub fast_forward: bool,
+    /// Minutes without input before the pause menu opens by itself (0 = disabled).
+    #[serde(default)]
+    pub idle_timeout_minutes: u32,
+    /// Seconds on a choice without input before Elara checks in (0 = disabled).
+    #[serde(default)]
+    pub idle_nudge_seconds: u32,
+    /// Milliseconds choices stay unselectable after they appear (0 = disabled).
+    #[serde(default)]
+    pub min_read_ms: u32,
+    /// Show each line whole, with no typing indicator, typewriter, blinking
+    /// or other decorative animation.
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Annotate choices with their stat and flag consequences.
+    #[serde(default)]
+    pub consequence_hints: bool,
+    /// Show trust, health and supplies gauges in the status bar.
+    #[serde(default)]
+    pub show_stats: bool,
+    /// Note in the chat when a choice changes Elara's trust.
+    #[serde(default = "default_true")]
+    pub trust_cues: bool,
+    /// Show the author's commentary notes (New Game+ only).
+    #[serde(default)]
+    pub commentary: bool,
+    /// Maximum message log entries kept in the save (0 = keep all).
+    #[serde(default)]
+    pub log_retention: u32,
+    /// Minimum gap between auto-save writes, in milliseconds (0 = save on every message).
+    #[serde(default)]
+    pub save_interval_ms: u32,
+    /// Chat layout used by the TUI.
+    #[serde(default)]
+    pub chat_layout: ChatLayout,
+    /// Frontend used when launching without `--ui`.
+    #[serde(default)]
+    pub ui: UiMode,
+    /// Chat colour theme (only unlocked themes can be picked).
+    #[serde(default)]
+    pub theme: Theme,
+    /// Typewriter pace per language, on top of the text speed.
+    #[serde(default)]
+    pub language_pace: LanguagePace,
+    /// Vary the typewriter pace like someone typing: longer after
+    /// punctuation, a little uneven within words.
+    #[serde(default = "default_true")]
+    pub typing_rhythm: bool,
+    /// How session separators are shown in the chat.
+    #[serde(default)]
+    pub session_markers: SessionMarkers,
+    /// Show the act's title card when the story moves into a new act.
+    #[serde(default = "default_true")]
+    pub act_cards: bool,
 }
 
 impl Default for GameSettings {
@@ -39,6 +150,64 @@ impl Default for GameSettings {
             text_speed: TextSpeed::Normal,
             waiting_times_enabled: true,
             automatic_dialogs_enabled: true,
+            fast_forward: true,
+            idle_timeout_minutes: 0,
+            idle_nudge_seconds: 0,
+            min_read_ms: 0,
+            reduce_motion: false,
+            consequence_hints: false,
+            show_stats: false,
+            trust_cues: true,
+            commentary: false,
+            chat_layout: ChatLayout::Inline,
+            log_retention: 0,
+            save_interval_ms: 0,
+            ui: UiMode::Tui,
+            theme: Theme::Classic,
+            language_pace: LanguagePace::default(),
+            typing_rhythm: true,
+            session_markers: SessionMarkers::All,
+            act_cards: true,
+        }
+    }
+}
+
+/// Typewriter pace for each language, in percent of the selected text speed.
+/// French runs about a tenth longer than English in this story, so it is
+/// revealed a little faster by default to take about as long on screen.
+/// Lines with no translation are shown in English and keep the English pace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguagePace {
+    pub en: u32,
+    pub fr: u32,
+    pub de: u32,
+}
+
+impl Default for LanguagePace {
+    fn default() -> Self {
+        Self {
+            en: 100,
+            fr: 110,
+            de: 110,
+        }
+    }
+}
+
+impl LanguagePace {
+    pub fn get(&self, lang: Language) -> u32 {
+        match lang {
+            Language::En => self.en,
+            Language::Fr => self.fr,
+            Language::De => self.de,
+        }
+    }
+
+    pub fn set(&mut self, lang: Language, percent: u32) {
+        match lang {
+            Language::En => self.en = percent,
+            Language::Fr => self.fr = percent,
+            Language::De => self.de = percent,
         }
     }
 }
@@ -47,6 +216,89 @@ fn default_true() -> bool {
     true
 }
 
+/// Real-time waits a player may skip in one playthrough.
+pub const WAIT_SKIPS_PER_RUN: u8 = 3;
+
+fn default_wait_skips() -> u8 {
+    WAIT_SKIPS_PER_RUN
+}
+
+/// Message history sizes offered in the pause menu (0 = keep everything).
+pub const LOG_RETENTION_PRESETS: [u32; 4] = [0, 200, 500, 1000];
+
+/// Auto-save throttles offered in the pause menu, in milliseconds (0 = every message).
+pub const SAVE_INTERVAL_PRESETS: [u32; 4] = [0, 1000, 5000, 15000];
+
+/// Minimum read times offered in the pause menu, in milliseconds (0 = disabled).
+pub const MIN_READ_PRESETS: [u32; 4] = [0, 500, 1000, 2000];
+
+/// Idle nudge delays offered in the pause menu, in seconds (0 = disabled).
+pub const IDLE_NUDGE_PRESETS: [u32; 4] = [0, 60, 120, 300];
+
+/// Per-language text pace presets offered in the pause menu, in percent.
+pub const PACE_PRESETS: [u32; 4] = [90, 100, 110, 120];
+
+/// Idle timeout presets offered in the pause menu, in minutes (0 = disabled).
+pub const IDLE_TIMEOUT_PRESETS: [u32; 5] = [0, 5, 10, 15, 30];
+
+/// A message that was still being revealed when the game was saved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypingProgress {
+    /// Node the message belongs to
+    pub node: String,
+    /// Index of the message among the node's visible messages
+    pub message_index: usize,
+    /// Bytes of the message already revealed
+    pub revealed: usize,
+}
+
+/// A choice the player committed to, in the order they were made
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    /// Node the choice was made at
+    pub node: String,
+    /// Source index of the choice within the node
+    pub choice: usize,
+}
+
+/// Most checkpoints kept in a save; the oldest is dropped first.
+pub const MAX_CHECKPOINTS: usize = 2;
+
+/// Snapshot of the run taken on entering a checkpoint node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The checkpoint node, entered afresh on restore
+    pub node: String,
+    /// When the snapshot was taken
+    pub taken_at: DateTime<Utc>,
+    /// The state on entry, without checkpoints of its own
+    pub state: Box<GameState>,
+}
+
+/// Most choices that can be rewound; the oldest is dropped first.
+pub const MAX_REWIND_STEPS: usize = 10;
+
+/// The run as it stood just before a choice was made
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewindStep {
+    /// Length of the message log at that point
+    pub log_len: usize,
+    /// A real-time wait began after this choice, so it can't be undone
+    #[serde(default)]
+    pub behind_wait: bool,
+    /// The state then, without its message log, checkpoints or rewind steps
+    pub state: Box<GameState>,
+}
+
+/// Why `GameState::undo` couldn't step back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RewindBlocked {
+    /// No choice left to undo
+    Nothing,
+    /// Real time has passed since the last choice
+    Wait,
+}
+
 /// A single entry in the message log
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
@@ -97,9 +349,9 @@ impl Stats {
     /// Modify a stat by name with a delta (clamped to 0..=10)
     pub fn modify(&mut self, name: &str, delta: i32) {
         match name {
-            "trust" => self.trust = (self.trust + delta).max(0).min(10),
-            "health" => self.health = (self.health + delta).max(0).min(10),
-            "supplies" => self.supplies = (self.supplies + delta).max(0).min(10),
+            "trust" => self.trust = (self.trust + delta).clamp(0, 10),
+            "health" => self.health = (self.health + delta).clamp(0, 10),
+            "supplies" => self.supplies = (self.supplies + delta).clamp(0, 10),
             _ => {}
         }
     }
@@ -120,8 +372,8 @@ impl Default for Stats {
 pub struct GameState {
     /// Current story node id
     pub current_node: String,
-    /// Story flags set by choices
-    pub flags: HashMap<String, bool>,
+    /// Story flags set by choices, kept sorted so saves are stable to diff
+    pub flags: BTreeMap<String, bool>,
     /// Selected language
     pub language: Language,
     /// If set, Elara is busy until this timestamp
@@ -133,6 +385,12 @@ pub struct GameState {
     /// How many messages of the current node were already displayed
     #[serde(default)]
     pub node_message_index: usize,
+    /// Whether the current node's on_enter effects were already applied
+    #[serde(default)]
+    pub node_entered: bool,
+    /// The message that was mid-reveal at save time, resumed on load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typing: Option<TypingProgress>,
     /// Which ending was reached, if any (string key e.g. "still_here", "gone_dark")
     pub ending: Option<String>,
     /// The game day (narrative day tracker)
@@ -140,6 +398,48 @@ pub struct GameState {
     /// Runtime settings configurable from the pause menu
     #[serde(default)]
     pub settings: GameSettings,
+    /// Whether older message log entries were dropped by log retention
+    #[serde(default)]
+    pub log_trimmed: bool,
+    /// Started through New Game+: choices explored in earlier runs are annotated
+    #[serde(default)]
+    pub new_game_plus: bool,
+    /// Glossary entries the player has unlocked
+    #[serde(default)]
+    pub glossary_unlocked: BTreeSet<String>,
+    /// Time spent actively playing (in the chat, no menu open, not waiting)
+    #[serde(default)]
+    pub active_play_ms: u64,
+    /// Time the game was open on this run, across sessions, waits excluded
+    #[serde(default)]
+    pub total_playtime_secs: u64,
+    /// Total length of the real-time waits scheduled so far
+    #[serde(default)]
+    pub waited_secs: u64,
+    /// Real-time waits that can still be skipped on this run
+    #[serde(default = "default_wait_skips")]
+    pub wait_skips_remaining: u8,
+    /// Every choice made this run, oldest first
+    #[serde(default)]
+    pub decision_path: Vec<DecisionRecord>,
+    /// The story's supplies-depleted interrupt has already been shown
+    #[serde(default)]
+    pub supplies_interrupt_fired: bool,
+    /// The story's death check has already redirected this run
+    #[serde(default)]
+    pub death_triggered: bool,
+    /// Where the story resumes when an interrupt reaches `@return`
+    #[serde(default)]
+    pub interrupt_return: Option<String>,
+    /// Message log indices of the messages the player bookmarked
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub bookmarks: BTreeSet<usize>,
+    /// Snapshots taken at checkpoint nodes, oldest first (at most `MAX_CHECKPOINTS`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checkpoints: Vec<Checkpoint>,
+    /// The last few choice points, oldest first (at most `MAX_REWIND_STEPS`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rewind: Vec<RewindStep>,
 }
 
 impl GameState {
@@ -153,15 +453,31 @@ impl GameState {
     ) -> Self {
         Self {
             current_node: start_node.to_string(),
-            flags: HashMap::new(),
+            flags: BTreeMap::new(),
             language,
             waiting_until: None,
             message_log: Vec::new(),
             stats: Stats::new(trust, health, supplies),
             node_message_index: 0,
+            node_entered: false,
+            typing: None,
             ending: None,
             day: 1,
             settings: GameSettings::default(),
+            log_trimmed: false,
+            new_game_plus: false,
+            glossary_unlocked: BTreeSet::new(),
+            active_play_ms: 0,
+            total_playtime_secs: 0,
+            waited_secs: 0,
+            wait_skips_remaining: WAIT_SKIPS_PER_RUN,
+            decision_path: Vec::new(),
+            supplies_interrupt_fired: false,
+            death_triggered: false,
+            interrupt_return: None,
+            bookmarks: BTreeSet::new(),
+            checkpoints: Vec::new(),
+            rewind: Vec::new(),
         }
     }
 
@@ -187,48 +503,444 @@ impl GameState {
     pub fn remove_flag(&mut self, flag: &str) {
         self.flags.remove(flag);
     }
+
+    /// Drop all but the last `keep` messages from the log (0 = keep all).
+    /// `SESSION:` separators after the cut are kept, and the one the
+    /// retained messages started in is kept too so they keep their context.
+    /// Returns true if anything was removed.
+    pub fn trim_log(&mut self, keep: usize) -> bool {
+        let is_session =
+            |e: &LogEntry| e.sender == Sender::System && e.text.starts_with("SESSION:");
+        let messages = self.message_log.iter().filter(|e| !is_session(e)).count();
+        if keep == 0 || messages <= keep {
+            return false;
+        }
+
+        // Index of the first retained message
+        let mut to_skip = messages - keep;
+        let mut cut = 0;
+        for (i, entry) in self.message_log.iter().enumerate() {
+            if is_session(entry) {
+                continue;
+            }
+            if to_skip == 0 {
+                cut = i;
+                break;
+            }
+            to_skip -= 1;
+        }
+
+        let session = self.message_log[..cut]
+            .iter()
+            .rposition(is_session)
+            .map(|i| self.message_log[i].clone());
+        let mut retained: Vec<LogEntry> = session.into_iter().collect();
+        let shift = cut - retained.len();
+        retained.extend(self.message_log.drain(cut..));
+        self.message_log = retained;
+        // Bookmarks follow their messages; those on dropped messages go
+        self.bookmarks = self
+            .bookmarks
+            .iter()
+            .filter(|&&i| i >= cut)
+            .map(|&i| i - shift)
+            .collect();
+        // Choices whose messages were dropped can no longer be rewound to
+        self.rewind.retain(|step| step.log_len >= cut);
+        for step in &mut self.rewind {
+            step.log_len -= shift;
+        }
+        self.log_trimmed = true;
+        true
+    }
+
+    /// Bookmark the latest Elara or player message, or unbookmark it if it
+    /// already is. Returns whether it is now bookmarked, or None if there is
+    /// no message yet.
+    pub fn toggle_bookmark_latest(&mut self) -> Option<bool> {
+        let latest = self
+            .message_log
+            .iter()
+            .rposition(|e| e.sender != Sender::System)?;
+        if self.bookmarks.remove(&latest) {
+            Some(false)
+        } else {
+            self.bookmarks.insert(latest);
+            Some(true)
+        }
+    }
+
+    /// Snapshot the current state as a checkpoint. Re-entering a checkpoint
+    /// node replaces its earlier snapshot; beyond `MAX_CHECKPOINTS` the
+    /// oldest is dropped.
+    pub fn record_checkpoint(&mut self) {
+        let mut snapshot = self.clone();
+        snapshot.checkpoints.clear();
+        let node = self.current_node.clone();
+        self.checkpoints.retain(|c| c.node != node);
+        self.checkpoints.push(Checkpoint {
+            node,
+            taken_at: Utc::now(),
+            state: Box::new(snapshot),
+        });
+        let excess = self.checkpoints.len().saturating_sub(MAX_CHECKPOINTS);
+        self.checkpoints.drain(..excess);
+    }
+
+    /// Remember the run as it stands before a choice, so `undo` can come
+    /// back to it. Beyond `MAX_REWIND_STEPS` the oldest is dropped.
+    pub fn record_rewind_step(&mut self) {
+        let log = std::mem::take(&mut self.message_log);
+        let checkpoints = std::mem::take(&mut self.checkpoints);
+        let rewind = std::mem::take(&mut self.rewind);
+        let mut snapshot = self.clone();
+        snapshot.typing = None;
+        self.message_log = log;
+        self.checkpoints = checkpoints;
+        self.rewind = rewind;
+
+        self.rewind.push(RewindStep {
+            log_len: self.message_log.len(),
+            behind_wait: false,
+            state: Box::new(snapshot),
+        });
+        let excess = self.rewind.len().saturating_sub(MAX_REWIND_STEPS);
+        self.rewind.drain(..excess);
+    }
+
+    /// A real-time wait has started: the choices made so far stay made.
+    pub fn block_rewind(&mut self) {
+        if let Some(step) = self.rewind.last_mut() {
+            step.behind_wait = true;
+        }
+    }
+
+    /// How many choices `undo` can currently step back through.
+    pub fn rewind_available(&self) -> usize {
+        self.rewind
+            .iter()
+            .rev()
+            .take_while(|step| !step.behind_wait)
+            .count()
+    }
+
+    /// Step back to just before the last choice, cutting the message log
+    /// back to that point. Language, settings, play time and checkpoints
+    /// are kept.
+    pub fn undo(&mut self) -> Result<(), RewindBlocked> {
+        let step = self.rewind.last().ok_or(RewindBlocked::Nothing)?;
+        if step.behind_wait {
+            return Err(RewindBlocked::Wait);
+        }
+        let step = self.rewind.pop().expect("checked above");
+        let mut restored = *step.state;
+        restored.message_log = std::mem::take(&mut self.message_log);
+        restored.message_log.truncate(step.log_len);
+        restored.bookmarks = self
+            .bookmarks
+            .iter()
+            .copied()
+            .filter(|&i| i < step.log_len)
+            .collect();
+        restored.log_trimmed = self.log_trimmed;
+        restored.language = self.language;
+        restored.settings = self.settings.clone();
+        restored.active_play_ms = self.active_play_ms;
+        restored.total_playtime_secs = self.total_playtime_secs;
+        restored.waited_secs = self.waited_secs;
+        restored.wait_skips_remaining = self.wait_skips_remaining;
+        restored.checkpoints = std::mem::take(&mut self.checkpoints);
+        restored.rewind = std::mem::take(&mut self.rewind);
+        *self = restored;
+        Ok(())
+    }
+
+    /// Roll the run back to the latest checkpoint. Language, settings, play
+    /// time and the checkpoints themselves are kept. Returns false if there
+    /// is no checkpoint.
+    pub fn restore_checkpoint(&mut self) -> bool {
+        let Some(checkpoint) = self.checkpoints.last() else {
+            return false;
+        };
+        let mut restored = (*checkpoint.state).clone();
+        restored.language = self.language;
+        restored.settings = self.settings.clone();
+        restored.active_play_ms = self.active_play_ms;
+        restored.total_playtime_secs = self.total_playtime_secs;
+        restored.waited_secs = self.waited_secs;
+        restored.wait_skips_remaining = self.wait_skips_remaining;
+        restored.checkpoints = std::mem::take(&mut self.checkpoints);
+        *self = restored;
+        true
+    }
+}
+
+/// Fluent builder for game states, so test fixtures only spell out what matters.
+///
+/// Starts from a fresh English game at node `"test"` with default stats.
+#[derive(Debug, Clone)]
+pub struct GameStateBuilder {
+    state: GameState,
+}
+
+impl Default for GameStateBuilder {
+    fn default() -> Self {
+        let stats = Stats::default();
+        Self {
+            state: GameState::new(
+                Language::En,
+                "test",
+                stats.trust,
+                stats.health,
+                stats.supplies,
+            ),
+        }
+    }
+}
+
+impl GameStateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.state.language = language;
+        self
+    }
+
+    pub fn at_node(mut self, node: &str) -> Self {
+        self.state.current_node = node.to_string();
+        self
+    }
+
+    pub fn trust(mut self, trust: i32) -> Self {
+        self.state.stats.trust = trust;
+        self
+    }
+
+    pub fn health(mut self, health: i32) -> Self {
+        self.state.stats.health = health;
+        self
+    }
+
+    pub fn supplies(mut self, supplies: i32) -> Self {
+        self.state.stats.supplies = supplies;
+        self
+    }
+
+    pub fn flag(mut self, flag: &str) -> Self {
+        self.state.set_flag(flag);
+        self
+    }
+
+    pub fn day(mut self, day: u32) -> Self {
+        self.state.day = day;
+        self
+    }
+
+    pub fn build(self) -> GameState {
+        self.state
+    }
 }
 
 // ── Save / Load ──────────────────────────────────────────────
 
-/// Get the path to the save directory (~/.eshara/)
+/// Environment variable naming the data directory, overriding the default.
+pub const HOME_ENV: &str = "ESHARA_HOME";
+
+/// Get the path to the data directory holding saves, endings and runs.
+///
+/// `ESHARA_HOME` wins when set. Otherwise it is `eshara` in the platform
+/// config directory: `$XDG_CONFIG_HOME` (or `~/.config`) on Linux,
+/// `AppData\Roaming` on Windows, `Library/Application Support` on macOS.
+/// A `~/.eshara` left by older versions is kept until that one exists.
+/// With neither a config nor a home directory it falls back to the system
+/// temp directory; the working directory is only searched for the story file.
 pub fn save_dir() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".eshara")
+    data_dir_from(
+        std::env::var_os(HOME_ENV).map(PathBuf::from),
+        dirs::config_dir(),
+        dirs::home_dir(),
+    )
+}
+
+/// Resolve the data directory from the override, config and home directories.
+fn data_dir_from(
+    override_dir: Option<PathBuf>,
+    config: Option<PathBuf>,
+    home: Option<PathBuf>,
+) -> PathBuf {
+    if let Some(dir) = override_dir.filter(|d| !d.as_os_str().is_empty()) {
+        return dir;
+    }
+    let legacy = home.map(|h| h.join(".eshara"));
+    match (config.map(|c| c.join("eshara")), legacy) {
+        (Some(dir), Some(legacy)) if !dir.exists() && legacy.exists() => legacy,
+        (Some(dir), _) => dir,
+        (None, Some(legacy)) => legacy,
+        (None, None) => std::env::temp_dir().join("eshara"),
+    }
 }
 
-/// Get the path to the save file (~/.eshara/save.json)
+/// Get the story file that overrides the embedded copy: `story.json` in the
+/// data directory if there is one, else `data/story.json` under the
+/// working directory.
+pub fn story_path() -> PathBuf {
+    story_path_in(&save_dir())
+}
+
+fn story_path_in(dir: &Path) -> PathBuf {
+    let in_data_dir = dir.join("story.json");
+    if in_data_dir.exists() {
+        in_data_dir
+    } else {
+        PathBuf::from(crate::story::EXTERNAL_STORY_PATH)
+    }
+}
+
+/// Get the path to the save file (<data dir>/save.json)
 pub fn save_path() -> PathBuf {
-    save_dir().join("save.json")
+    save_slot_path(0)
+}
+
+/// Get the path to the quick-save slot (<data dir>/quicksave.json)
+pub fn quicksave_path() -> PathBuf {
+    quicksave_slot_path(0)
+}
+
+/// Number of parallel save slots. Slot 0 is the original `save.json`.
+pub const SAVE_SLOTS: u8 = 4;
+
+/// File name of a save slot: `save.json` for slot 0, `save_<n>.json` otherwise
+fn slot_file(stem: &str, slot: u8) -> String {
+    match slot {
+        0 => format!("{}.json", stem),
+        n => format!("{}_{}.json", stem, n),
+    }
+}
+
+/// Get the path to a save slot (<data dir>/save_<n>.json)
+pub fn save_slot_path(slot: u8) -> PathBuf {
+    save_dir().join(slot_file("save", slot))
+}
+
+/// Get the path to a save slot's quick-save, so F9 never crosses playthroughs
+pub fn quicksave_slot_path(slot: u8) -> PathBuf {
+    save_dir().join(slot_file("quicksave", slot))
 }
 
+/// Header written before the gzip payload of a compressed save.
+/// Saves without it are read as plain JSON (older versions).
+const SAVE_MAGIC: &[u8] = b"ESHARA-GZ1\n";
+
 /// Save the game state to disk
 pub fn save_game(state: &GameState) -> io::Result<()> {
+    save_game_slot(state, 0)
+}
+
+/// Save the game state to one of the save slots
+pub fn save_game_slot(state: &GameState, slot: u8) -> io::Result<()> {
     let dir = save_dir();
     if !dir.exists() {
         fs::create_dir_all(&dir)?;
     }
-    let json =
-        serde_json::to_string_pretty(state).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    fs::write(save_path(), json)
+    save_game_to(state, &save_slot_path(slot))
+}
+
+/// Write a compressed save to an arbitrary path
+pub fn save_game_to(state: &GameState, path: &Path) -> io::Result<()> {
+    fs::write(path, encode_save(state)?)
+}
+
+/// Serialize a game state as magic header + gzip-compressed JSON
+fn encode_save(state: &GameState) -> io::Result<Vec<u8>> {
+    let json = serde_json::to_vec(state).map_err(io::Error::other)?;
+    let mut encoder =
+        flate2::write::GzEncoder::new(SAVE_MAGIC.to_vec(), flate2::Compression::default());
+    encoder.write_all(&json)?;
+    encoder.finish()
+}
+
+/// Parse a save, decompressing it if it carries the magic header
+fn decode_save(bytes: &[u8]) -> io::Result<GameState> {
+    let json = match bytes.strip_prefix(SAVE_MAGIC) {
+        Some(compressed) => {
+            let mut json = Vec::new();
+            flate2::read::GzDecoder::new(compressed).read_to_end(&mut json)?;
+            json
+        }
+        None => bytes.to_vec(),
+    };
+    serde_json::from_slice(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Coalesces rapid auto-save requests so the save file is written at most
+/// once per interval. A request inside the interval is only remembered;
+/// the caller flushes it once `due` says so, or forces a write at quit,
+/// endings and real-time waits.
+#[derive(Debug, Clone, Default)]
+pub struct SaveScheduler {
+    last_write: Option<Instant>,
+    pending: bool,
+}
+
+impl SaveScheduler {
+    /// Note a save request; returns whether it should be written now.
+    pub fn request(&mut self, now: Instant, interval: Duration) -> bool {
+        let throttled = self
+            .last_write
+            .is_some_and(|last| now.saturating_duration_since(last) < interval);
+        self.pending = throttled;
+        !throttled
+    }
+
+    /// Whether a deferred request has waited out the interval.
+    pub fn due(&self, now: Instant, interval: Duration) -> bool {
+        self.pending
+            && self
+                .last_write
+                .is_none_or(|last| now.saturating_duration_since(last) >= interval)
+    }
+
+    /// Whether a request is still waiting to be written.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Record that the save file was just written.
+    pub fn written(&mut self, now: Instant) {
+        self.last_write = Some(now);
+        self.pending = false;
+    }
 }
 
 /// Load the game state from disk, if a save file exists
 pub fn load_game() -> io::Result<Option<GameState>> {
-    let path = save_path();
+    load_game_slot(0)
+}
+
+/// Load the game state from a save slot, if it holds a save
+pub fn load_game_slot(slot: u8) -> io::Result<Option<GameState>> {
+    let path = save_slot_path(slot);
     if !path.exists() {
         return Ok(None);
     }
-    let json = fs::read_to_string(path)?;
-    let state: GameState =
-        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    Ok(Some(state))
+    load_game_from(&path).map(Some)
+}
+
+/// Load a game state from an arbitrary save file (compressed or plain JSON)
+pub fn load_game_from(path: &Path) -> io::Result<GameState> {
+    decode_save(&fs::read(path)?)
 }
 
 /// Delete the save file
 pub fn delete_save() -> io::Result<()> {
-    let path = save_path();
+    delete_save_slot(0)
+}
+
+/// Delete a save slot's save file
+pub fn delete_save_slot(slot: u8) -> io::Result<()> {
+    let path = save_slot_path(slot);
     if path.exists() {
         fs::remove_file(path)?;
     }
@@ -237,45 +949,663 @@ pub fn delete_save() -> io::Result<()> {
 
 /// Check if a save file exists
 pub fn save_exists() -> bool {
-    save_path().exists()
+    save_slot_exists(0)
+}
+
+/// Check if a save slot holds a save
+pub fn save_slot_exists(slot: u8) -> bool {
+    save_slot_path(slot).exists()
+}
+
+/// What a save slot holds, for picking one at launch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveSlotInfo {
+    pub slot: u8,
+    pub current_node: String,
+    pub day: u32,
+    pub language: Language,
+    /// When the slot was last written
+    pub modified: DateTime<Utc>,
+}
+
+/// Every save slot that holds a readable save, in slot order
+pub fn list_saves() -> Vec<SaveSlotInfo> {
+    list_saves_in(&save_dir())
+}
+
+/// Like `list_saves`, reading slots from `dir`. Unreadable saves are left out.
+pub fn list_saves_in(dir: &Path) -> Vec<SaveSlotInfo> {
+    (0..SAVE_SLOTS)
+        .filter_map(|slot| {
+            let path = dir.join(slot_file("save", slot));
+            let modified = fs::metadata(&path).ok()?.modified().ok()?;
+            let state = load_game_from(&path).ok()?;
+            Some(SaveSlotInfo {
+                slot,
+                current_node: state.current_node,
+                day: state.day,
+                language: state.language,
+                modified: modified.into(),
+            })
+        })
+        .collect()
+}
+
+// ── Endings ledger ───────────────────────────────────────────
+
+/// When an ending was first reached.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndingRecord {
+    pub first_seen: DateTime<Utc>,
+    /// Story day the run was on
+    pub day: u32,
+}
+
+/// Endings discovered across all runs. Kept beside the save file so it
+/// survives the save being deleted at the end of a run, and `--reset`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EndingsLedger {
+    #[serde(default)]
+    pub endings_seen: BTreeSet<String>,
+    /// First time each ending was reached. Ledgers written before this
+    /// was kept list endings in `endings_seen` only.
+    #[serde(default)]
+    pub ending_records: BTreeMap<String, EndingRecord>,
+    /// Choices taken in any run, as `node_id:index`
+    #[serde(default)]
+    pub choices_taken: BTreeSet<String>,
+    /// Flags unlocked by endings, seeded into every new game
+    #[serde(default)]
+    pub unlocked_flags: BTreeSet<String>,
+}
+
+impl EndingsLedger {
+    /// Get the path to the ledger file (<data dir>/endings.json)
+    pub fn path() -> PathBuf {
+        save_dir().join("endings.json")
+    }
+
+    /// Load the ledger, starting empty if it is missing or unreadable
+    pub fn load() -> Self {
+        Self::load_from(&Self::path())
+    }
+
+    pub fn load_from(path: &Path) -> Self {
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let dir = save_dir();
+        if !dir.exists() {
+            fs::create_dir_all(&dir)?;
+        }
+        self.save_to(&Self::path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Record an ending reached on `day`; returns true the first time it is seen
+    pub fn record(&mut self, ending: &str, day: u32) -> bool {
+        self.ending_records
+            .entry(ending.to_string())
+            .or_insert_with(|| EndingRecord {
+                first_seen: Utc::now(),
+                day,
+            });
+        self.endings_seen.insert(ending.to_string())
+    }
+
+    pub fn has_seen(&self, ending: &str) -> bool {
+        self.endings_seen.contains(ending)
+    }
+
+    /// Keep an ending's unlocked flags for later runs; returns true if any is new
+    pub fn unlock_flags(&mut self, flags: &[String]) -> bool {
+        let before = self.unlocked_flags.len();
+        self.unlocked_flags.extend(flags.iter().cloned());
+        self.unlocked_flags.len() > before
+    }
+
+    /// Record a choice; returns true the first time it is taken in any run
+    pub fn record_choice(&mut self, node: &str, index: usize) -> bool {
+        self.choices_taken.insert(format!("{}:{}", node, index))
+    }
+
+    pub fn has_taken(&self, node: &str, index: usize) -> bool {
+        self.choices_taken.contains(&format!("{}:{}", node, index))
+    }
+
+    /// How many of the given endings have been seen
+    pub fn discovered<'a>(&self, endings: impl IntoIterator<Item = &'a String>) -> usize {
+        endings.into_iter().filter(|key| self.has_seen(key)).count()
+    }
+
+    /// Themes available given how many endings the story has
+    pub fn unlocked_themes(&self, total_endings: usize) -> Vec<Theme> {
+        let mut themes = vec![Theme::Classic, Theme::HighContrast, Theme::Monochrome];
+        if total_endings > 0 && self.endings_seen.len() >= total_endings {
+            themes.push(Theme::Signal);
+        }
+        themes
+    }
+}
+
+// ── Saved runs ───────────────────────────────────────────────
+
+/// Most named runs kept at once; saving another means deleting one first.
+pub const MAX_SAVED_RUNS: usize = 8;
+
+/// Longest label accepted for a saved run, in characters.
+pub const SAVED_RUN_LABEL_MAX: usize = 32;
+
+/// The label a typed name is stored under: trimmed and cut to
+/// [`SAVED_RUN_LABEL_MAX`] characters.
+pub fn saved_run_label(name: &str) -> String {
+    name.trim().chars().take(SAVED_RUN_LABEL_MAX).collect()
+}
+
+/// A whole run stored under a name the player picked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedRun {
+    pub label: String,
+    pub saved_at: DateTime<Utc>,
+    pub state: GameState,
+}
+
+/// Named snapshots of whole runs, kept apart from the save and the
+/// quick-save slot so exploring a branch never costs the main playthrough.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedRuns {
+    #[serde(default)]
+    pub runs: Vec<SavedRun>,
+}
+
+impl SavedRuns {
+    /// Get the path to the saved runs file (<data dir>/runs.json)
+    pub fn path() -> PathBuf {
+        save_dir().join("runs.json")
+    }
+
+    /// Load the saved runs, starting empty if the file doesn't exist yet.
+    /// A file that can't be read or parsed is an error, so callers never
+    /// write an empty list over runs the player still has.
+    pub fn load_from(path: &Path) -> io::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        serde_json::from_slice(&fs::read(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_vec(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Store `state` under `label`, replacing a run saved under the same
+    /// label. Returns false when the label is blank or every slot is taken.
+    pub fn add(&mut self, label: &str, state: &GameState) -> bool {
+        let label = saved_run_label(label);
+        if label.is_empty() {
+            return false;
+        }
+        let run = SavedRun {
+            label,
+            saved_at: Utc::now(),
+            state: state.clone(),
+        };
+        if let Some(existing) = self.runs.iter_mut().find(|r| r.label == run.label) {
+            *existing = run;
+            return true;
+        }
+        if self.runs.len() >= MAX_SAVED_RUNS {
+            return false;
+        }
+        self.runs.push(run);
+        true
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<SavedRun> {
+        (index < self.runs.len()).then(|| self.runs.remove(index))
+    }
+
+    /// The run at `index`, ready to play on from. Language and settings are
+    /// preferences, so the current ones are kept.
+    pub fn restore(&self, index: usize, current: &GameState) -> Option<GameState> {
+        let mut state = self.runs.get(index)?.state.clone();
+        state.language = current.language;
+        state.settings = current.settings.clone();
+        Some(state)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.runs.len() >= MAX_SAVED_RUNS
+    }
+}
+
+// ── Save comparison ──────────────────────────────────────────
+
+/// Describe how two game states differ, one human-readable line per difference.
+/// Returns an empty list when the states are equivalent.
+pub fn diff_states(a: &GameState, b: &GameState) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if a.current_node != b.current_node {
+        lines.push(format!(
+            "current node: {} -> {}",
+            a.current_node, b.current_node
+        ));
+    }
+    if a.day != b.day {
+        lines.push(format!("day: {} -> {}", a.day, b.day));
+    }
+    for stat in ["trust", "health", "supplies"] {
+        let (before, after) = (a.stats.get(stat), b.stats.get(stat));
+        if before != after {
+            lines.push(format!(
+                "{}: {} -> {}",
+                stat,
+                before.unwrap_or_default(),
+                after.unwrap_or_default()
+            ));
+        }
+    }
+
+    let flags_of = |state: &GameState| -> Vec<String> {
+        state
+            .flags
+            .iter()
+            .filter(|(_, set)| **set)
+            .map(|(flag, _)| flag.clone())
+            .collect()
+    };
+    let (flags_a, flags_b) = (flags_of(a), flags_of(b));
+    let added: Vec<&str> = flags_b
+        .iter()
+        .filter(|f| !flags_a.contains(f))
+        .map(String::as_str)
+        .collect();
+    let removed: Vec<&str> = flags_a
+        .iter()
+        .filter(|f| !flags_b.contains(f))
+        .map(String::as_str)
+        .collect();
+    if !added.is_empty() {
+        lines.push(format!("flags added: {}", added.join(", ")));
+    }
+    if !removed.is_empty() {
+        lines.push(format!("flags removed: {}", removed.join(", ")));
+    }
+
+    if a.ending != b.ending {
+        lines.push(format!(
+            "ending: {} -> {}",
+            a.ending.as_deref().unwrap_or("none"),
+            b.ending.as_deref().unwrap_or("none")
+        ));
+    }
+    if a.language != b.language {
+        lines.push(format!("language: {:?} -> {:?}", a.language, b.language));
+    }
+    if a.message_log.len() != b.message_log.len() {
+        lines.push(format!(
+            "messages logged: {} -> {}",
+            a.message_log.len(),
+            b.message_log.len()
+        ));
+    }
+
+    lines
+}
+
+// ── Transcript export ────────────────────────────────────────
+
+/// Document format for `export_transcript`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    /// Every log entry with sender and timestamp, `SESSION:` markers included
+    Json,
+    /// Readable turns, with session markers as headings
+    Markdown,
+}
+
+impl TranscriptFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            TranscriptFormat::Json => "json",
+            TranscriptFormat::Markdown => "md",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TranscriptDocument<'a> {
+    current_node: &'a str,
+    day: u32,
+    language: Language,
+    /// The oldest messages were dropped from the save before export
+    log_trimmed: bool,
+    entries: &'a [LogEntry],
+}
+
+/// Render the run's message log as a standalone document. Text is written
+/// as-is, so accented letters are not escaped in either format.
+pub fn export_transcript(state: &GameState, format: TranscriptFormat) -> String {
+    match format {
+        TranscriptFormat::Json => serde_json::to_string_pretty(&TranscriptDocument {
+            current_node: &state.current_node,
+            day: state.day,
+            language: state.language,
+            log_trimmed: state.log_trimmed,
+            entries: &state.message_log,
+        })
+        .unwrap_or_default(),
+        TranscriptFormat::Markdown => transcript_markdown(state),
+    }
+}
+
+fn transcript_markdown(state: &GameState) -> String {
+    let lang = state.language;
+    let mut out = format!("# {}\n", sys_msg(Msg::TranscriptTitle, lang));
+    if state.log_trimmed {
+        out.push_str(&format!("\n_{}_\n", sys_msg(Msg::TranscriptTrimmed, lang)));
+    }
+    for entry in &state.message_log {
+        let text = entry.text.as_str();
+        match entry.sender {
+            Sender::System => match text.strip_prefix("SESSION:") {
+                Some(label) => out.push_str(&format!("\n## {}\n", label)),
+                None => out.push_str(&format!("\n*{}*\n", text)),
+            },
+            Sender::Elara => out.push_str(&format!("\n**Elara:** {}\n", text)),
+            Sender::Player => out.push_str(&format!(
+                "\n> **{}:** {}\n",
+                sys_msg(Msg::TranscriptPlayer, lang),
+                text
+            )),
+        }
+    }
+    out
+}
+
+/// Write a transcript into `dir` under a timestamped name and return its path.
+/// A second export within the same second gets a `-2`, `-3`... suffix instead
+/// of overwriting the first.
+pub fn write_transcript(
+    state: &GameState,
+    format: TranscriptFormat,
+    dir: &Path,
+) -> io::Result<PathBuf> {
+    let stem = format!("eshara-transcript-{}", Utc::now().format("%Y%m%d-%H%M%S"));
+    let contents = export_transcript(state, format);
+    let mut n = 1;
+    loop {
+        let name = match n {
+            1 => format!("{}.{}", stem, format.extension()),
+            n => format!("{}-{}.{}", stem, n, format.extension()),
+        };
+        let path = dir.join(name);
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => n += 1,
+            Err(e) => return Err(e),
+        }
+    }
 }
 
 // ── CLI argument parsing ─────────────────────────────────────
 
 /// Parsed command-line arguments
 pub struct CliArgs {
+    /// If true, print usage and exit
+    pub help: bool,
+    /// If true, print the version and story content hash and exit
+    pub version: bool,
     /// If true, delete save and exit
     pub reset: bool,
     /// Optional language override
     pub language: Option<Language>,
+    /// If set, print the differences between two save files and exit
+    pub diff: Option<(PathBuf, PathBuf)>,
+    /// If set, write a trace of engine events to this file
+    pub log: Option<PathBuf>,
+    /// If true, play through the line-based frontend reading choices from stdin
+    pub stdin_choices: bool,
+    /// If non-empty, list story strings missing a translation in these languages and exit
+    pub check_lang: Vec<Language>,
+    /// If true, report story errors and flag warnings and exit
+    pub lint: bool,
+    /// If set, time story parsing and validation over this many iterations and exit
+    pub bench_load: Option<usize>,
+    /// Optional frontend override (remembered in the save)
+    pub ui: Option<UiMode>,
+    /// If non-empty, print all story and system text in these languages and exit
+    pub dump_text: Vec<Language>,
+    /// If true, F12 toggles the timing debug panel
+    pub dev: bool,
+    /// If set, replay this shared route code without saving and exit
+    pub replay_route: Option<String>,
+    /// If true, use the embedded story without asking when the story file is broken
+    pub fallback_embedded: bool,
+    /// If set, play (or with --reset, delete) this save slot instead of asking
+    pub slot: Option<u8>,
+    /// If true, turn on reduce motion (remembered in the save)
+    pub no_animation: bool,
+    /// If true, shorten real-time waits to the story's debug delay
+    pub debug: bool,
+    /// If set, validate this story file and exit (1 if it has errors)
+    pub validate: Option<PathBuf>,
+    /// If true, print the story graph as Graphviz DOT and exit
+    pub graph: bool,
 }
 
+/// Iterations for `--bench-load` when no count is given
+const DEFAULT_BENCH_ITERATIONS: usize = 20;
+
+/// Usage text printed by `--help` and after a command-line error
+pub const USAGE: &str = "\
+Usage: eshara [options]
+
+Options:
+  --lang <en|fr|de>         Override the starting language
+  --ui <tui|classic>        Pick the frontend (remembered in the save)
+  --no-animation            Show every line at once, without typing animation (remembered)
+  --slot <0-3>              Play this save slot (starting it if empty) instead of asking
+  --stdin-choices           Play the classic frontend for this session, reading choices from stdin
+  --reset                   Delete the save file and exit
+  --diff <saveA> <saveB>    Print how two save files differ and exit
+  --lint                    Validate the story and report flag warnings, then exit
+  --validate <story.json>   Validate a story file and summarize it, then exit (1 on errors)
+  --graph                   Print the story graph as Graphviz DOT and exit
+  --bench-load [n]          Time loading the story over n runs (default 20) and exit
+  --check-lang [lang]       List story strings missing a translation (default: fr and de) and exit
+  --dump-text <lang> [lang] Print all story and system text in one or two languages and exit
+  --replay-route <code>     Replay a route code as plain text without saving, then exit
+  --fallback-embedded       Play the built-in story if the story file fails to load
+  --dev                     Enable the F12 timing panel
+  --debug                   Shorten every real-time wait to the story's debug delay
+  --log <file>              Write a trace of engine events to a file
+  -V, --version             Print the version and story content hash, then exit
+  -h, --help                Show this help and exit
+";
+
 /// Parse command-line arguments (minimal, no dependency)
-pub fn parse_cli_args() -> CliArgs {
+pub fn parse_cli_args() -> Result<CliArgs, String> {
     let args: Vec<String> = std::env::args().collect();
     parse_cli_args_from(&args)
 }
 
-fn parse_cli_args_from(args: &[String]) -> CliArgs {
+/// The value following the flag at `args[*i]`, advancing past it.
+fn flag_value<'a>(args: &'a [String], i: &mut usize, what: &str) -> Result<&'a str, String> {
+    match args.get(*i + 1) {
+        Some(value) if !value.starts_with("--") => {
+            *i += 1;
+            Ok(value)
+        }
+        _ => Err(format!("{} needs {}", args[*i], what)),
+    }
+}
+
+fn parse_cli_args_from(args: &[String]) -> Result<CliArgs, String> {
+    let mut help = false;
     let mut reset = false;
     let mut language = None;
+    let mut diff = None;
+    let mut log = None;
+    let mut stdin_choices = false;
+    let mut check_lang = Vec::new();
+    let mut lint = false;
+    let mut bench_load = None;
+    let mut ui = None;
+    let mut dump_text = Vec::new();
+    let mut dev = false;
+    let mut replay_route = None;
+    let mut fallback_embedded = false;
+    let mut version = false;
+    let mut slot = None;
+    let mut no_animation = false;
+    let mut debug = false;
+    let mut validate = None;
+    let mut graph = false;
 
     let mut i = 1;
     while i < args.len() {
         match args[i].as_str() {
+            "-h" | "--help" => help = true,
+            "-V" | "--version" => version = true,
             "--reset" => reset = true,
+            "--stdin-choices" => stdin_choices = true,
+            "--lint" => lint = true,
+            "--graph" => graph = true,
+            "--dev" => dev = true,
+            "--debug" => debug = true,
+            "--no-animation" => no_animation = true,
+            "--fallback-embedded" => fallback_embedded = true,
+            "--check-lang" => {
+                // Optional language, e.g. `--check-lang de`; every translation otherwise
+                match args.get(i + 1).and_then(|l| crate::i18n::parse_language(l)) {
+                    Some(Language::En) => {
+                        return Err(
+                            "--check-lang needs a translated language (fr or de)".to_string()
+                        )
+                    }
+                    Some(lang) => {
+                        check_lang = vec![lang];
+                        i += 1;
+                    }
+                    None => check_lang = vec![Language::Fr, Language::De],
+                }
+            }
+            "--bench-load" => {
+                // Optional iteration count, e.g. `--bench-load 100`
+                let count = args.get(i + 1).and_then(|n| n.parse::<usize>().ok());
+                if count.is_some() {
+                    i += 1;
+                }
+                bench_load = Some(count.unwrap_or(DEFAULT_BENCH_ITERATIONS).max(1));
+            }
             "--lang" => {
-                if i + 1 < args.len() {
-                    language = crate::i18n::parse_language(&args[i + 1]);
+                let value = flag_value(args, &mut i, "a language (en, fr or de)")?;
+                language = Some(
+                    crate::i18n::parse_language(value)
+                        .ok_or_else(|| format!("unknown language '{}' for --lang", value))?,
+                );
+            }
+            "--diff" => {
+                let a = flag_value(args, &mut i, "two save files")?;
+                let b = flag_value(args, &mut i, "two save files")
+                    .map_err(|_| "--diff needs two save files".to_string())?;
+                diff = Some((PathBuf::from(a), PathBuf::from(b)));
+            }
+            "--ui" => {
+                let value = flag_value(args, &mut i, "a frontend (tui or classic)")?;
+                ui = Some(
+                    parse_ui_mode(value)
+                        .ok_or_else(|| format!("unknown frontend '{}' for --ui", value))?,
+                );
+            }
+            "--slot" => {
+                let value = flag_value(args, &mut i, "a save slot")?;
+                slot = Some(
+                    value
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|&n| n < SAVE_SLOTS)
+                        .ok_or_else(|| {
+                            format!(
+                                "'{}' is not a save slot (0-{}) for --slot",
+                                value,
+                                SAVE_SLOTS - 1
+                            )
+                        })?,
+                );
+            }
+            "--replay-route" => {
+                replay_route = Some(flag_value(args, &mut i, "a route code")?.to_string());
+            }
+            "--log" => {
+                log = Some(PathBuf::from(flag_value(args, &mut i, "a file path")?));
+            }
+            "--validate" => {
+                validate = Some(PathBuf::from(flag_value(args, &mut i, "a story file")?));
+            }
+            "--dump-text" => {
+                // One or two languages, e.g. `--dump-text en fr`
+                while dump_text.len() < 2 && i + 1 < args.len() {
+                    match crate::i18n::parse_language(&args[i + 1]) {
+                        Some(lang) => dump_text.push(lang),
+                        None => break,
+                    }
                     i += 1;
                 }
+                if dump_text.is_empty() {
+                    return Err("--dump-text needs a language (en, fr or de)".to_string());
+                }
             }
-            _ => {}
+            other if other.starts_with('-') => return Err(format!("unknown flag '{}'", other)),
+            other => return Err(format!("unexpected argument '{}'", other)),
         }
         i += 1;
     }
 
-    CliArgs { reset, language }
+    Ok(CliArgs {
+        help,
+        version,
+        reset,
+        language,
+        diff,
+        log,
+        stdin_choices,
+        check_lang,
+        lint,
+        bench_load,
+        ui,
+        dump_text,
+        dev,
+        replay_route,
+        fallback_embedded,
+        slot,
+        no_animation,
+        debug,
+        validate,
+        graph,
+    })
 }
 
 #[cfg(test)]
@@ -297,6 +1627,170 @@ mod tests {
         assert_eq!(state.day, 1);
     }
 
+    #[test]
+    fn test_game_state_builder() {
+        let state = GameStateBuilder::new()
+            .language(Language::Fr)
+            .at_node("a2_camp")
+            .trust(7)
+            .health(4)
+            .supplies(1)
+            .flag("has_medicine")
+            .day(3)
+            .build();
+        assert_eq!(state.language, Language::Fr);
+        assert_eq!(state.current_node, "a2_camp");
+        assert_eq!(state.stats.trust, 7);
+        assert_eq!(state.stats.health, 4);
+        assert_eq!(state.stats.supplies, 1);
+        assert!(state.has_flag("has_medicine"));
+        assert_eq!(state.day, 3);
+        assert_eq!(state.node_message_index, 0);
+    }
+
+    fn log_entry(sender: Sender, text: &str) -> LogEntry {
+        LogEntry {
+            sender,
+            text: text.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_trim_log_keeps_recent_messages_and_session_context() {
+        let mut state = GameStateBuilder::new().build();
+        state.message_log = vec![
+            log_entry(Sender::System, "SESSION:day one"),
+            log_entry(Sender::Elara, "a"),
+            log_entry(Sender::Player, "b"),
+            log_entry(Sender::Elara, "c"),
+            log_entry(Sender::System, "SESSION:day two"),
+            log_entry(Sender::Elara, "d"),
+        ];
+
+        assert!(!state.trim_log(0));
+        assert!(!state.trim_log(4));
+        assert!(!state.log_trimmed);
+
+        assert!(state.trim_log(2));
+        let texts: Vec<&str> = state.message_log.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["SESSION:day one", "c", "SESSION:day two", "d"]);
+        assert!(state.log_trimmed);
+
+        assert!(state.trim_log(1));
+        let texts: Vec<&str> = state.message_log.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["SESSION:day two", "d"]);
+    }
+
+    #[test]
+    fn test_bookmarks_toggle_follow_trim_and_persist() {
+        let mut state = GameStateBuilder::new().build();
+        assert_eq!(state.toggle_bookmark_latest(), None);
+
+        state.message_log = vec![
+            log_entry(Sender::System, "SESSION:day one"),
+            log_entry(Sender::Elara, "a"),
+            log_entry(Sender::Player, "b"),
+            log_entry(Sender::Elara, "c"),
+            log_entry(Sender::System, "[2 hours later]"),
+        ];
+        // The latest message is "c", skipping the system line after it
+        assert_eq!(state.toggle_bookmark_latest(), Some(true));
+        assert_eq!(state.toggle_bookmark_latest(), Some(false));
+        assert!(state.bookmarks.is_empty());
+        state.bookmarks = [1, 3].into_iter().collect();
+        state.message_log.push(log_entry(Sender::Elara, "d"));
+
+        assert!(state.trim_log(3));
+        let texts: Vec<&str> = state.message_log.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["SESSION:day one", "c", "[2 hours later]", "d"]);
+        assert_eq!(state.bookmarks.iter().copied().collect::<Vec<_>>(), vec![1]);
+
+        let tmp = std::env::temp_dir().join("eshara_test_bookmarks_save");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let save_file = tmp.join("save.json");
+        save_game_to(&state, &save_file).unwrap();
+        let loaded = load_game_from(&save_file).unwrap();
+        assert_eq!(loaded.bookmarks, state.bookmarks);
+        let _ = fs::remove_dir_all(&tmp);
+
+        // Saves without bookmarks leave the field out entirely
+        let json = serde_json::to_string(&GameStateBuilder::new().build()).unwrap();
+        assert!(!json.contains("bookmarks"));
+    }
+
+    #[test]
+    fn test_saved_runs_create_list_restore_delete() {
+        let tmp = std::env::temp_dir().join("eshara_test_saved_runs");
+        let _ = fs::remove_dir_all(&tmp);
+        let path = tmp.join("runs.json");
+        assert!(SavedRuns::load_from(&path).unwrap().runs.is_empty());
+
+        let mut runs = SavedRuns::default();
+        let rift = GameStateBuilder::new().at_node("rift").trust(7).build();
+        let camp = GameStateBuilder::new()
+            .at_node("camp")
+            .flag("at_camp")
+            .build();
+        assert!(runs.add("  before the rift ", &rift));
+        assert!(runs.add("settlement arrival", &camp));
+        assert!(!runs.add("   ", &camp));
+        runs.save_to(&path).unwrap();
+
+        let mut loaded = SavedRuns::load_from(&path).unwrap();
+        let labels: Vec<&str> = loaded.runs.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, vec!["before the rift", "settlement arrival"]);
+
+        // Restoring keeps the current preferences
+        let mut current = GameStateBuilder::new().language(Language::Fr).build();
+        current.settings.text_speed = TextSpeed::Fast;
+        let restored = loaded.restore(0, &current).unwrap();
+        assert_eq!(restored.current_node, "rift");
+        assert_eq!(restored.stats.trust, 7);
+        assert_eq!(restored.language, Language::Fr);
+        assert_eq!(restored.settings.text_speed, TextSpeed::Fast);
+        assert!(loaded.restore(5, &current).is_none());
+
+        // Same label overwrites in place
+        assert!(loaded.add("before the rift", &camp));
+        assert_eq!(loaded.runs.len(), 2);
+        assert_eq!(loaded.runs[0].state.current_node, "camp");
+
+        assert_eq!(loaded.remove(1).unwrap().label, "settlement arrival");
+        assert!(loaded.remove(1).is_none());
+        loaded.save_to(&path).unwrap();
+        assert_eq!(SavedRuns::load_from(&path).unwrap().runs.len(), 1);
+
+        // Bounded: a new label is refused once every slot is used
+        for i in 1..MAX_SAVED_RUNS {
+            assert!(loaded.add(&format!("run {}", i), &rift));
+        }
+        assert!(loaded.is_full());
+        assert!(!loaded.add("one too many", &rift));
+        assert!(loaded.add("run 1", &camp));
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_saved_runs_corrupt_file_is_an_error() {
+        let tmp = std::env::temp_dir().join("eshara_test_saved_runs_corrupt");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let path = tmp.join("runs.json");
+        fs::write(&path, b"{\"runs\": [").unwrap();
+
+        let err = SavedRuns::load_from(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            saved_run_label("  a very long name for a run, far too long "),
+            "a very long name for a run, far "
+        );
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn test_flags() {
         let mut state = GameState::new(Language::Fr, "test", 3, 10, 3);
@@ -348,6 +1842,8 @@ mod tests {
         assert_eq!(deserialized.settings.text_speed, TextSpeed::Normal);
         assert!(deserialized.settings.waiting_times_enabled);
         assert!(deserialized.settings.automatic_dialogs_enabled);
+        assert_eq!(deserialized.settings.idle_timeout_minutes, 0);
+        assert_eq!(deserialized.settings.ui, UiMode::Tui);
     }
 
     #[test]
@@ -370,15 +1866,328 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dir_path() {
-        let dir = save_dir();
-        assert!(dir.to_string_lossy().contains(".eshara"));
+    fn test_slow_text_speed_roundtrip() {
+        let mut state = GameStateBuilder::new().build();
+        state.settings.text_speed = TextSpeed::Slow;
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(json.contains("\"text_speed\":\"Slow\""));
+
+        let loaded: GameState = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.settings.text_speed, TextSpeed::Slow);
+    }
+
+    #[test]
+    fn test_save_slots_are_listed_with_metadata() {
+        let tmp = std::env::temp_dir().join("eshara_test_slots");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+
+        // Slot 0 keeps the original file name
+        assert_eq!(slot_file("save", 0), "save.json");
+        assert_eq!(slot_file("quicksave", 2), "quicksave_2.json");
+
+        let main = GameStateBuilder::new().at_node("camp").day(3).build();
+        save_game_to(&main, &tmp.join("save.json")).unwrap();
+        let side = GameStateBuilder::new()
+            .at_node("rift")
+            .language(Language::Fr)
+            .build();
+        save_game_to(&side, &tmp.join("save_2.json")).unwrap();
+        fs::write(tmp.join("save_3.json"), "not a save").unwrap();
+
+        let saves = list_saves_in(&tmp);
+        let summary: Vec<(u8, &str, u32, Language)> = saves
+            .iter()
+            .map(|s| (s.slot, s.current_node.as_str(), s.day, s.language))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(0, "camp", 3, Language::En), (2, "rift", 1, Language::Fr)]
+        );
+        assert!(saves[1].modified <= Utc::now());
+        assert!(list_saves_in(&tmp.join("missing")).is_empty());
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_compressed_save_roundtrip_large_log() {
+        let tmp = std::env::temp_dir().join("eshara_test_compressed_save");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let save_file = tmp.join("save.json");
+
+        let mut state = GameStateBuilder::new().at_node("a2_camp").build();
+        for i in 0..2000 {
+            state.message_log.push(LogEntry {
+                sender: if i % 2 == 0 {
+                    Sender::Elara
+                } else {
+                    Sender::Player
+                },
+                text: format!("Message number {} from the wasteland.", i),
+                timestamp: Utc::now(),
+            });
+        }
+        save_game_to(&state, &save_file).unwrap();
+
+        let plain_size = serde_json::to_vec_pretty(&state).unwrap().len();
+        let compressed_size = fs::metadata(&save_file).unwrap().len() as usize;
+        assert!(
+            compressed_size * 4 < plain_size,
+            "compressed {} bytes vs plain {} bytes",
+            compressed_size,
+            plain_size
+        );
+
+        let loaded = load_game_from(&save_file).unwrap();
+        assert_eq!(loaded.current_node, "a2_camp");
+        assert_eq!(loaded.message_log.len(), 2000);
+        assert_eq!(
+            loaded.message_log[1999].text,
+            "Message number 1999 from the wasteland."
+        );
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_checkpoint_restore_rolls_back_state() {
+        let mut state = GameStateBuilder::new()
+            .at_node("camp")
+            .trust(4)
+            .flag("at_camp")
+            .build();
+        state.record_checkpoint();
+
+        state.current_node = "road".to_string();
+        state.node_message_index = 2;
+        state.set_flag("took_the_road");
+        state.remove_flag("at_camp");
+        state.stats.modify("trust", 3);
+        state.stats.modify("health", -2);
+        state.death_triggered = true;
+        state.decision_path.push(DecisionRecord {
+            node: "camp".to_string(),
+            choice: 1,
+        });
+        state.settings.text_speed = TextSpeed::Fast;
+        state.active_play_ms = 60_000;
+        state.total_playtime_secs = 90;
+
+        assert!(state.restore_checkpoint());
+        assert_eq!(state.current_node, "camp");
+        assert_eq!(state.node_message_index, 0);
+        assert!(state.has_flag("at_camp"));
+        assert!(!state.has_flag("took_the_road"));
+        assert_eq!(state.stats.get("trust"), Some(4));
+        assert_eq!(state.stats.get("health"), Some(10));
+        // A death after the checkpoint is undone, so the check can fire again
+        assert!(!state.death_triggered);
+        assert!(state.decision_path.is_empty());
+        // Preferences and play time carry over; the checkpoint stays usable
+        assert_eq!(state.settings.text_speed, TextSpeed::Fast);
+        assert_eq!(state.active_play_ms, 60_000);
+        assert_eq!(state.total_playtime_secs, 90);
+        assert_eq!(state.checkpoints.len(), 1);
+        assert!(state.checkpoints[0].state.checkpoints.is_empty());
+
+        // Bounded, newest last, and re-entering a checkpoint replaces it
+        for node in ["gate", "camp", "labs"] {
+            state.current_node = node.to_string();
+            state.record_checkpoint();
+        }
+        let nodes: Vec<&str> = state.checkpoints.iter().map(|c| c.node.as_str()).collect();
+        assert_eq!(nodes, vec!["camp", "labs"]);
+        assert_eq!(state.checkpoints.len(), MAX_CHECKPOINTS);
+
+        let mut fresh = GameStateBuilder::new().build();
+        assert!(!fresh.restore_checkpoint());
+    }
+
+    #[test]
+    fn test_undo_steps_back_one_choice_at_a_time() {
+        let mut state = GameStateBuilder::new().at_node("camp").trust(4).build();
+        assert_eq!(state.undo(), Err(RewindBlocked::Nothing));
+        for (node, text) in [("camp", "Stay"), ("road", "Go"), ("gate", "Knock")] {
+            state.current_node = node.to_string();
+            state.node_message_index = 1;
+            state.message_log.push(log_entry(Sender::Elara, "Well?"));
+            state.record_rewind_step();
+            state.message_log.push(log_entry(Sender::Player, text));
+            state.decision_path.push(DecisionRecord {
+                node: node.to_string(),
+                choice: 0,
+            });
+            state.stats.modify("trust", 1);
+            state.set_flag(node);
+        }
+        state.bookmarks.insert(5);
+        state.settings.text_speed = TextSpeed::Fast;
+        assert_eq!(state.rewind_available(), 3);
+
+        assert_eq!(state.undo(), Ok(()));
+        assert_eq!(state.current_node, "gate");
+        assert_eq!(state.node_message_index, 1);
+        assert_eq!(state.message_log.len(), 5);
+        assert_eq!(state.message_log[4].text, "Well?");
+        assert!(state.bookmarks.is_empty());
+        assert_eq!(state.decision_path.len(), 2);
+        assert_eq!(state.stats.get("trust"), Some(6));
+        assert!(!state.has_flag("gate"));
+        assert_eq!(state.settings.text_speed, TextSpeed::Fast);
+
+        // Trimming drops the steps whose messages are gone and shifts the rest
+        state.trim_log(3);
+        assert_eq!(state.rewind.len(), 1);
+        assert_eq!(state.rewind[0].log_len, 1);
+
+        // Once a real-time wait starts, nothing before it can be undone
+        state.block_rewind();
+        assert_eq!(state.rewind_available(), 0);
+        assert_eq!(state.undo(), Err(RewindBlocked::Wait));
+        assert_eq!(state.current_node, "gate");
+
+        for _ in 0..MAX_REWIND_STEPS + 2 {
+            state.record_rewind_step();
+        }
+        assert_eq!(state.rewind.len(), MAX_REWIND_STEPS);
+        assert!(state.rewind[0].state.message_log.is_empty());
+    }
+
+    #[test]
+    fn test_identical_states_save_byte_identical() {
+        let flags = ["vasik_badge", "has_medicine", "lina_asked", "entered_main"];
+        let build = |order: &mut dyn Iterator<Item = &&str>| {
+            let mut state = GameStateBuilder::new().at_node("a3_outpost").build();
+            for flag in order {
+                state.set_flag(flag);
+            }
+            state
+        };
+        let a = build(&mut flags.iter());
+        let b = build(&mut flags.iter().rev());
+
+        assert_eq!(encode_save(&a).unwrap(), encode_save(&b).unwrap());
+        let json = serde_json::to_string(&a).unwrap();
+        assert!(json.contains(
+            r#""flags":{"entered_main":true,"has_medicine":true,"lina_asked":true,"vasik_badge":true}"#
+        ));
+    }
+
+    #[test]
+    fn test_load_plain_json_save() {
+        let tmp = std::env::temp_dir().join("eshara_test_plain_save");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let save_file = tmp.join("save.json");
+
+        let state = GameStateBuilder::new().at_node("a1_warehouse").build();
+        fs::write(&save_file, serde_json::to_string_pretty(&state).unwrap()).unwrap();
+
+        let loaded = load_game_from(&save_file).unwrap();
+        assert_eq!(loaded.current_node, "a1_warehouse");
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_endings_ledger_roundtrip() {
+        let tmp = std::env::temp_dir().join("eshara_test_endings_ledger");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let path = tmp.join("endings.json");
+
+        // Missing file starts empty
+        let mut ledger = EndingsLedger::load_from(&path);
+        assert!(ledger.endings_seen.is_empty());
+
+        assert!(ledger.record("static", 4));
+        assert!(!ledger.record("static", 9));
+        ledger.save_to(&path).unwrap();
+
+        let loaded = EndingsLedger::load_from(&path);
+        assert!(loaded.has_seen("static"));
+        assert!(!loaded.has_seen("still_here"));
+        // The first sighting is kept
+        assert_eq!(loaded.ending_records["static"].day, 4);
+        let endings = ["static".to_string(), "still_here".to_string()];
+        assert_eq!(loaded.discovered(&endings), 1);
+
+        // Ledgers from before the records still load
+        fs::write(&path, r#"{"endings_seen": ["echo"]}"#).unwrap();
+        let old = EndingsLedger::load_from(&path);
+        assert!(old.has_seen("echo"));
+        assert!(old.ending_records.is_empty());
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_ledger_choices_and_theme_unlocks() {
+        let mut ledger = EndingsLedger::default();
+        assert!(ledger.record_choice("a1_oriented", 1));
+        assert!(!ledger.record_choice("a1_oriented", 1));
+        assert!(ledger.has_taken("a1_oriented", 1));
+        assert!(!ledger.has_taken("a1_oriented", 0));
+
+        let always = [Theme::Classic, Theme::HighContrast, Theme::Monochrome];
+        assert_eq!(ledger.unlocked_themes(2), always);
+        ledger.record("static", 1);
+        ledger.record("echo", 1);
+        assert_eq!(
+            ledger.unlocked_themes(2),
+            [&always[..], &[Theme::Signal]].concat()
+        );
+    }
+
+    #[test]
+    fn test_data_dir_prefers_override_then_config_then_legacy() {
+        let tmp = std::env::temp_dir().join("eshara_test_data_dir");
+        let _ = fs::remove_dir_all(&tmp);
+        let config = tmp.join("config");
+        let home = tmp.join("home");
+        let resolve = |over: Option<&Path>| {
+            data_dir_from(
+                over.map(Path::to_path_buf),
+                Some(config.clone()),
+                Some(home.clone()),
+            )
+        };
+
+        assert_eq!(resolve(Some(&tmp.join("custom"))), tmp.join("custom"));
+        assert_eq!(resolve(Some(Path::new(""))), config.join("eshara"));
+
+        // An existing ~/.eshara is kept until the config dir has one
+        fs::create_dir_all(home.join(".eshara")).unwrap();
+        assert_eq!(resolve(None), home.join(".eshara"));
+        fs::create_dir_all(config.join("eshara")).unwrap();
+        assert_eq!(resolve(None), config.join("eshara"));
+
+        assert_eq!(
+            data_dir_from(None, None, Some(home.clone())),
+            home.join(".eshara")
+        );
+        assert_eq!(
+            data_dir_from(None, None, None),
+            std::env::temp_dir().join("eshara")
+        );
+
+        // The story file falls back to the working directory
+        let dir = config.join("eshara");
+        assert_eq!(
+            story_path_in(&dir),
+            PathBuf::from(crate::story::EXTERNAL_STORY_PATH)
+        );
+        fs::write(dir.join("story.json"), "{}").unwrap();
+        assert_eq!(story_path_in(&dir), dir.join("story.json"));
+        let _ = fs::remove_dir_all(&tmp);
     }
 
     #[test]
     fn test_parse_cli_args_reset() {
         let args = vec!["eshara".to_string(), "--reset".to_string()];
-        let parsed = parse_cli_args_from(&args);
+        let parsed = parse_cli_args_from(&args).unwrap();
         assert!(parsed.reset);
         assert!(parsed.language.is_none());
     }
@@ -386,7 +2195,294 @@ mod tests {
     #[test]
     fn test_parse_cli_args_language() {
         let args = vec!["eshara".to_string(), "--lang".to_string(), "fr".to_string()];
-        let parsed = parse_cli_args_from(&args);
+        let parsed = parse_cli_args_from(&args).unwrap();
         assert_eq!(parsed.language, Some(Language::Fr));
     }
+
+    #[test]
+    fn test_parse_cli_args_diff() {
+        let args: Vec<String> = ["eshara", "--diff", "a.json", "b.json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let parsed = parse_cli_args_from(&args).unwrap();
+        assert_eq!(
+            parsed.diff,
+            Some((PathBuf::from("a.json"), PathBuf::from("b.json")))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_args_log() {
+        let args: Vec<String> = ["eshara", "--log", "trace.log", "--lang", "fr"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let parsed = parse_cli_args_from(&args).unwrap();
+        assert_eq!(parsed.log, Some(PathBuf::from("trace.log")));
+        assert_eq!(parsed.language, Some(Language::Fr));
+        assert!(!parsed.stdin_choices);
+        assert!(!parsed.dev);
+        assert!(!parsed.fallback_embedded);
+        assert_eq!(parsed.ui, None);
+        assert_eq!(parsed.validate, None);
+
+        let args: Vec<String> = ["eshara", "--validate", "draft.json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let parsed = parse_cli_args_from(&args).unwrap();
+        assert_eq!(parsed.validate, Some(PathBuf::from("draft.json")));
+    }
+
+    #[test]
+    fn test_parse_cli_args_ui() {
+        let args: Vec<String> = ["eshara", "--ui", "classic"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            parse_cli_args_from(&args).unwrap().ui,
+            Some(UiMode::Classic)
+        );
+        assert_eq!(parse_ui_mode("TUI"), Some(UiMode::Tui));
+        assert_eq!(parse_ui_mode("fancy"), None);
+    }
+
+    #[test]
+    fn test_parse_cli_args_bench_load() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            parse_cli_args_from(&args).unwrap().bench_load
+        };
+        assert_eq!(parse(&["eshara", "--bench-load", "5"]), Some(5));
+        assert_eq!(
+            parse(&["eshara", "--bench-load", "--reset"]),
+            Some(DEFAULT_BENCH_ITERATIONS)
+        );
+        assert_eq!(parse(&["eshara", "--bench-load", "0"]), Some(1));
+        assert_eq!(parse(&["eshara"]), None);
+    }
+
+    #[test]
+    fn test_parse_cli_args_dump_text() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            parse_cli_args_from(&args).map(|parsed| parsed.dump_text)
+        };
+        assert_eq!(
+            parse(&["eshara", "--dump-text", "fr", "en", "--reset"]),
+            Ok(vec![Language::Fr, Language::En])
+        );
+        assert_eq!(
+            parse(&["eshara", "--dump-text", "en", "--reset"]),
+            Ok(vec![Language::En])
+        );
+        assert!(parse(&["eshara", "--dump-text"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_cli_args_check_lang() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            parse_cli_args_from(&args).map(|parsed| parsed.check_lang)
+        };
+        assert_eq!(parse(&["eshara"]), Ok(vec![]));
+        assert_eq!(
+            parse(&["eshara", "--check-lang", "de"]),
+            Ok(vec![Language::De])
+        );
+        assert_eq!(
+            parse(&["eshara", "--check-lang", "--reset"]),
+            Ok(vec![Language::Fr, Language::De])
+        );
+        assert!(parse(&["eshara", "--check-lang", "en"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_cli_args_errors() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            parse_cli_args_from(&args).err()
+        };
+        assert_eq!(
+            parse(&["eshara", "--frobnicate"]),
+            Some("unknown flag '--frobnicate'".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "save.json"]),
+            Some("unexpected argument 'save.json'".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--lang"]),
+            Some("--lang needs a language (en, fr or de)".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--log", "--dev"]),
+            Some("--log needs a file path".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--validate"]),
+            Some("--validate needs a story file".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--diff", "a.json"]),
+            Some("--diff needs two save files".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--lang", "es"]),
+            Some("unknown language 'es' for --lang".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--ui", "fancy"]),
+            Some("unknown frontend 'fancy' for --ui".to_string())
+        );
+        assert_eq!(
+            parse(&["eshara", "--slot", "4"]),
+            Some("'4' is not a save slot (0-3) for --slot".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_args_help_lists_every_flag() {
+        for flag in ["--help", "-h"] {
+            let args: Vec<String> = ["eshara", flag].iter().map(|s| s.to_string()).collect();
+            assert!(parse_cli_args_from(&args).unwrap().help);
+        }
+        for flag in [
+            "--lang",
+            "--ui",
+            "--stdin-choices",
+            "--reset",
+            "--diff",
+            "--lint",
+            "--bench-load",
+            "--check-lang",
+            "--dump-text",
+            "--replay-route",
+            "--fallback-embedded",
+            "--dev",
+            "--log",
+            "--version",
+            "--slot",
+            "--no-animation",
+            "--debug",
+            "--validate",
+            "--graph",
+        ] {
+            assert!(USAGE.contains(flag), "{} missing from usage", flag);
+            let args: Vec<String> = ["eshara", flag, "en", "x"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+            // Known flags never trip the unknown-flag error
+            if let Err(e) = parse_cli_args_from(&args) {
+                assert!(!e.contains("unknown flag"), "{}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_export_transcript_markdown_and_json() {
+        let mut state = GameStateBuilder::new().language(Language::Fr).build();
+        state.message_log = vec![
+            log_entry(Sender::System, "SESSION:lun. 09:00"),
+            log_entry(Sender::Elara, "Ça va ? Déjà l'aube."),
+            log_entry(Sender::Player, "Je suis là."),
+            log_entry(Sender::System, "[2 h écoulé]"),
+        ];
+        state.log_trimmed = true;
+
+        let md = export_transcript(&state, TranscriptFormat::Markdown);
+        assert!(md.starts_with("# Eshara \u{2014} transcription\n"));
+        assert!(md.contains("\n_Les messages les plus anciens"));
+        assert!(md.contains("\n## lun. 09:00\n"));
+        assert!(md.contains("\n**Elara:** Ça va ? Déjà l'aube.\n"));
+        assert!(md.contains("\n> **Toi:** Je suis là.\n"));
+        assert!(md.contains("\n*[2 h écoulé]*\n"));
+        assert!(!md.contains("SESSION:"));
+
+        let json = export_transcript(&state, TranscriptFormat::Json);
+        assert!(json.contains("Ça va ? Déjà l'aube."));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["log_trimmed"], true);
+        assert_eq!(value["entries"].as_array().unwrap().len(), 4);
+        assert_eq!(value["entries"][0]["text"], "SESSION:lun. 09:00");
+        assert_eq!(value["entries"][2]["sender"], "Player");
+
+        let tmp = std::env::temp_dir().join("eshara_test_transcript");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&tmp).unwrap();
+        let path = write_transcript(&state, TranscriptFormat::Markdown, &tmp).unwrap();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("eshara-transcript-") && name.ends_with(".md"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), md);
+
+        // Exports in quick succession never overwrite each other
+        let paths: BTreeSet<PathBuf> = (0..3)
+            .map(|_| write_transcript(&state, TranscriptFormat::Markdown, &tmp).unwrap())
+            .chain([path])
+            .collect();
+        assert_eq!(paths.len(), 4);
+        assert_eq!(fs::read_dir(&tmp).unwrap().count(), 4);
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_diff_states_identical() {
+        let a = GameState::new(Language::En, "a1_first_contact", 3, 10, 3);
+        assert!(diff_states(&a, &a.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_states_reports_changes() {
+        let mut a = GameState::new(Language::En, "a2_camp", 3, 10, 3);
+        a.set_flag("went_over_overpass");
+        let mut b = a.clone();
+        b.current_node = "a3_doubt".to_string();
+        b.day = 2;
+        b.stats.trust = 5;
+        b.remove_flag("went_over_overpass");
+        b.set_flag("has_shielding");
+        b.ending = Some("echo".to_string());
+
+        let diff = diff_states(&a, &b);
+        assert_eq!(
+            diff,
+            vec![
+                "current node: a2_camp -> a3_doubt",
+                "day: 1 -> 2",
+                "trust: 3 -> 5",
+                "flags added: has_shielding",
+                "flags removed: went_over_overpass",
+                "ending: none -> echo",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_save_scheduler_coalesces_rapid_requests() {
+        let interval = Duration::from_millis(1000);
+        let start = Instant::now();
+        let mut scheduler = SaveScheduler::default();
+
+        // The first request always writes
+        assert!(scheduler.request(start, interval));
+        scheduler.written(start);
+
+        // Requests inside the interval are held back, then come due together
+        let soon = start + Duration::from_millis(300);
+        assert!(!scheduler.request(soon, interval));
+        assert!(!scheduler.request(soon + Duration::from_millis(100), interval));
+        assert!(scheduler.is_pending());
+        assert!(!scheduler.due(soon, interval));
+        let later = start + interval;
+        assert!(scheduler.due(later, interval));
+        scheduler.written(later);
+        assert!(!scheduler.is_pending());
+        assert!(!scheduler.due(later + interval, interval));
+
+        // With no interval every request writes
+        assert!(scheduler.request(later, Duration::ZERO));
+    }
 }
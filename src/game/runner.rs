@@ -0,0 +1,208 @@
+//! Headless playthroughs for integration tests and balance checks.
+//!
+//! `HeadlessRunner` drives the same node logic as the frontends (effects,
+//! branches, death checks, interrupts) with nothing drawn, printed, saved
+//! or slept through. A run stops at an ending, at a real-time wait, or
+//! when the resolver has no choice to give.
+
+use crate::game::{GameState, Stats};
+use crate::i18n::Language;
+use crate::story::{StoryData, StoryNode};
+use crate::tui::{App, Screen};
+
+/// Why a headless run stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunStop {
+    /// An ending was reached
+    Ending,
+    /// A real-time wait was scheduled; `skip_wait` and run again to go on
+    Waiting,
+    /// The resolver gave no choice, or one this node doesn't have
+    NoChoice(String),
+    /// The story pointed at a node that doesn't exist
+    MissingNode(String),
+}
+
+/// What a headless run went through
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    /// Node ids in the order they were entered, re-entries included
+    pub visited: Vec<String>,
+    pub stop: RunStop,
+    /// The ending reached, if any
+    pub ending: Option<String>,
+    /// The story's type for that ending (e.g. "good")
+    pub ending_type: Option<String>,
+    /// Stats where the run stopped
+    pub stats: Stats,
+}
+
+/// Plays a story without a terminal, resolving each choice by source index.
+pub struct HeadlessRunner {
+    app: App,
+}
+
+impl HeadlessRunner {
+    /// Start from `state` on `story`. Nothing is ever saved.
+    pub fn new(story: StoryData, state: GameState) -> Self {
+        let mut app = App::new(state, story);
+        app.persist = false;
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        Self { app }
+    }
+
+    /// A fresh English game on the embedded story
+    pub fn embedded() -> Self {
+        let story = crate::story::embedded_story();
+        let state = GameState::from_story(Language::En, &story);
+        Self::new(story, state)
+    }
+
+    /// The state as the run left it
+    pub fn state(&self) -> &GameState {
+        &self.app.game_state
+    }
+
+    /// End a scheduled wait as if the time had passed
+    pub fn skip_wait(&mut self) {
+        if self.app.game_state.waiting_until.take().is_some() {
+            self.app.wait_message = None;
+            self.app.advance_story = true;
+        }
+    }
+
+    /// Play a fixed list of choices (source indices), in order
+    pub fn run_choices(&mut self, choices: &[usize]) -> Transcript {
+        let mut steps = choices.iter().copied();
+        self.run(|_| steps.next())
+    }
+
+    /// Play until an ending, a wait, or `choose` has no answer. `choose` is
+    /// given the node waiting on a choice and returns the source index of
+    /// the choice to take.
+    pub fn run(&mut self, mut choose: impl FnMut(&StoryNode) -> Option<usize>) -> Transcript {
+        let app = &mut self.app;
+        let mut visited = Vec::new();
+        let stop = loop {
+            if app.advance_story {
+                let state = &app.game_state;
+                if state.node_message_index == 0 && !state.node_entered {
+                    visited.push(state.current_node.clone());
+                }
+                app.process_current_node();
+            }
+            app.flush_messages();
+
+            let node_id = app.game_state.current_node.clone();
+            if app.screen == Screen::StoryError {
+                break RunStop::MissingNode(node_id);
+            }
+            if app.screen == Screen::Ending {
+                break RunStop::Ending;
+            }
+            if app.game_state.waiting_until.is_some() {
+                break RunStop::Waiting;
+            }
+            if app.advance_story {
+                continue;
+            }
+
+            // A locked choice can't be taken, so stop rather than retry it forever
+            let index = app.story_data.nodes.get(&node_id).and_then(&mut choose);
+            if !index.is_some_and(|index| app.take_choice(index)) {
+                break RunStop::NoChoice(node_id);
+            }
+        };
+
+        let ending = app.game_state.ending.clone();
+        let ending_type = ending
+            .as_ref()
+            .and_then(|id| app.story_data.endings.get(id))
+            .map(|info| info.ending_type.clone());
+        Transcript {
+            visited,
+            stop,
+            ending,
+            ending_type,
+            stats: app.game_state.stats.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story_from_json(json: &str) -> StoryData {
+        serde_json::from_str(json).expect("test story should parse")
+    }
+
+    #[test]
+    fn test_scripted_choices_reach_an_ending() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "fork" },
+                "stats": { "trust": { "initial": 3, "min": 0, "max": 10 },
+                           "health": { "initial": 10, "min": 0, "max": 10 } },
+                "endings": { "home": { "title": { "en": "Home", "fr": "" }, "type": "good" } },
+                "nodes": {
+                    "fork": { "id": "fork", "messages": [{ "en": "Which way?", "fr": "" }],
+                              "choices": [
+                                  { "label": { "en": "Left", "fr": "" }, "next_node": "camp",
+                                    "on_choose": { "trust_change": 2 } },
+                                  { "label": { "en": "Right", "fr": "" }, "next_node": "home" }
+                              ] },
+                    "camp": { "id": "camp", "messages": [{ "en": "Resting.", "fr": "" }],
+                              "on_enter": { "health_change": -3 },
+                              "delay": { "seconds": 60, "message": { "en": "Asleep.", "fr": "" } },
+                              "next_node": "fork" },
+                    "home": { "id": "home", "messages": [{ "en": "Home.", "fr": "" }],
+                              "ending": "home" }
+                }
+            }"#,
+        );
+        let state = GameState::from_story(Language::En, &story);
+        let mut runner = HeadlessRunner::new(story, state);
+
+        let first = runner.run_choices(&[0]);
+        assert_eq!(first.visited, vec!["fork", "camp"]);
+        assert_eq!(first.stop, RunStop::Waiting);
+        assert_eq!(first.stats.trust, 5);
+        assert_eq!(first.stats.health, 7);
+
+        runner.skip_wait();
+        let stuck = runner.run_choices(&[5]);
+        assert_eq!(stuck.visited, vec!["fork"]);
+        assert_eq!(stuck.stop, RunStop::NoChoice("fork".to_string()));
+
+        let last = runner.run(|node| (node.id == "fork").then_some(1));
+        assert_eq!(last.visited, vec!["home"]);
+        assert_eq!(last.stop, RunStop::Ending);
+        assert_eq!(last.ending.as_deref(), Some("home"));
+        assert_eq!(last.ending_type.as_deref(), Some("good"));
+        assert_eq!(runner.state().decision_path.len(), 2);
+    }
+
+    #[test]
+    fn test_embedded_story_plays_to_an_ending() {
+        let mut runner = HeadlessRunner::embedded();
+        let mut visited = Vec::new();
+        let transcript = loop {
+            let transcript = runner.run(|_| Some(0));
+            visited.extend(transcript.visited.iter().cloned());
+            if transcript.stop != RunStop::Waiting {
+                break transcript;
+            }
+            runner.skip_wait();
+        };
+        assert_eq!(transcript.stop, RunStop::Ending);
+        assert!(transcript.ending_type.is_some());
+        assert_eq!(visited[0], "a1_first_contact");
+        assert!(runner
+            .state()
+            .decision_path
+            .iter()
+            .all(|record| visited.contains(&record.node)));
+    }
+}
@@ -1,17 +1,41 @@
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
 use serde::{Deserialize, Serialize};
 
 /// Supported languages
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     En,
     Fr,
+    De,
 }
 
-/// A string localized in both English and French
+impl Language {
+    /// Every supported language, in the order they are offered
+    pub const ALL: [Language; 3] = [Language::En, Language::Fr, Language::De];
+
+    /// Short code used on the command line and in tooling output
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Fr => "fr",
+            Language::De => "de",
+        }
+    }
+}
+
+/// A string localized in English, with optional translations.
+///
+/// English is the canonical text and must be present. Any other language
+/// may be left empty (or, for German, left out of the JSON entirely): it is
+/// then shown in English, so partially translated stories still run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocalizedString {
     pub en: String,
     pub fr: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub de: String,
 }
 
 impl LocalizedString {
@@ -19,29 +43,76 @@ impl LocalizedString {
         Self {
             en: en.to_string(),
             fr: fr.to_string(),
+            de: String::new(),
         }
     }
 
-    /// Get the string for the given language
+    /// Get the string for the given language, falling back to English
+    /// (the canonical language) when the translation is empty. Each missing
+    /// translation is logged once.
     pub fn get(&self, lang: Language) -> &str {
+        let text = self.raw(lang);
+        if text.is_empty() && lang != Language::En {
+            warn_missing_once(&self.en, lang);
+            return &self.en;
+        }
+        text
+    }
+
+    /// Whether the string has no text for the given language.
+    pub fn is_missing(&self, lang: Language) -> bool {
+        self.raw(lang).is_empty()
+    }
+
+    /// The string for the given language exactly as authored, without fallback.
+    pub fn raw(&self, lang: Language) -> &str {
         match lang {
             Language::En => &self.en,
             Language::Fr => &self.fr,
+            Language::De => &self.de,
+        }
+    }
+}
+
+/// Every system string side by side in the given languages, one block per key.
+pub fn system_text_dump(langs: &[Language]) -> String {
+    let mut out = String::from("== system strings ==\n");
+    for &key in Msg::ALL {
+        out.push_str(&format!("{:?}\n", key));
+        for &lang in langs {
+            out.push_str(&format!("  {}: {}\n", lang.code(), sys_msg(key, lang)));
+        }
+    }
+    out
+}
+
+/// Log a missing translation the first time it is displayed.
+fn warn_missing_once(en: &str, lang: Language) {
+    static WARNED: OnceLock<Mutex<HashSet<(String, Language)>>> = OnceLock::new();
+    let warned = WARNED.get_or_init(|| Mutex::new(HashSet::new()));
+    if let Ok(mut warned) = warned.lock() {
+        if warned.insert((en.to_string(), lang)) {
+            log::warn!("missing_translation lang={:?} en={:?}", lang, en);
         }
     }
 }
 
 /// System message keys for all UI/menu text
 #[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
 pub enum Msg {
     LanguagePrompt,
     LanguageOption1,
     LanguageOption2,
+    LanguageOption3,
     ContinueOrNew,
     ContinueOption,
     NewGameOption,
     ElaraTyping,
+    ElaraNudge,
     ElaraUnavailable,
+    WaitQuitHint,
+    WaitSkipHint,
     ElaraBackAround,
     WaitOrQuit,
     WaitOption,
@@ -52,11 +123,34 @@ pub enum Msg {
     SessionStart,
     EndingReached,
     DaysSurvived,
+    ActivePlayTime,
+    TimeConnected,
+    WaitsEndured,
     KeyChoices,
+    RouteCode,
+    Epilogues,
+    EndingsDiscovered,
+    QuickSaved,
+    BookmarkAdded,
+    BookmarkRemoved,
+    BookmarksNone,
+    BookmarksHint,
+    SessionsHint,
+    QuickLoaded,
+    QuickLoadConfirm,
+    QuickSaveMissing,
+    QuickSaveFailed,
+    QuickSaveUnreadable,
+    EndingUnknown,
+    EndingNew,
     PlayAgain,
     YesOption,
+    NewGamePlusOption,
     NoOption,
+    ChoiceExplored,
+    ChoiceLocked,
     InvalidChoice,
+    RereadChoice,
     SaveDeleted,
     IntroRadioCrackle,
     PauseMenuTitle,
@@ -66,124 +160,821 @@ pub enum Msg {
     MenuTextSpeed,
     MenuWaitingTimes,
     MenuAutomaticDialogs,
+    MenuFastForward,
+    MenuIdleTimeout,
+    MenuIdleNudge,
+    MenuMinReadTime,
+    MenuTypingRhythm,
+    MenuReduceMotion,
+    MenuConsequenceHints,
+    MenuCommentary,
+    MenuLanguagePace,
+    MenuShowStats,
+    MenuTrustCues,
+    TrustCueUp,
+    TrustCueDown,
+    HintTrust,
+    HintHealth,
+    HintSupplies,
+    HintSets,
+    MenuChatLayout,
+    MenuSessionMarkers,
+    MenuActCards,
+    MenuLogRetention,
+    MenuSaveInterval,
+    MenuTheme,
+    SettingThemeClassic,
+    SettingThemeSignal,
+    SettingThemeHighContrast,
+    SettingThemeMonochrome,
+    MenuGlossary,
+    MenuRecap,
+    RecapLastChoice,
+    RecapNothingYet,
+    GlossaryLocked,
+    GlossaryUnlocked,
+    CheckpointReached,
+    CheckpointRestored,
+    Rewound,
+    RewindNothing,
+    RewindBlockedByWait,
+    CheckpointOption,
+    StoryNodeMissing,
+    SlotSelectPrompt,
+    SaveSlot,
+    SlotNewGame,
+    StoryErrorCheckpoint,
+    StoryErrorNewGame,
+    MenuRewind,
+    MenuExportTranscript,
+    TranscriptExported,
+    TranscriptFailed,
+    TranscriptTitle,
+    TranscriptPlayer,
+    TranscriptTrimmed,
+    MenuReturnToCheckpoint,
+    MenuSavedRuns,
+    SavedRunsNew,
+    SavedRunsHint,
+    SavedRunsNameHint,
+    SavedRunsFull,
+    SavedRunStored,
+    SavedRunDeleted,
+    SavedRunRestored,
+    SavedRunsError,
+    SavedRunsUnreadable,
+    GlossaryEmpty,
+    ExploreHint,
+    ExploreTitle,
+    ExploreNotCanon,
+    ExploreYouChose,
+    ExploreNoAlternatives,
+    MenuReplayIntro,
     MenuValidate,
     MenuSaveQuit,
     SettingEnabled,
     SettingDisabled,
+    SettingOff,
+    SettingAll,
+    SettingEveryMessage,
+    SettingLayoutInline,
+    SettingLayoutGutter,
+    SettingMarkersCollapsed,
+    SettingMarkersHidden,
+    SessionResumed,
+    GutterPlayer,
+    ActProgress,
+    ActCardSkip,
+    MoreContent,
+    NewMessagesBelow,
+    SettingSpeedSlow,
     SettingSpeedNormal,
     SettingSpeedFast,
     SettingSpeedInstant,
     SettingLangEn,
     SettingLangFr,
+    SettingLangDe,
     LanguageSwitched,
     SavedAndQuit,
+    TerminalTooSmall,
+    TerminalNotInteractive,
+    TerminalTryClassic,
+    StoryDeadEnd,
+    ConfirmChoiceYes,
+    ConfirmChoiceBack,
+}
+
+impl Msg {
+    /// Every system message, in declaration order (for translation tooling).
+    pub const ALL: &'static [Msg] = &[
+        Msg::LanguagePrompt,
+        Msg::LanguageOption1,
+        Msg::LanguageOption2,
+        Msg::LanguageOption3,
+        Msg::ContinueOrNew,
+        Msg::ContinueOption,
+        Msg::NewGameOption,
+        Msg::ElaraTyping,
+        Msg::ElaraNudge,
+        Msg::ElaraUnavailable,
+        Msg::WaitQuitHint,
+        Msg::WaitSkipHint,
+        Msg::ElaraBackAround,
+        Msg::WaitOrQuit,
+        Msg::WaitOption,
+        Msg::QuitOption,
+        Msg::SignalLost,
+        Msg::DaySeparator,
+        Msg::BacklogHeader,
+        Msg::SessionStart,
+        Msg::EndingReached,
+        Msg::DaysSurvived,
+        Msg::ActivePlayTime,
+        Msg::TimeConnected,
+        Msg::WaitsEndured,
+        Msg::KeyChoices,
+        Msg::RouteCode,
+        Msg::Epilogues,
+        Msg::EndingsDiscovered,
+        Msg::QuickSaved,
+        Msg::BookmarkAdded,
+        Msg::BookmarkRemoved,
+        Msg::BookmarksNone,
+        Msg::BookmarksHint,
+        Msg::SessionsHint,
+        Msg::QuickLoaded,
+        Msg::QuickLoadConfirm,
+        Msg::QuickSaveMissing,
+        Msg::QuickSaveFailed,
+        Msg::QuickSaveUnreadable,
+        Msg::EndingUnknown,
+        Msg::EndingNew,
+        Msg::PlayAgain,
+        Msg::YesOption,
+        Msg::NewGamePlusOption,
+        Msg::NoOption,
+        Msg::ChoiceExplored,
+        Msg::ChoiceLocked,
+        Msg::InvalidChoice,
+        Msg::RereadChoice,
+        Msg::SaveDeleted,
+        Msg::IntroRadioCrackle,
+        Msg::PauseMenuTitle,
+        Msg::PauseMenuHint,
+        Msg::MenuResume,
+        Msg::MenuLanguage,
+        Msg::MenuTextSpeed,
+        Msg::MenuWaitingTimes,
+        Msg::MenuAutomaticDialogs,
+        Msg::MenuFastForward,
+        Msg::MenuIdleTimeout,
+        Msg::MenuIdleNudge,
+        Msg::MenuMinReadTime,
+        Msg::MenuTypingRhythm,
+        Msg::MenuReduceMotion,
+        Msg::MenuConsequenceHints,
+        Msg::MenuCommentary,
+        Msg::MenuLanguagePace,
+        Msg::MenuShowStats,
+        Msg::MenuTrustCues,
+        Msg::TrustCueUp,
+        Msg::TrustCueDown,
+        Msg::HintTrust,
+        Msg::HintHealth,
+        Msg::HintSupplies,
+        Msg::HintSets,
+        Msg::MenuChatLayout,
+        Msg::MenuSessionMarkers,
+        Msg::MenuActCards,
+        Msg::MenuLogRetention,
+        Msg::MenuSaveInterval,
+        Msg::MenuTheme,
+        Msg::SettingThemeClassic,
+        Msg::SettingThemeSignal,
+        Msg::SettingThemeHighContrast,
+        Msg::SettingThemeMonochrome,
+        Msg::MenuGlossary,
+        Msg::MenuRecap,
+        Msg::RecapLastChoice,
+        Msg::RecapNothingYet,
+        Msg::GlossaryLocked,
+        Msg::GlossaryUnlocked,
+        Msg::CheckpointReached,
+        Msg::CheckpointRestored,
+        Msg::Rewound,
+        Msg::RewindNothing,
+        Msg::RewindBlockedByWait,
+        Msg::CheckpointOption,
+        Msg::StoryNodeMissing,
+        Msg::SlotSelectPrompt,
+        Msg::SaveSlot,
+        Msg::SlotNewGame,
+        Msg::StoryErrorCheckpoint,
+        Msg::StoryErrorNewGame,
+        Msg::MenuRewind,
+        Msg::MenuExportTranscript,
+        Msg::TranscriptExported,
+        Msg::TranscriptFailed,
+        Msg::TranscriptTitle,
+        Msg::TranscriptPlayer,
+        Msg::TranscriptTrimmed,
+        Msg::MenuReturnToCheckpoint,
+        Msg::MenuSavedRuns,
+        Msg::SavedRunsNew,
+        Msg::SavedRunsHint,
+        Msg::SavedRunsNameHint,
+        Msg::SavedRunsFull,
+        Msg::SavedRunStored,
+        Msg::SavedRunDeleted,
+        Msg::SavedRunRestored,
+        Msg::SavedRunsError,
+        Msg::SavedRunsUnreadable,
+        Msg::GlossaryEmpty,
+        Msg::ExploreHint,
+        Msg::ExploreTitle,
+        Msg::ExploreNotCanon,
+        Msg::ExploreYouChose,
+        Msg::ExploreNoAlternatives,
+        Msg::MenuReplayIntro,
+        Msg::MenuValidate,
+        Msg::MenuSaveQuit,
+        Msg::SettingEnabled,
+        Msg::SettingDisabled,
+        Msg::SettingOff,
+        Msg::SettingAll,
+        Msg::SettingEveryMessage,
+        Msg::SettingLayoutInline,
+        Msg::SettingLayoutGutter,
+        Msg::SettingMarkersCollapsed,
+        Msg::SettingMarkersHidden,
+        Msg::SessionResumed,
+        Msg::GutterPlayer,
+        Msg::ActProgress,
+        Msg::ActCardSkip,
+        Msg::MoreContent,
+        Msg::NewMessagesBelow,
+        Msg::SettingSpeedSlow,
+        Msg::SettingSpeedNormal,
+        Msg::SettingSpeedFast,
+        Msg::SettingSpeedInstant,
+        Msg::SettingLangEn,
+        Msg::SettingLangFr,
+        Msg::SettingLangDe,
+        Msg::LanguageSwitched,
+        Msg::SavedAndQuit,
+        Msg::TerminalTooSmall,
+        Msg::TerminalNotInteractive,
+        Msg::TerminalTryClassic,
+        Msg::StoryDeadEnd,
+        Msg::ConfirmChoiceYes,
+        Msg::ConfirmChoiceBack,
+    ];
 }
 
 /// Get a localized system message
 pub fn sys_msg(key: Msg, lang: Language) -> &'static str {
     match (key, lang) {
         // Language selection (shown before language is chosen, so both are hardcoded)
-        (Msg::LanguagePrompt, _) => "Choose your language / Choisissez votre langue:",
+        (Msg::LanguagePrompt, _) => {
+            "Choose your language / Choisissez votre langue / W\u{00e4}hle deine Sprache:"
+        }
         (Msg::LanguageOption1, _) => "1. English",
         (Msg::LanguageOption2, _) => "2. Fran\u{00e7}ais",
+        (Msg::LanguageOption3, _) => "3. Deutsch",
 
         // Continue or new game
         (Msg::ContinueOrNew, Language::En) => "A save file was found. What would you like to do?",
         (Msg::ContinueOrNew, Language::Fr) => "Une sauvegarde a \u{00e9}t\u{00e9} trouv\u{00e9}e. Que voulez-vous faire ?",
+        (Msg::ContinueOrNew, Language::De) => "Ein Spielstand wurde gefunden. Was m\u{00f6}chtest du tun?",
         (Msg::ContinueOption, Language::En) => "1. Continue",
         (Msg::ContinueOption, Language::Fr) => "1. Continuer",
+        (Msg::ContinueOption, Language::De) => "1. Weiterspielen",
         (Msg::NewGameOption, Language::En) => "2. New Game",
         (Msg::NewGameOption, Language::Fr) => "2. Nouvelle Partie",
+        (Msg::NewGameOption, Language::De) => "2. Neues Spiel",
 
         // Typing indicator
         (Msg::ElaraTyping, Language::En) => "Elara is typing",
         (Msg::ElaraTyping, Language::Fr) => "Elara \u{00e9}crit",
+        (Msg::ElaraTyping, Language::De) => "Elara schreibt",
+        (Msg::ElaraNudge, Language::En) => "You still there? ... Okay. Take your time.",
+        (Msg::ElaraNudge, Language::Fr) => "T'es toujours l\u{00e0} ? ... Ok. Prends ton temps.",
+        (Msg::ElaraNudge, Language::De) => "Bist du noch da? ... Okay. Lass dir Zeit.",
 
         // Waiting
         (Msg::ElaraUnavailable, Language::En) => "Elara is not available right now.",
         (Msg::ElaraUnavailable, Language::Fr) => "Elara n'est pas disponible pour le moment.",
+        (Msg::ElaraUnavailable, Language::De) => "Elara ist gerade nicht erreichbar.",
+        (Msg::WaitQuitHint, Language::En) => "[q] Quit and come back later",
+        (Msg::WaitQuitHint, Language::Fr) => "[q] Quitter et revenir plus tard",
+        (Msg::WaitQuitHint, Language::De) => "[q] Beenden und sp\u{00e4}ter wiederkommen",
+        (Msg::WaitSkipHint, Language::En) => "[s] Skip the wait",
+        (Msg::WaitSkipHint, Language::Fr) => "[s] Passer l'attente",
+        (Msg::WaitSkipHint, Language::De) => "[s] Warten \u{00fc}berspringen",
         (Msg::ElaraBackAround, Language::En) => "She said she'd be back around",
         (Msg::ElaraBackAround, Language::Fr) => "Elle a dit qu'elle reviendrait vers",
+        (Msg::ElaraBackAround, Language::De) => "Sie sagte, sie sei zur\u{00fc}ck gegen",
         (Msg::WaitOrQuit, Language::En) => "What would you like to do?",
         (Msg::WaitOrQuit, Language::Fr) => "Que voulez-vous faire ?",
+        (Msg::WaitOrQuit, Language::De) => "Was m\u{00f6}chtest du tun?",
         (Msg::WaitOption, Language::En) => "1. Wait",
         (Msg::WaitOption, Language::Fr) => "1. Attendre",
+        (Msg::WaitOption, Language::De) => "1. Warten",
         (Msg::QuitOption, Language::En) => "2. Quit and come back later",
         (Msg::QuitOption, Language::Fr) => "2. Quitter et revenir plus tard",
+        (Msg::QuitOption, Language::De) => "2. Beenden und sp\u{00e4}ter wiederkommen",
 
         // Signal lost (Ctrl+C)
         (Msg::SignalLost, Language::En) => "Signal lost...",
         (Msg::SignalLost, Language::Fr) => "Signal perdu...",
+        (Msg::SignalLost, Language::De) => "Signal verloren...",
 
         // Day separator
         (Msg::DaySeparator, Language::En) => "Day",
         (Msg::DaySeparator, Language::Fr) => "Jour",
+        (Msg::DaySeparator, Language::De) => "Tag",
 
         // Backlog / session
         (Msg::BacklogHeader, Language::En) => "--- Previous messages ---",
         (Msg::BacklogHeader, Language::Fr) => "--- Messages pr\u{00e9}c\u{00e9}dents ---",
+        (Msg::BacklogHeader, Language::De) => "--- Fr\u{00fc}here Nachrichten ---",
         (Msg::SessionStart, Language::En) => "Session",
         (Msg::SessionStart, Language::Fr) => "Session",
+        (Msg::SessionStart, Language::De) => "Sitzung",
 
         // Ending screen
         (Msg::EndingReached, Language::En) => "ENDING REACHED",
         (Msg::EndingReached, Language::Fr) => "FIN ATTEINTE",
+        (Msg::EndingReached, Language::De) => "ENDE ERREICHT",
         (Msg::DaysSurvived, Language::En) => "Days survived:",
         (Msg::DaysSurvived, Language::Fr) => "Jours de survie :",
+        (Msg::DaysSurvived, Language::De) => "\u{00dc}berlebte Tage:",
+        (Msg::ActivePlayTime, Language::En) => "Active play time:",
+        (Msg::ActivePlayTime, Language::Fr) => "Temps de jeu actif :",
+        (Msg::ActivePlayTime, Language::De) => "Aktive Spielzeit:",
+        (Msg::TimeConnected, Language::En) => "Time connected:",
+        (Msg::TimeConnected, Language::Fr) => "Temps de connexion :",
+        (Msg::TimeConnected, Language::De) => "Verbunden:",
+        (Msg::WaitsEndured, Language::En) => "Real-time waits endured:",
+        (Msg::WaitsEndured, Language::Fr) => "Attentes en temps r\u{00e9}el :",
+        (Msg::WaitsEndured, Language::De) => "Ertragene Wartezeiten:",
         (Msg::KeyChoices, Language::En) => "Key choices made:",
         (Msg::KeyChoices, Language::Fr) => "Choix d\u{00e9}terminants :",
+        (Msg::KeyChoices, Language::De) => "Wichtige Entscheidungen:",
+        (Msg::RouteCode, Language::En) => "Route code:",
+        (Msg::RouteCode, Language::Fr) => "Code du parcours :",
+        (Msg::RouteCode, Language::De) => "Routencode:",
+        (Msg::Epilogues, Language::En) => "Where are they now:",
+        (Msg::Epilogues, Language::Fr) => "Que sont-ils devenus :",
+        (Msg::Epilogues, Language::De) => "Was aus ihnen wurde:",
+        (Msg::EndingsDiscovered, Language::En) => "Endings discovered:",
+        (Msg::EndingsDiscovered, Language::Fr) => "Fins d\u{00e9}couvertes :",
+        (Msg::EndingsDiscovered, Language::De) => "Entdeckte Enden:",
+
+        // Quick-save slot
+        (Msg::QuickSaved, Language::En) => "Quick-saved. [F9] to return here.",
+        (Msg::QuickSaved, Language::Fr) => "Sauvegarde rapide effectu\u{00e9}e. [F9] pour revenir ici.",
+        (Msg::QuickSaved, Language::De) => "Schnellgespeichert. [F9] bringt dich hierher zur\u{00fc}ck.",
+        (Msg::BookmarkAdded, Language::En) => "Message bookmarked. [n] to jump between bookmarks.",
+        (Msg::BookmarkAdded, Language::Fr) => "Message marqu\u{00e9}. [n] pour passer d'une marque \u{00e0} l'autre.",
+        (Msg::BookmarkAdded, Language::De) => "Nachricht markiert. [n] springt zwischen den Markierungen.",
+        (Msg::BookmarkRemoved, Language::En) => "Bookmark removed.",
+        (Msg::BookmarkRemoved, Language::Fr) => "Marque retir\u{00e9}e.",
+        (Msg::BookmarkRemoved, Language::De) => "Markierung entfernt.",
+        (Msg::BookmarksNone, Language::En) => "No bookmarks yet. [b] marks the last message.",
+        (Msg::BookmarksNone, Language::Fr) => "Aucune marque. [b] marque le dernier message.",
+        (Msg::BookmarksNone, Language::De) => "Noch keine Markierungen. [b] markiert die letzte Nachricht.",
+        (Msg::BookmarksHint, Language::En) => "Bookmarks",
+        (Msg::BookmarksHint, Language::Fr) => "Marques",
+        (Msg::BookmarksHint, Language::De) => "Markierungen",
+        (Msg::SessionsHint, Language::En) => "Sessions",
+        (Msg::SessionsHint, Language::Fr) => "Sessions",
+        (Msg::SessionsHint, Language::De) => "Sitzungen",
+        (Msg::QuickLoaded, Language::En) => "Quick-save loaded.",
+        (Msg::QuickLoaded, Language::Fr) => "Sauvegarde rapide charg\u{00e9}e.",
+        (Msg::QuickLoaded, Language::De) => "Schnellspeicherstand geladen.",
+        (Msg::QuickLoadConfirm, Language::En) => {
+            "Progress since the quick-save will be lost. Press [F9] again to load it."
+        }
+        (Msg::QuickLoadConfirm, Language::Fr) => {
+            "La progression depuis la sauvegarde rapide sera perdue. Appuie encore sur [F9] pour la charger."
+        }
+        (Msg::QuickLoadConfirm, Language::De) => "Der Fortschritt seit dem Schnellspeichern geht verloren. Dr\u{00fc}ck erneut [F9], um ihn zu laden.",
+        (Msg::QuickSaveMissing, Language::En) => "No quick-save yet. [F5] to make one.",
+        (Msg::QuickSaveMissing, Language::Fr) => "Pas encore de sauvegarde rapide. [F5] pour en faire une.",
+        (Msg::QuickSaveMissing, Language::De) => "Noch kein Schnellspeicherstand. [F5] legt einen an.",
+        (Msg::QuickSaveFailed, Language::En) => "Couldn't quick-save:",
+        (Msg::QuickSaveFailed, Language::Fr) => "Sauvegarde rapide impossible :",
+        (Msg::QuickSaveFailed, Language::De) => "Schnellspeichern fehlgeschlagen:",
+        (Msg::QuickSaveUnreadable, Language::En) => "Couldn't read the quick-save:",
+        (Msg::QuickSaveUnreadable, Language::Fr) => "Impossible de lire la sauvegarde rapide :",
+        (Msg::QuickSaveUnreadable, Language::De) => "Schnellspeicherstand nicht lesbar:",
+        (Msg::EndingUnknown, _) => "???",
+        (Msg::EndingNew, Language::En) => "NEW",
+        (Msg::EndingNew, Language::Fr) => "NOUVEAU",
+        (Msg::EndingNew, Language::De) => "NEU",
         (Msg::PlayAgain, Language::En) => "Play again?",
         (Msg::PlayAgain, Language::Fr) => "Rejouer ?",
+        (Msg::PlayAgain, Language::De) => "Noch einmal spielen?",
         (Msg::YesOption, Language::En) => "1. Yes",
         (Msg::YesOption, Language::Fr) => "1. Oui",
-        (Msg::NoOption, Language::En) => "2. No",
-        (Msg::NoOption, Language::Fr) => "2. Non",
+        (Msg::YesOption, Language::De) => "1. Ja",
+        (Msg::NewGamePlusOption, Language::En) => "2. New Game+ (keep unlocks and hints)",
+        (Msg::NewGamePlusOption, Language::Fr) => "2. Nouvelle partie+ (garder bonus et indices)",
+        (Msg::NewGamePlusOption, Language::De) => "2. Neues Spiel+ (Freischaltungen und Hinweise behalten)",
+        (Msg::NoOption, Language::En) => "3. No",
+        (Msg::NoOption, Language::Fr) => "3. Non",
+        (Msg::NoOption, Language::De) => "3. Nein",
+        (Msg::ChoiceExplored, Language::En) => "(explored before)",
+        (Msg::ChoiceExplored, Language::Fr) => "(d\u{00e9}j\u{00e0} explor\u{00e9})",
+        (Msg::ChoiceExplored, Language::De) => "(schon erkundet)",
+        (Msg::ChoiceLocked, Language::En) => "locked",
+        (Msg::ChoiceLocked, Language::Fr) => "verrouill\u{00e9}",
+        (Msg::ChoiceLocked, Language::De) => "gesperrt",
 
         // Invalid input
         (Msg::InvalidChoice, Language::En) => "Invalid choice. Please try again.",
         (Msg::InvalidChoice, Language::Fr) => "Choix invalide. Veuillez r\u{00e9}essayer.",
+        (Msg::InvalidChoice, Language::De) => "Ung\u{00fc}ltige Wahl. Bitte versuch es noch einmal.",
+        (Msg::RereadChoice, Language::En) => "Re-read that",
+        (Msg::RereadChoice, Language::Fr) => "Relire \u{00e7}a",
+        (Msg::RereadChoice, Language::De) => "Nochmal lesen",
 
         // Save management
         (Msg::SaveDeleted, Language::En) => "Save file deleted. Starting fresh.",
         (Msg::SaveDeleted, Language::Fr) => "Sauvegarde supprim\u{00e9}e. Red\u{00e9}marrage.",
+        (Msg::SaveDeleted, Language::De) => "Spielstand gel\u{00f6}scht. Neustart.",
 
         // Pause menu
         (Msg::PauseMenuTitle, Language::En) => "--- MENU ---",
         (Msg::PauseMenuTitle, Language::Fr) => "--- MENU ---",
+        (Msg::PauseMenuTitle, Language::De) => "--- MEN\u{00dc} ---",
         (Msg::PauseMenuHint, Language::En) => "[Esc] Menu",
         (Msg::PauseMenuHint, Language::Fr) => "[Esc] Menu",
+        (Msg::PauseMenuHint, Language::De) => "[Esc] Men\u{00fc}",
         (Msg::MenuResume, Language::En) => "Resume",
         (Msg::MenuResume, Language::Fr) => "Reprendre",
+        (Msg::MenuResume, Language::De) => "Fortsetzen",
         (Msg::MenuLanguage, Language::En) => "Language",
         (Msg::MenuLanguage, Language::Fr) => "Langue",
+        (Msg::MenuLanguage, Language::De) => "Sprache",
         (Msg::MenuTextSpeed, Language::En) => "Text speed",
         (Msg::MenuTextSpeed, Language::Fr) => "Vitesse du texte",
+        (Msg::MenuTextSpeed, Language::De) => "Textgeschwindigkeit",
         (Msg::MenuWaitingTimes, Language::En) => "Waiting times",
         (Msg::MenuWaitingTimes, Language::Fr) => "Temps d'attente",
+        (Msg::MenuWaitingTimes, Language::De) => "Wartezeiten",
         (Msg::MenuAutomaticDialogs, Language::En) => "Automatic dialogs",
         (Msg::MenuAutomaticDialogs, Language::Fr) => "Dialogues automatiques",
+        (Msg::MenuAutomaticDialogs, Language::De) => "Automatische Dialoge",
+        (Msg::MenuFastForward, Language::En) => "Hold Space to fast-forward",
+        (Msg::MenuFastForward, Language::Fr) => "Maintenir Espace pour acc\u{00e9}l\u{00e9}rer",
+        (Msg::MenuFastForward, Language::De) => "Leertaste halten zum Vorspulen",
+        (Msg::MenuIdleTimeout, Language::En) => "Idle pause",
+        (Msg::MenuIdleTimeout, Language::Fr) => "Pause si inactif",
+        (Msg::MenuIdleTimeout, Language::De) => "Pause bei Inaktivit\u{00e4}t",
+        (Msg::MenuTypingRhythm, Language::En) => "Typing rhythm",
+        (Msg::MenuTypingRhythm, Language::Fr) => "Rythme de frappe",
+        (Msg::MenuTypingRhythm, Language::De) => "Tipprhythmus",
+        (Msg::MenuReduceMotion, Language::En) => "Reduce motion",
+        (Msg::MenuReduceMotion, Language::Fr) => "R\u{00e9}duire les animations",
+        (Msg::MenuReduceMotion, Language::De) => "Animationen reduzieren",
+        (Msg::MenuConsequenceHints, Language::En) => "Choice consequences",
+        (Msg::MenuConsequenceHints, Language::Fr) => "Cons\u{00e9}quences des choix",
+        (Msg::MenuConsequenceHints, Language::De) => "Folgen der Entscheidungen",
+        (Msg::MenuCommentary, Language::En) => "Author's commentary",
+        (Msg::MenuCommentary, Language::Fr) => "Commentaire de l'auteur",
+        (Msg::MenuCommentary, Language::De) => "Kommentar des Autors",
+        (Msg::MenuLanguagePace, Language::En) => "Text pace",
+        (Msg::MenuLanguagePace, Language::Fr) => "Rythme du texte",
+        (Msg::MenuLanguagePace, Language::De) => "Lesetempo",
+        (Msg::MenuShowStats, Language::En) => "Show stats",
+        (Msg::MenuShowStats, Language::Fr) => "Afficher les stats",
+        (Msg::MenuShowStats, Language::De) => "Werte anzeigen",
+        (Msg::MenuTrustCues, Language::En) => "Trust cues",
+        (Msg::MenuTrustCues, Language::Fr) => "Indices de confiance",
+        (Msg::MenuTrustCues, Language::De) => "Vertrauenshinweise",
+        (Msg::TrustCueUp, Language::En) => "(Elara seems to trust you a little more.)",
+        (Msg::TrustCueUp, Language::Fr) => "(Elara semble te faire un peu plus confiance.)",
+        (Msg::TrustCueUp, Language::De) => "(Elara scheint dir etwas mehr zu vertrauen.)",
+        (Msg::TrustCueDown, Language::En) => "(Elara seems a little more wary of you.)",
+        (Msg::TrustCueDown, Language::Fr) => "(Elara semble un peu plus m\u{00e9}fiante envers toi.)",
+        (Msg::TrustCueDown, Language::De) => "(Elara scheint dir gegen\u{00fc}ber etwas misstrauischer.)",
+        (Msg::HintTrust, Language::En) => "trust",
+        (Msg::HintTrust, Language::Fr) => "confiance",
+        (Msg::HintTrust, Language::De) => "Vertrauen",
+        (Msg::HintHealth, Language::En) => "health",
+        (Msg::HintHealth, Language::Fr) => "sant\u{00e9}",
+        (Msg::HintHealth, Language::De) => "Gesundheit",
+        (Msg::HintSupplies, Language::En) => "supplies",
+        (Msg::HintSupplies, Language::Fr) => "provisions",
+        (Msg::HintSupplies, Language::De) => "Vorr\u{00e4}te",
+        (Msg::HintSets, Language::En) => "sets:",
+        (Msg::HintSets, Language::Fr) => "active :",
+        (Msg::HintSets, Language::De) => "setzt:",
+        (Msg::MenuChatLayout, Language::En) => "Chat layout",
+        (Msg::MenuChatLayout, Language::Fr) => "Disposition du chat",
+        (Msg::MenuChatLayout, Language::De) => "Chat-Layout",
+        (Msg::MenuSessionMarkers, Language::En) => "Session markers",
+        (Msg::MenuSessionMarkers, Language::Fr) => "Marqueurs de session",
+        (Msg::MenuSessionMarkers, Language::De) => "Sitzungsmarken",
+        (Msg::MenuActCards, Language::En) => "Act title cards",
+        (Msg::MenuActCards, Language::Fr) => "Titres des actes",
+        (Msg::MenuActCards, Language::De) => "Akt-Titelkarten",
+        (Msg::MenuLogRetention, Language::En) => "Messages kept",
+        (Msg::MenuLogRetention, Language::Fr) => "Messages conserv\u{00e9}s",
+        (Msg::MenuLogRetention, Language::De) => "Gespeicherte Nachrichten",
+        (Msg::MenuSaveInterval, Language::En) => "Autosave gap",
+        (Msg::MenuSaveInterval, Language::Fr) => "\u{00c9}cart de sauvegarde",
+        (Msg::MenuSaveInterval, Language::De) => "Speicherabstand",
+        (Msg::MenuTheme, Language::En) => "Theme",
+        (Msg::MenuTheme, Language::Fr) => "Th\u{00e8}me",
+        (Msg::MenuTheme, Language::De) => "Design",
+        (Msg::SettingThemeClassic, Language::En) => "classic",
+        (Msg::SettingThemeClassic, Language::Fr) => "classique",
+        (Msg::SettingThemeClassic, Language::De) => "klassisch",
+        (Msg::SettingThemeSignal, Language::En) => "signal",
+        (Msg::SettingThemeSignal, Language::Fr) => "signal",
+        (Msg::SettingThemeSignal, Language::De) => "Signal",
+        (Msg::SettingThemeHighContrast, Language::En) => "high contrast",
+        (Msg::SettingThemeHighContrast, Language::Fr) => "contraste \u{00e9}lev\u{00e9}",
+        (Msg::SettingThemeHighContrast, Language::De) => "hoher Kontrast",
+        (Msg::SettingThemeMonochrome, Language::En) => "monochrome",
+        (Msg::SettingThemeMonochrome, Language::Fr) => "monochrome",
+        (Msg::SettingThemeMonochrome, Language::De) => "einfarbig",
+        (Msg::MenuIdleNudge, Language::En) => "Elara checks in",
+        (Msg::MenuIdleNudge, Language::Fr) => "Elara s'inqui\u{00e8}te",
+        (Msg::MenuIdleNudge, Language::De) => "Elara meldet sich",
+        (Msg::MenuMinReadTime, Language::En) => "Read delay",
+        (Msg::MenuMinReadTime, Language::Fr) => "D\u{00e9}lai de lecture",
+        (Msg::MenuMinReadTime, Language::De) => "Lesepause",
+        (Msg::MenuGlossary, Language::En) => "Glossary",
+        (Msg::MenuGlossary, Language::Fr) => "Glossaire",
+        (Msg::MenuGlossary, Language::De) => "Glossar",
+        (Msg::MenuRecap, Language::En) => "Story recap",
+        (Msg::MenuRecap, Language::Fr) => "R\u{00e9}sum\u{00e9} de l'histoire",
+        (Msg::MenuRecap, Language::De) => "Bisherige Geschichte",
+        (Msg::RecapLastChoice, Language::En) => "Your last reply:",
+        (Msg::RecapLastChoice, Language::Fr) => "Ta derni\u{00e8}re r\u{00e9}ponse :",
+        (Msg::RecapLastChoice, Language::De) => "Deine letzte Antwort:",
+        (Msg::RecapNothingYet, Language::En) => "Nothing has happened yet.",
+        (Msg::RecapNothingYet, Language::Fr) => "Rien ne s'est encore pass\u{00e9}.",
+        (Msg::RecapNothingYet, Language::De) => "Bisher ist noch nichts passiert.",
+        (Msg::GlossaryLocked, Language::En) => "???",
+        (Msg::GlossaryLocked, Language::Fr) => "???",
+        (Msg::GlossaryLocked, Language::De) => "???",
+        (Msg::GlossaryUnlocked, Language::En) => "New glossary entry:",
+        (Msg::GlossaryUnlocked, Language::Fr) => "Nouvelle entr\u{00e9}e du glossaire :",
+        (Msg::GlossaryUnlocked, Language::De) => "Neuer Glossareintrag:",
+        (Msg::CheckpointReached, Language::En) => "Checkpoint:",
+        (Msg::CheckpointReached, Language::Fr) => "Point de reprise :",
+        (Msg::CheckpointReached, Language::De) => "Kontrollpunkt:",
+        (Msg::CheckpointRestored, Language::En) => "Back at checkpoint:",
+        (Msg::CheckpointRestored, Language::Fr) => "Retour au point de reprise :",
+        (Msg::CheckpointRestored, Language::De) => "Zur\u{00fc}ck am Kontrollpunkt:",
+        (Msg::Rewound, Language::En) => "Rewound to your last choice.",
+        (Msg::Rewound, Language::Fr) => "Retour \u{00e0} ton dernier choix.",
+        (Msg::Rewound, Language::De) => "Zur\u{00fc}ck zu deiner letzten Entscheidung.",
+        (Msg::RewindNothing, Language::En) => "There is no choice to rewind.",
+        (Msg::RewindNothing, Language::Fr) => "Il n'y a aucun choix \u{00e0} annuler.",
+        (Msg::RewindNothing, Language::De) => "Es gibt keine Entscheidung zum Zur\u{00fc}cknehmen.",
+        (Msg::RewindBlockedByWait, Language::En) => {
+            "Time has passed since that choice; it can't be rewound."
+        }
+        (Msg::RewindBlockedByWait, Language::Fr) => {
+            "Du temps a pass\u{00e9} depuis ce choix ; il ne peut plus \u{00ea}tre annul\u{00e9}."
+        }
+        (Msg::RewindBlockedByWait, Language::De) => "Seit dieser Entscheidung ist Zeit vergangen; sie l\u{00e4}sst sich nicht mehr zur\u{00fc}cknehmen.",
+        (Msg::CheckpointOption, Language::En) => "4. Replay from the last checkpoint",
+        (Msg::CheckpointOption, Language::Fr) => "4. Reprendre au dernier point de reprise",
+        (Msg::CheckpointOption, Language::De) => "4. Ab dem letzten Kontrollpunkt weiterspielen",
+        (Msg::StoryNodeMissing, Language::En) => "The story can't go on from here. Missing node:",
+        (Msg::StoryNodeMissing, Language::Fr) => {
+            "L'histoire ne peut pas continuer. N\u{0153}ud introuvable :"
+        }
+        (Msg::StoryNodeMissing, Language::De) => "Die Geschichte kann hier nicht weitergehen. Fehlender Knoten:",
+        (Msg::SlotSelectPrompt, Language::En) => "Which save do you want to play?",
+        (Msg::SlotSelectPrompt, Language::Fr) => "Quelle sauvegarde veux-tu jouer ?",
+        (Msg::SlotSelectPrompt, Language::De) => "Welchen Spielstand m\u{00f6}chtest du spielen?",
+        (Msg::SaveSlot, Language::En) => "Slot",
+        (Msg::SaveSlot, Language::Fr) => "Emplacement",
+        (Msg::SaveSlot, Language::De) => "Platz",
+        (Msg::SlotNewGame, Language::En) => "New game in slot",
+        (Msg::SlotNewGame, Language::Fr) => "Nouvelle partie dans l'emplacement",
+        (Msg::SlotNewGame, Language::De) => "Neues Spiel auf Platz",
+        (Msg::StoryErrorCheckpoint, Language::En) => "Return to the last checkpoint",
+        (Msg::StoryErrorCheckpoint, Language::Fr) => "Revenir au dernier point de reprise",
+        (Msg::StoryErrorCheckpoint, Language::De) => "Zum letzten Kontrollpunkt zur\u{00fc}ckkehren",
+        (Msg::StoryErrorNewGame, Language::En) => "Start a new game",
+        (Msg::StoryErrorNewGame, Language::Fr) => "Commencer une nouvelle partie",
+        (Msg::StoryErrorNewGame, Language::De) => "Ein neues Spiel beginnen",
+        (Msg::MenuRewind, Language::En) => "Rewind last choice",
+        (Msg::MenuRewind, Language::Fr) => "Annuler le dernier choix",
+        (Msg::MenuRewind, Language::De) => "Letzte Entscheidung zur\u{00fc}cknehmen",
+        (Msg::MenuExportTranscript, Language::En) => "Export transcript",
+        (Msg::MenuExportTranscript, Language::Fr) => "Exporter la transcription",
+        (Msg::MenuExportTranscript, Language::De) => "Protokoll exportieren",
+        (Msg::TranscriptExported, Language::En) => "Transcript saved to",
+        (Msg::TranscriptExported, Language::Fr) => "Transcription enregistr\u{00e9}e dans",
+        (Msg::TranscriptExported, Language::De) => "Protokoll gespeichert unter",
+        (Msg::TranscriptFailed, Language::En) => "Couldn't save the transcript:",
+        (Msg::TranscriptFailed, Language::Fr) => "Impossible d'enregistrer la transcription :",
+        (Msg::TranscriptFailed, Language::De) => "Protokoll konnte nicht gespeichert werden:",
+        (Msg::TranscriptTitle, Language::En) => "Eshara \u{2014} transcript",
+        (Msg::TranscriptTitle, Language::Fr) => "Eshara \u{2014} transcription",
+        (Msg::TranscriptTitle, Language::De) => "Eshara \u{2014} Protokoll",
+        (Msg::TranscriptPlayer, Language::En) => "You",
+        (Msg::TranscriptPlayer, Language::Fr) => "Toi",
+        (Msg::TranscriptPlayer, Language::De) => "Du",
+        (Msg::TranscriptTrimmed, Language::En) => {
+            "Older messages were trimmed from this save and are not included."
+        }
+        (Msg::TranscriptTrimmed, Language::Fr) => {
+            "Les messages les plus anciens ont \u{00e9}t\u{00e9} retir\u{00e9}s de cette sauvegarde et ne figurent pas ici."
+        }
+        (Msg::TranscriptTrimmed, Language::De) => {
+            "\u{00c4}ltere Nachrichten wurden aus diesem Spielstand entfernt und fehlen hier."
+        }
+        (Msg::MenuReturnToCheckpoint, Language::En) => "Return to last checkpoint",
+        (Msg::MenuReturnToCheckpoint, Language::Fr) => "Revenir au point de reprise",
+        (Msg::MenuReturnToCheckpoint, Language::De) => "Zum letzten Kontrollpunkt zur\u{00fc}ck",
+        (Msg::MenuSavedRuns, Language::En) => "Saved runs",
+        (Msg::MenuSavedRuns, Language::Fr) => "Parties enregistr\u{00e9}es",
+        (Msg::MenuSavedRuns, Language::De) => "Gespeicherte Durchl\u{00e4}ufe",
+        (Msg::SavedRunsNew, Language::En) => "+ Save this run as:",
+        (Msg::SavedRunsNew, Language::Fr) => "+ Enregistrer cette partie sous :",
+        (Msg::SavedRunsNew, Language::De) => "+ Diesen Durchlauf speichern als:",
+        (Msg::SavedRunsHint, Language::En) => "[Enter] Load  [d] Delete  [Esc] Back",
+        (Msg::SavedRunsHint, Language::Fr) => "[Enter] Charger  [d] Supprimer  [Esc] Retour",
+        (Msg::SavedRunsHint, Language::De) => "[Enter] Laden  [d] L\u{00f6}schen  [Esc] Zur\u{00fc}ck",
+        (Msg::SavedRunsNameHint, Language::En) => "Type a name, [Enter] to save, [Esc] to cancel",
+        (Msg::SavedRunsNameHint, Language::Fr) => {
+            "Tape un nom, [Enter] pour enregistrer, [Esc] pour annuler"
+        }
+        (Msg::SavedRunsNameHint, Language::De) => "Namen eingeben, [Enter] zum Speichern, [Esc] zum Abbrechen",
+        (Msg::SavedRunsFull, Language::En) => "Every slot is taken. Delete a run with [d] first.",
+        (Msg::SavedRunsFull, Language::Fr) => {
+            "Tous les emplacements sont pris. Supprime d'abord une partie avec [d]."
+        }
+        (Msg::SavedRunsFull, Language::De) => "Alle Pl\u{00e4}tze sind belegt. L\u{00f6}sch zuerst einen Durchlauf mit [d].",
+        (Msg::SavedRunStored, Language::En) => "Run saved as",
+        (Msg::SavedRunStored, Language::Fr) => "Partie enregistr\u{00e9}e sous",
+        (Msg::SavedRunStored, Language::De) => "Durchlauf gespeichert als",
+        (Msg::SavedRunDeleted, Language::En) => "Deleted:",
+        (Msg::SavedRunDeleted, Language::Fr) => "Supprim\u{00e9}e :",
+        (Msg::SavedRunDeleted, Language::De) => "Gel\u{00f6}scht:",
+        (Msg::SavedRunRestored, Language::En) => "Back in saved run:",
+        (Msg::SavedRunRestored, Language::Fr) => "Retour \u{00e0} la partie enregistr\u{00e9}e :",
+        (Msg::SavedRunRestored, Language::De) => "Zur\u{00fc}ck im gespeicherten Durchlauf:",
+        (Msg::SavedRunsError, Language::En) => "Couldn't update the saved runs:",
+        (Msg::SavedRunsError, Language::Fr) => {
+            "Impossible de mettre \u{00e0} jour les parties enregistr\u{00e9}es :"
+        }
+        (Msg::SavedRunsError, Language::De) => {
+            "Gespeicherte Durchl\u{00e4}ufe konnten nicht aktualisiert werden:"
+        }
+        (Msg::SavedRunsUnreadable, Language::En) => {
+            "Couldn't read the saved runs, so they won't be changed:"
+        }
+        (Msg::SavedRunsUnreadable, Language::Fr) => {
+            "Impossible de lire les parties enregistr\u{00e9}es, elles ne seront pas modifi\u{00e9}es :"
+        }
+        (Msg::SavedRunsUnreadable, Language::De) => {
+            "Gespeicherte Durchl\u{00e4}ufe nicht lesbar, sie bleiben unver\u{00e4}ndert:"
+        }
+        (Msg::GlossaryEmpty, Language::En) => "No entries yet.",
+        (Msg::GlossaryEmpty, Language::Fr) => "Aucune entr\u{00e9}e pour l'instant.",
+        (Msg::GlossaryEmpty, Language::De) => "Noch keine Eintr\u{00e4}ge.",
+        (Msg::ExploreHint, Language::En) => "[E] Explore other choices",
+        (Msg::ExploreHint, Language::Fr) => "[E] Explorer les autres choix",
+        (Msg::ExploreHint, Language::De) => "[E] Andere Entscheidungen ansehen",
+        (Msg::ExploreTitle, Language::En) => "Other choices",
+        (Msg::ExploreTitle, Language::Fr) => "Autres choix",
+        (Msg::ExploreTitle, Language::De) => "Andere Entscheidungen",
+        (Msg::ExploreNotCanon, Language::En) => {
+            "What might have been. None of this happened in your run."
+        }
+        (Msg::ExploreNotCanon, Language::Fr) => {
+            "Ce qui aurait pu \u{00ea}tre. Rien de tout cela n'est arriv\u{00e9} dans ta partie."
+        }
+        (Msg::ExploreNotCanon, Language::De) => "Was h\u{00e4}tte sein k\u{00f6}nnen. Nichts davon ist in deinem Durchlauf passiert.",
+        (Msg::ExploreYouChose, Language::En) => "You chose:",
+        (Msg::ExploreYouChose, Language::Fr) => "Tu as choisi :",
+        (Msg::ExploreYouChose, Language::De) => "Du hast gew\u{00e4}hlt:",
+        (Msg::ExploreNoAlternatives, Language::En) => "There was no other choice here.",
+        (Msg::ExploreNoAlternatives, Language::Fr) => "Il n'y avait pas d'autre choix ici.",
+        (Msg::ExploreNoAlternatives, Language::De) => "Hier gab es keine andere Wahl.",
+        (Msg::MenuReplayIntro, Language::En) => "Replay intro",
+        (Msg::MenuReplayIntro, Language::Fr) => "Revoir l'introduction",
+        (Msg::MenuReplayIntro, Language::De) => "Intro erneut abspielen",
         (Msg::MenuValidate, Language::En) => "Validate",
         (Msg::MenuValidate, Language::Fr) => "Valider",
+        (Msg::MenuValidate, Language::De) => "\u{00dc}bernehmen",
         (Msg::MenuSaveQuit, Language::En) => "Save & Quit",
         (Msg::MenuSaveQuit, Language::Fr) => "Sauvegarder & Quitter",
+        (Msg::MenuSaveQuit, Language::De) => "Speichern & Beenden",
         (Msg::SettingEnabled, Language::En) => "enabled",
         (Msg::SettingEnabled, Language::Fr) => "activé",
+        (Msg::SettingEnabled, Language::De) => "an",
         (Msg::SettingDisabled, Language::En) => "disabled",
         (Msg::SettingDisabled, Language::Fr) => "désactivé",
+        (Msg::SettingDisabled, Language::De) => "aus",
+        (Msg::SettingOff, Language::En) => "off",
+        (Msg::SettingOff, Language::Fr) => "non",
+        (Msg::SettingOff, Language::De) => "aus",
+        (Msg::SettingAll, Language::En) => "all",
+        (Msg::SettingAll, Language::Fr) => "tous",
+        (Msg::SettingAll, Language::De) => "alle",
+        (Msg::SettingEveryMessage, Language::En) => "every message",
+        (Msg::SettingEveryMessage, Language::Fr) => "chaque message",
+        (Msg::SettingEveryMessage, Language::De) => "jede Nachricht",
+        (Msg::SettingLayoutInline, Language::En) => "inline",
+        (Msg::SettingLayoutInline, Language::Fr) => "en ligne",
+        (Msg::SettingLayoutInline, Language::De) => "fortlaufend",
+        (Msg::SettingLayoutGutter, Language::En) => "gutter",
+        (Msg::SettingLayoutGutter, Language::Fr) => "colonne",
+        (Msg::SettingLayoutGutter, Language::De) => "Randspalte",
+        (Msg::SettingMarkersCollapsed, Language::En) => "collapsed",
+        (Msg::SettingMarkersCollapsed, Language::Fr) => "regroup\u{00e9}s",
+        (Msg::SettingMarkersCollapsed, Language::De) => "zusammengefasst",
+        (Msg::SettingMarkersHidden, Language::En) => "hidden",
+        (Msg::SettingMarkersHidden, Language::Fr) => "masqu\u{00e9}s",
+        (Msg::SettingMarkersHidden, Language::De) => "ausgeblendet",
+        (Msg::SessionResumed, Language::En) => "(resumed)",
+        (Msg::SessionResumed, Language::Fr) => "(reprise)",
+        (Msg::SessionResumed, Language::De) => "(fortgesetzt)",
+        (Msg::GutterPlayer, Language::En) => "You",
+        (Msg::GutterPlayer, Language::Fr) => "Toi",
+        (Msg::GutterPlayer, Language::De) => "Du",
+        (Msg::ActProgress, Language::En) => "Act",
+        (Msg::ActProgress, Language::Fr) => "Acte",
+        (Msg::ActProgress, Language::De) => "Akt",
+        (Msg::ActCardSkip, Language::En) => "Press any key to continue",
+        (Msg::ActCardSkip, Language::Fr) => "Appuie sur une touche pour continuer",
+        (Msg::ActCardSkip, Language::De) => "Beliebige Taste dr\u{00fc}cken, um fortzufahren",
+        (Msg::MoreContent, Language::En) => "more",
+        (Msg::MoreContent, Language::Fr) => "suite",
+        (Msg::MoreContent, Language::De) => "mehr",
+        (Msg::NewMessagesBelow, Language::En) => "\u{2193} new messages",
+        (Msg::NewMessagesBelow, Language::Fr) => "\u{2193} nouveaux messages",
+        (Msg::NewMessagesBelow, Language::De) => "\u{2193} neue Nachrichten",
+        (Msg::SettingSpeedSlow, Language::En) => "slow",
+        (Msg::SettingSpeedSlow, Language::Fr) => "lent",
+        (Msg::SettingSpeedSlow, Language::De) => "langsam",
         (Msg::SettingSpeedNormal, Language::En) => "normal",
         (Msg::SettingSpeedNormal, Language::Fr) => "normal",
+        (Msg::SettingSpeedNormal, Language::De) => "normal",
         (Msg::SettingSpeedFast, Language::En) => "fast",
         (Msg::SettingSpeedFast, Language::Fr) => "rapide",
+        (Msg::SettingSpeedFast, Language::De) => "schnell",
         (Msg::SettingSpeedInstant, Language::En) => "instant",
         (Msg::SettingSpeedInstant, Language::Fr) => "instantané",
+        (Msg::SettingSpeedInstant, Language::De) => "sofort",
         (Msg::SettingLangEn, Language::En) => "en",
         (Msg::SettingLangEn, Language::Fr) => "en",
+        (Msg::SettingLangEn, Language::De) => "en",
         (Msg::SettingLangFr, Language::En) => "fr",
         (Msg::SettingLangFr, Language::Fr) => "fr",
+        (Msg::SettingLangFr, Language::De) => "fr",
+        (Msg::SettingLangDe, _) => "de",
         (Msg::LanguageSwitched, Language::En) => "Language changed to English.",
         (Msg::LanguageSwitched, Language::Fr) => "Langue chang\u{00e9}e en fran\u{00e7}ais.",
+        (Msg::LanguageSwitched, Language::De) => "Sprache auf Deutsch umgestellt.",
         (Msg::SavedAndQuit, Language::En) => "Game saved. See you soon.",
         (Msg::SavedAndQuit, Language::Fr) => "Partie sauvegard\u{00e9}e. \u{00c0} bient\u{00f4}t.",
+        (Msg::SavedAndQuit, Language::De) => "Spiel gespeichert. Bis bald.",
+
+        // Layout
+        (Msg::TerminalTooSmall, Language::En) => "Terminal too small. Please enlarge the window.",
+        (Msg::TerminalTooSmall, Language::Fr) => "Terminal trop petit. Agrandissez la fen\u{00ea}tre.",
+        (Msg::TerminalTooSmall, Language::De) => "Terminal zu klein. Bitte vergr\u{00f6}\u{00df}ere das Fenster.",
+        (Msg::TerminalNotInteractive, Language::En) => {
+            "The full-screen interface needs an interactive terminal."
+        }
+        (Msg::TerminalNotInteractive, Language::Fr) => {
+            "L'interface plein \u{00e9}cran a besoin d'un terminal interactif."
+        }
+        (Msg::TerminalNotInteractive, Language::De) => "Die Vollbildoberfl\u{00e4}che braucht ein interaktives Terminal.",
+        (Msg::TerminalTryClassic, Language::En) => {
+            "Try `eshara --ui classic`, or `--stdin-choices` to pipe choices in."
+        }
+        (Msg::TerminalTryClassic, Language::Fr) => {
+            "Essayez `eshara --ui classic`, ou `--stdin-choices` pour envoyer les choix par un tube."
+        }
+        (Msg::TerminalTryClassic, Language::De) => "Versuch es mit `eshara --ui classic` oder mit `--stdin-choices`, um Entscheidungen per Pipe zu \u{00fc}bergeben.",
+        (Msg::ConfirmChoiceYes, Language::En) => "Do it",
+        (Msg::ConfirmChoiceYes, Language::Fr) => "Confirmer",
+        (Msg::ConfirmChoiceYes, Language::De) => "Mach es",
+        (Msg::ConfirmChoiceBack, Language::En) => "Go back",
+        (Msg::ConfirmChoiceBack, Language::Fr) => "Revenir",
+        (Msg::ConfirmChoiceBack, Language::De) => "Zur\u{00fc}ck",
+        (Msg::StoryDeadEnd, Language::En) => {
+            "The signal cuts out. The story has nowhere to go from here \u{2014} press Esc to save and quit."
+        }
+        (Msg::StoryDeadEnd, Language::Fr) => {
+            "Le signal se coupe. L'histoire ne m\u{00e8}ne nulle part d'ici \u{2014} appuyez sur \u{c9}chap pour sauvegarder et quitter."
+        }
+        (Msg::StoryDeadEnd, Language::De) => "Das Signal bricht ab. Die Geschichte f\u{00fc}hrt von hier nirgendwohin \u{2014} dr\u{00fc}ck Esc, um zu speichern und zu beenden.",
 
         // Intro
         (Msg::IntroRadioCrackle, Language::En) => {
@@ -192,6 +983,7 @@ pub fn sys_msg(key: Msg, lang: Language) -> &'static str {
         (Msg::IntroRadioCrackle, Language::Fr) => {
             "* krrzzz... krrzzz... *\n\nUn faible signal perce \u{00e0} travers le gr\u{00e9}sillement.\nQuelqu'un essaie de vous joindre."
         }
+        (Msg::IntroRadioCrackle, Language::De) => "* krrzzz... krrzzz... *\n\nEin schwaches Signal dringt durch das Rauschen.\nJemand versucht, dich zu erreichen.",
     }
 }
 
@@ -200,6 +992,7 @@ pub fn parse_language(s: &str) -> Option<Language> {
     match s.to_lowercase().as_str() {
         "en" | "english" => Some(Language::En),
         "fr" | "french" | "français" | "francais" => Some(Language::Fr),
+        "de" | "german" | "deutsch" => Some(Language::De),
         _ => None,
     }
 }
@@ -215,6 +1008,32 @@ mod tests {
         assert_eq!(s.get(Language::Fr), "Bonjour");
     }
 
+    #[test]
+    fn test_localized_string_empty_falls_back_to_english() {
+        let s = LocalizedString::new("Hello", "");
+        assert_eq!(s.get(Language::Fr), "Hello");
+        assert!(s.is_missing(Language::Fr));
+        assert!(!s.is_missing(Language::En));
+    }
+
+    #[test]
+    fn test_german_is_optional_in_story_json() {
+        let s: LocalizedString =
+            serde_json::from_str(r#"{"en": "Hello", "fr": "Bonjour"}"#).unwrap();
+        assert_eq!(s.get(Language::De), "Hello");
+        assert!(s.is_missing(Language::De));
+        // Untranslated strings serialize as before
+        assert_eq!(
+            serde_json::to_string(&s).unwrap(),
+            r#"{"en":"Hello","fr":"Bonjour"}"#
+        );
+
+        let s: LocalizedString =
+            serde_json::from_str(r#"{"en": "Hello", "fr": "Bonjour", "de": "Hallo"}"#).unwrap();
+        assert_eq!(s.get(Language::De), "Hallo");
+        assert_eq!(s.get(Language::Fr), "Bonjour");
+    }
+
     #[test]
     fn test_language_serialization() {
         let lang = Language::En;
@@ -231,6 +1050,11 @@ mod tests {
         assert_eq!(msg, "Elara is typing");
         let msg = sys_msg(Msg::ElaraTyping, Language::Fr);
         assert_eq!(msg, "Elara \u{00e9}crit");
+        let msg = sys_msg(Msg::ElaraTyping, Language::De);
+        assert_eq!(msg, "Elara schreibt");
+        for &key in Msg::ALL {
+            assert!(!sys_msg(key, Language::De).is_empty(), "{:?}", key);
+        }
     }
 
     #[test]
@@ -239,6 +1063,8 @@ mod tests {
         assert_eq!(parse_language("EN"), Some(Language::En));
         assert_eq!(parse_language("fr"), Some(Language::Fr));
         assert_eq!(parse_language("français"), Some(Language::Fr));
+        assert_eq!(parse_language("de"), Some(Language::De));
+        assert_eq!(parse_language("Deutsch"), Some(Language::De));
         assert_eq!(parse_language("invalid"), None);
     }
 
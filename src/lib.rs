@@ -1,5 +1,8 @@
 pub mod game;
 pub mod i18n;
+pub mod line;
+pub mod logging;
+pub mod route;
 pub mod story;
 pub mod time;
 pub mod tui;
@@ -9,9 +12,10 @@ use std::sync::atomic::{AtomicBool, Ordering};
 /// Global flag set by the Ctrl+C handler
 static INTERRUPTED: AtomicBool = AtomicBool::new(false);
 
-/// Mark the process as interrupted (called from Ctrl+C handler)
-pub fn set_interrupted() {
-    INTERRUPTED.store(true, Ordering::Relaxed);
+/// Mark the process as interrupted (called from Ctrl+C handler).
+/// Returns true if it already was, i.e. this is a second Ctrl+C.
+pub fn set_interrupted() -> bool {
+    INTERRUPTED.swap(true, Ordering::Relaxed)
 }
 
 /// Check if Ctrl+C was pressed (used by tui::run)
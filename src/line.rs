@@ -0,0 +1,485 @@
+//! Classic line-based frontend (`--ui classic`, or `--stdin-choices` for scripts).
+//!
+//! Messages are printed as plain lines and each decision reads a numbered
+//! answer from the input stream. The story is driven through the same `App`
+//! node logic as the TUI, so routing, effects and saves behave identically;
+//! only the presentation differs.
+
+use std::io::{self, BufRead, Write};
+
+use crate::i18n::{sys_msg, Msg};
+use crate::tui::{App, ChatEntry, Overlay, Screen};
+
+/// Drive the game from `input` until an ending, a real-time wait, or EOF.
+pub fn run<R: BufRead, W: Write>(app: &mut App, mut input: R, out: &mut W) -> io::Result<()> {
+    if app.screen == Screen::SlotSelect {
+        writeln!(out, "{}", sys_msg(Msg::SlotSelectPrompt, app.lang()))?;
+        let options = app.prompt_options.clone();
+        let Some(index) = read_choice(app, &options, &mut input, out)? else {
+            return out.flush();
+        };
+        app.choose_save_slot(index);
+    }
+
+    let lang = app.lang();
+    // The backlog was already shown in a previous session
+    let mut printed = app.chat.len();
+
+    match app.screen {
+        Screen::LanguageSelect | Screen::Intro => {
+            writeln!(out, "{}", sys_msg(Msg::IntroRadioCrackle, lang))?;
+            writeln!(out)?;
+            app.finish_intro();
+        }
+        _ => {
+            app.screen = Screen::Game;
+        }
+    }
+
+    loop {
+        if crate::is_interrupted() {
+            app.save_now();
+            break;
+        }
+
+        if crate::time::is_waiting(&app.game_state) {
+            print_entries(app, out, printed)?;
+            writeln!(out, "{}", sys_msg(Msg::ElaraUnavailable, app.lang()))?;
+            break;
+        }
+
+        if app.advance_story {
+            app.process_current_node();
+            printed = print_act_card(app, out, printed)?;
+        }
+        app.flush_messages();
+        printed = print_entries(app, out, printed)?;
+
+        if app.should_quit {
+            break;
+        }
+
+        if app.screen == Screen::StoryError {
+            writeln!(
+                out,
+                "{} '{}'",
+                sys_msg(Msg::StoryNodeMissing, app.lang()),
+                app.game_state.current_node
+            )?;
+            let options = app.prompt_options.clone();
+            let Some(index) = read_choice(app, &options, &mut input, out)? else {
+                break;
+            };
+            app.resolve_story_error(index);
+            if app.screen == Screen::LanguageSelect {
+                // A new game here keeps the language and skips the prompt
+                app.game_state.language = lang;
+                app.finish_intro();
+            }
+            // A restored checkpoint brings back history already printed
+            printed = app.chat.len();
+            continue;
+        }
+
+        if app.screen == Screen::Ending {
+            print_ending(app, out)?;
+            if app.persist {
+                let _ = crate::game::delete_save_slot(app.save_slot);
+            }
+            break;
+        }
+
+        if app.advance_story {
+            continue;
+        }
+
+        if app.choices.is_empty() {
+            break;
+        }
+
+        // Nothing moves while the prompt waits, so write what was held back
+        app.flush_pending_save();
+        let choices = app.choices.clone();
+        match read_choice(app, &choices, &mut input, out)? {
+            Some(index) => {
+                // A locked choice says why and asks again
+                if let Some(hint) = app.locked_choice_hint(index) {
+                    writeln!(out, "[{}]", hint)?;
+                    continue;
+                }
+                app.choice_index = index;
+                app.select_choice();
+                if app.overlay == Overlay::ConfirmChoice {
+                    confirm(app, &mut input, out)?;
+                }
+            }
+            None => {
+                // EOF — leave cleanly so the run can be resumed later
+                app.save_now();
+                writeln!(out, "{}", sys_msg(Msg::SavedAndQuit, app.lang()))?;
+                break;
+            }
+        }
+    }
+
+    app.flush_pending_save();
+    out.flush()
+}
+
+/// Replay a shared route from the start node, printing the run the same way
+/// as `run`. Waits are skipped and nothing is saved. Fails if the choices
+/// don't fit the story or run out before an ending.
+pub fn replay<W: Write>(app: &mut App, choices: &[usize], out: &mut W) -> io::Result<()> {
+    let mismatch = |msg: String| io::Error::other(format!("route code does not fit: {}", msg));
+    app.persist = false;
+    app.screen = Screen::Game;
+    app.advance_story = true;
+    let mut printed = app.chat.len();
+    let mut steps = choices.iter();
+
+    loop {
+        if app.game_state.waiting_until.take().is_some() {
+            app.wait_message = None;
+            app.advance_story = true;
+        }
+        if app.advance_story {
+            app.process_current_node();
+            printed = print_act_card(app, out, printed)?;
+        }
+        app.flush_messages();
+        printed = print_entries(app, out, printed)?;
+
+        if app.screen == Screen::StoryError {
+            return Err(mismatch(format!(
+                "the story broke off at '{}'",
+                app.game_state.current_node
+            )));
+        }
+        if app.screen == Screen::Ending {
+            print_ending(app, out)?;
+            if steps.next().is_some() {
+                return Err(mismatch("it continues past the ending".to_string()));
+            }
+            return out.flush();
+        }
+        if app.advance_story {
+            continue;
+        }
+
+        let node_id = app.game_state.current_node.clone();
+        let Some(&choice) = steps.next() else {
+            return Err(mismatch(format!(
+                "it stops at '{}' before an ending",
+                node_id
+            )));
+        };
+        if !app.take_choice(choice) {
+            return Err(mismatch(format!(
+                "'{}' has no choice {}",
+                node_id,
+                choice + 1
+            )));
+        }
+    }
+}
+
+/// Print the act title card the story just put up after the lines before it,
+/// then take it down: there is nothing to dismiss between plain lines.
+/// Returns the new printed count.
+fn print_act_card<W: Write>(app: &mut App, out: &mut W, from: usize) -> io::Result<usize> {
+    if app.overlay != Overlay::ActCard {
+        return Ok(from);
+    }
+    let printed = print_entries(app, out, from)?;
+    if let Some((heading, title)) = app.act_card_text() {
+        writeln!(out)?;
+        writeln!(out, "=== {}: {} ===", heading, title)?;
+        writeln!(out)?;
+    }
+    app.close_act_card();
+    Ok(printed)
+}
+
+/// Print chat entries added since `from`; returns the new printed count.
+fn print_entries<W: Write>(app: &App, out: &mut W, from: usize) -> io::Result<usize> {
+    for entry in app.chat.iter().skip(from) {
+        match entry {
+            ChatEntry::Elara(text) => writeln!(out, "Elara: {}", text)?,
+            ChatEntry::Player(text) => writeln!(out, "> {}", text)?,
+            ChatEntry::System(text) => writeln!(out, "{}", text)?,
+            ChatEntry::Separator(label) => writeln!(out, "--- {} ---", label)?,
+            ChatEntry::Commentary(note) if app.game_state.settings.commentary => {
+                writeln!(out, "  ({})", note)?
+            }
+            ChatEntry::Commentary(_) => {}
+        }
+    }
+    Ok(app.chat.len())
+}
+
+/// Print the numbered options and read answers until one is valid.
+/// Returns the zero-based index, or None on EOF.
+fn read_choice<R: BufRead, W: Write>(
+    app: &App,
+    options: &[String],
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<Option<usize>> {
+    for (i, label) in options.iter().enumerate() {
+        writeln!(out, "{}. {}", i + 1, label)?;
+    }
+
+    out.flush()?;
+
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        match line.trim().parse::<usize>() {
+            Ok(n) if (1..=options.len()).contains(&n) => return Ok(Some(n - 1)),
+            _ => writeln!(out, "{}", sys_msg(Msg::InvalidChoice, app.lang()))?,
+        }
+    }
+}
+
+/// Ask the pending choice's confirmation prompt; anything but "y" goes back.
+fn confirm<R: BufRead, W: Write>(app: &mut App, input: &mut R, out: &mut W) -> io::Result<()> {
+    writeln!(out, "{} [y/n]", app.confirm_prompt().unwrap_or_default())?;
+    out.flush()?;
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    if line.trim().eq_ignore_ascii_case("y") {
+        app.confirm_choice();
+    } else {
+        app.cancel_choice_confirm();
+    }
+    Ok(())
+}
+
+fn print_ending<W: Write>(app: &App, out: &mut W) -> io::Result<()> {
+    let lang = app.lang();
+    let (title, description) = app
+        .ending_reached
+        .as_deref()
+        .map(|key| app.story_data.ending_text(key, lang))
+        .unwrap_or_default();
+
+    writeln!(out)?;
+    writeln!(out, "{} {}", sys_msg(Msg::EndingReached, lang), title)?;
+    if let Some(description) = description {
+        writeln!(out, "{}", description)?;
+    }
+    writeln!(
+        out,
+        "{} {}",
+        sys_msg(Msg::DaysSurvived, lang),
+        app.game_state.day
+    )?;
+    for (label, seconds) in [
+        (Msg::TimeConnected, app.game_state.total_playtime_secs),
+        (Msg::ActivePlayTime, app.game_state.active_play_ms / 1000),
+        (Msg::WaitsEndured, app.game_state.waited_secs),
+    ] {
+        writeln!(
+            out,
+            "{} {}",
+            sys_msg(label, lang),
+            crate::time::format_duration(seconds)
+        )?;
+    }
+    if let Some(code) = crate::route::encode(&app.story_data, &app.game_state.decision_path) {
+        writeln!(out, "{} {}", sys_msg(Msg::RouteCode, lang), code)?;
+    }
+    writeln!(
+        out,
+        "{} {}/{}",
+        sys_msg(Msg::EndingsDiscovered, lang),
+        app.ledger.discovered(app.story_data.endings.keys()),
+        app.story_data.endings.len()
+    )?;
+
+    let epilogues = app.story_data.epilogues_for(&app.game_state);
+    if !epilogues.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "{}", sys_msg(Msg::Epilogues, lang))?;
+        for (name, text) in epilogues {
+            let text = app.story_data.localized(text, lang);
+            writeln!(out, "  {}: {}", name.get(lang), text)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameState;
+    use crate::i18n::Language;
+    use crate::story::StoryData;
+
+    const STORY: &str = r#"{
+        "meta": { "title": "Test", "version": "1", "start_node": "start" },
+        "endings": {
+            "left": { "title": { "en": "Gone Left", "fr": "Parti" }, "type": "bad" },
+            "right": { "title": { "en": "Gone Right", "fr": "Parti" }, "type": "good" }
+        },
+        "nodes": {
+            "start": {
+                "id": "start",
+                "messages": [{ "en": "Left or right?", "fr": "Gauche ou droite ?" }],
+                "choices": [
+                    { "label": { "en": "Left", "fr": "Gauche" }, "next_node": "end_left" },
+                    { "label": { "en": "Right", "fr": "Droite" }, "next_node": "end_right" }
+                ]
+            },
+            "end_left": { "id": "end_left", "messages": [{ "en": "Left it is.", "fr": "" }], "ending": "left" },
+            "end_right": { "id": "end_right", "messages": [{ "en": "Right it is.", "fr": "" }], "ending": "right" }
+        }
+    }"#;
+
+    fn test_app() -> App {
+        let story: StoryData = serde_json::from_str(STORY).unwrap();
+        let state = GameState::from_story(Language::En, &story);
+        let mut app = App::new(state, story);
+        app.persist = false;
+        app.screen = Screen::LanguageSelect;
+        app
+    }
+
+    #[test]
+    fn test_missing_node_offers_a_new_game() {
+        let story: StoryData = serde_json::from_str(&STORY.replace(
+            r#""next_node": "end_left""#,
+            r#""next_node": "end_nowhere""#,
+        ))
+        .unwrap();
+        let mut app = App::new(GameState::from_story(Language::En, &story), story);
+        app.persist = false;
+        app.screen = Screen::LanguageSelect;
+        let mut out = Vec::new();
+        run(
+            &mut app,
+            "1
+1
+2
+"
+            .as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Missing node: 'end_nowhere'\n1. Start a new game\n"));
+        assert_eq!(out.matches("Elara: Left or right?").count(), 2);
+        assert_eq!(app.game_state.ending.as_deref(), Some("right"));
+    }
+
+    #[test]
+    fn test_numbered_choice_reaches_ending() {
+        let mut app = test_app();
+        let mut out = Vec::new();
+        run(&mut app, "7\nabc\n2\n".as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Elara: Left or right?"));
+        assert!(out.contains("1. Left\n2. Right\n"));
+        assert_eq!(out.matches("Invalid choice").count(), 2);
+        assert!(out.contains("> Right"));
+        assert!(out.contains("Elara: Right it is."));
+        assert!(out.contains("ENDING REACHED Gone Right"));
+        assert!(out.contains("Active play time: "));
+        assert!(out.contains("Endings discovered: 1/2"));
+        assert_eq!(app.game_state.ending.as_deref(), Some("right"));
+    }
+
+    #[test]
+    fn test_locked_choice_prints_its_hint() {
+        let story: StoryData = serde_json::from_str(&STORY.replace(
+            r#""next_node": "end_left" }"#,
+            r#""next_node": "end_left", "condition": { "min_trust": 9 },
+                "show_locked": true, "locked_hint": { "en": "she doesn't trust you yet", "fr": "" } }"#,
+        ))
+        .unwrap();
+        let mut app = App::new(GameState::from_story(Language::En, &story), story);
+        app.persist = false;
+        app.screen = Screen::LanguageSelect;
+        let mut out = Vec::new();
+        run(&mut app, "1\n2\n".as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("1. Left \u{2014} [she doesn't trust you yet]\n2. Right\n"));
+        assert!(out.contains("\n[she doesn't trust you yet]\n"));
+        assert!(!out.contains("Invalid choice"));
+        assert_eq!(app.game_state.ending.as_deref(), Some("right"));
+    }
+
+    #[test]
+    fn test_act_card_is_printed_between_lines() {
+        let story: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp",
+                          "acts": { "2": { "en": "The Crossing", "fr": "La travers\u00e9e" } } },
+                "endings": { "over": { "title": { "en": "Over", "fr": "Fini" }, "type": "good" } },
+                "nodes": {
+                    "camp": { "id": "camp", "act": 1, "messages": [{ "en": "Ready?", "fr": "" }],
+                              "choices": [{ "label": { "en": "Go", "fr": "" }, "next_node": "river" }] },
+                    "river": { "id": "river", "act": 2, "messages": [{ "en": "Cold water.", "fr": "" }],
+                               "next_node": "end" },
+                    "end": { "id": "end", "act": 2, "messages": [], "ending": "over" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut app = App::new(GameState::from_story(Language::En, &story), story);
+        app.persist = false;
+        app.screen = Screen::LanguageSelect;
+        let mut out = Vec::new();
+        run(&mut app, "1\n".as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("> Go\n\n=== ACT II: The Crossing ===\n\nElara: Cold water.\n"));
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.game_state.ending.as_deref(), Some("over"));
+    }
+
+    #[test]
+    fn test_replay_follows_route_code() {
+        let mut app = test_app();
+        let mut out = Vec::new();
+        run(&mut app, "1\n".as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let code = crate::route::encode(&app.story_data, &app.game_state.decision_path).unwrap();
+        assert!(out.contains(&format!("Route code: {}", code)));
+
+        let mut replayed = test_app();
+        let choices = crate::route::decode(&replayed.story_data, &code)
+            .unwrap()
+            .choices;
+        let mut out = Vec::new();
+        replay(&mut replayed, &choices, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("> Left"));
+        assert!(out.contains("ENDING REACHED Gone Left"));
+
+        // Too short, and a choice the node doesn't have
+        let err = replay(&mut test_app(), &[], &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("stops at 'start'"));
+        let err = replay(&mut test_app(), &[5], &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("'start' has no choice 6"));
+    }
+
+    #[test]
+    fn test_eof_stops_at_choice() {
+        let mut app = test_app();
+        let mut out = Vec::new();
+        run(&mut app, "".as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Game saved"));
+        assert_eq!(app.game_state.current_node, "start");
+        assert_eq!(app.game_state.node_message_index, 1);
+        assert!(app.game_state.ending.is_none());
+    }
+}
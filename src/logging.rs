@@ -0,0 +1,55 @@
+//! Developer trace of engine events.
+//!
+//! Engine code reports node transitions, choices, effects, branches, waits and
+//! endings through the `log` facade. Nothing is recorded unless `--log <file>`
+//! installs the file logger below, so the calls are free in normal play.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes one timestamped line per engine event to a file.
+struct FileLogger {
+    file: Mutex<File>,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with("eshara")
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(
+                file,
+                "{} {:<5} {}",
+                chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f"),
+                record.level(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Install the file logger, truncating any previous trace at `path`.
+pub fn init(path: &Path) -> io::Result<()> {
+    let file = File::create(path)?;
+    log::set_boxed_logger(Box::new(FileLogger {
+        file: Mutex::new(file),
+    }))
+    .map_err(io::Error::other)?;
+    log::set_max_level(LevelFilter::Debug);
+    Ok(())
+}
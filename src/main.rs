@@ -1,15 +1,26 @@
-use std::io;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
 
-use eshara::game::{self, delete_save, load_game, parse_cli_args, save_exists, GameState};
-use eshara::i18n::{sys_msg, Language, Msg};
-use eshara::story::load_story;
+use eshara::game::{
+    self, delete_save_slot, diff_states, load_game_from, parse_cli_args, EndingsLedger, GameState,
+    UiMode,
+};
+use eshara::i18n::{self, sys_msg, Language, Msg};
+use eshara::line;
+use eshara::story::{self, LoadBench};
 use eshara::time;
-use eshara::tui::{self, App, Screen};
+use eshara::tui::{self, App};
 
 fn main() {
-    // Install Ctrl+C handler
+    // Install Ctrl+C handler; a second Ctrl+C means shutdown is stuck, so
+    // put the terminal back and leave at once
     let _ = ctrlc::set_handler(move || {
-        eshara::set_interrupted();
+        if eshara::set_interrupted() {
+            if ratatui::crossterm::terminal::is_raw_mode_enabled().unwrap_or(false) {
+                ratatui::restore();
+            }
+            std::process::exit(130);
+        }
     });
 
     if let Err(e) = run() {
@@ -21,90 +32,218 @@ fn main() {
 }
 
 fn run() -> io::Result<()> {
-    let args = parse_cli_args();
+    let args = parse_cli_args().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{}\n\n{}", e, game::USAGE),
+        )
+    })?;
+
+    // Handle --help
+    if args.help {
+        print!("{}", game::USAGE);
+        return Ok(());
+    }
 
     // Handle --reset
     if args.reset {
-        delete_save()?;
+        let slot = args.slot.unwrap_or(0);
+        delete_save_slot(slot)?;
+        println!("{}", game::save_slot_path(slot).display());
         println!("{}", sys_msg(Msg::SaveDeleted, Language::En));
         println!("{}", sys_msg(Msg::SaveDeleted, Language::Fr));
         return Ok(());
     }
 
-    let story_data = load_story();
-
-    // Determine starting state and screen
-    let (game_state, start_screen, prompt_options) = if save_exists() {
-        if let Some(existing) = load_game().unwrap_or_else(|_| {
-            // Incompatible or corrupted save — discard it silently
-            let _ = delete_save();
-            None
-        }) {
-            let lang = args.language.unwrap_or(existing.language);
-            let mut state = existing;
-            state.language = lang;
-            time::set_waiting_times_enabled(state.settings.waiting_times_enabled);
-
-            if time::is_waiting(&state) {
-                // Elara is still busy — keep the user in chat view.
-                (state, Screen::Game, Vec::new())
-            } else {
-                // Clear completed wait if any
-                if state.waiting_until.is_some() {
-                    state.waiting_until = None;
-                    let _ = game::save_game(&state);
-                }
-
-                // Show continue or new game prompt
-                let opts = vec![
-                    sys_msg(Msg::ContinueOption, lang).to_string(),
-                    sys_msg(Msg::NewGameOption, lang).to_string(),
-                ];
-                (state, Screen::ContinueOrNew, opts)
+    // Handle --diff
+    if let Some((path_a, path_b)) = args.diff {
+        let a = load_game_from(&path_a)?;
+        let b = load_game_from(&path_b)?;
+        println!("--- {}", path_a.display());
+        println!("+++ {}", path_b.display());
+        let differences = diff_states(&a, &b);
+        if differences.is_empty() {
+            println!("No differences.");
+        }
+        for line in differences {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    // Handle --log
+    if let Some(ref path) = args.log {
+        if let Err(e) = eshara::logging::init(path) {
+            eprintln!("Could not open log file {}: {}", path.display(), e);
+        }
+    }
+
+    // Handle --validate (a story file being edited; nothing else is loaded)
+    if let Some(ref path) = args.validate {
+        let story_data = story::read_story_file(path).map_err(io::Error::other)?;
+        let errors = story_data.validate();
+        println!("{}: {}", path.display(), story_data.summary());
+        for error in &errors {
+            println!("error: {}", error);
+        }
+        println!("{} error(s).", errors.len());
+        if !errors.is_empty() {
+            return Err(io::Error::other("story failed validation"));
+        }
+        return Ok(());
+    }
+
+    // Handle --bench-load (before loading the story, which refuses a broken file)
+    if let Some(iterations) = args.bench_load {
+        let results = story::bench_load(iterations).map_err(io::Error::other)?;
+        for bench in results {
+            println!(
+                "{} ({} nodes, {} iterations)",
+                bench.source,
+                bench.nodes,
+                bench.parse.len()
+            );
+            for (label, samples) in [("parse", &bench.parse), ("validate", &bench.validate)] {
+                let (min, median, max) = LoadBench::summary(samples);
+                println!(
+                    "  {:<9} min {:>9.3?}  median {:>9.3?}  max {:>9.3?}",
+                    label, min, median, max
+                );
             }
-        } else {
-            // Corrupted save — start fresh
-            let lang = args.language.unwrap_or(Language::En);
-            let state = GameState::from_story(lang, &story_data);
-            time::set_waiting_times_enabled(state.settings.waiting_times_enabled);
-            let opts = vec![
-                sys_msg(Msg::LanguageOption1, Language::En).to_string(),
-                sys_msg(Msg::LanguageOption2, Language::En).to_string(),
-            ];
-            (state, Screen::LanguageSelect, opts)
         }
-    } else {
-        // No save — new game
+        return Ok(());
+    }
+
+    let external = game::story_path();
+    let story_data = story::load_story_with(&external, |errors| {
+        offer_embedded_story(&external, errors, args.fallback_embedded)
+    })
+    .map_err(|errors| {
+        io::Error::other(format!(
+            "{} has {} error(s); fix it, remove it, or pass --fallback-embedded",
+            external.display(),
+            errors.len()
+        ))
+    })?;
+    log::info!("story_hash {}", story_data.content_hash());
+
+    // Handle --graph
+    if args.graph {
+        print!("{}", story::export_dot(&story_data));
+        return Ok(());
+    }
+
+    // Handle --version
+    if args.version {
+        println!("eshara {}", env!("CARGO_PKG_VERSION"));
+        println!(
+            "story: {} {} (content hash {})",
+            story_data.meta.title,
+            story_data.meta.version,
+            story_data.content_hash()
+        );
+        return Ok(());
+    }
+
+    // Handle --dump-text
+    if !args.dump_text.is_empty() {
+        print!("{}", story_data.text_dump(&args.dump_text));
+        print!("{}", i18n::system_text_dump(&args.dump_text));
+        return Ok(());
+    }
+
+    // Handle --lint
+    if args.lint {
+        let errors = story_data.validate();
+        let warnings = story_data.flag_warnings();
+        for error in &errors {
+            println!("error: {}", error);
+        }
+        for warning in &warnings {
+            println!("warning: {}", warning);
+        }
+        println!("{} error(s), {} warning(s).", errors.len(), warnings.len());
+        if !errors.is_empty() {
+            return Err(io::Error::other("story failed validation"));
+        }
+        return Ok(());
+    }
+
+    // Handle --replay-route (a throwaway run; the save is never touched)
+    if let Some(ref code) = args.replay_route {
+        let route = eshara::route::decode(&story_data, code).map_err(io::Error::other)?;
+        if route.content_changed {
+            eprintln!(
+                "warning: this route code was made on different story content \
+                 (current content hash {}); the replay may not follow it",
+                story_data.content_hash()
+            );
+        }
+        let choices = route.choices;
         let lang = args.language.unwrap_or(Language::En);
         let state = GameState::from_story(lang, &story_data);
-        time::set_waiting_times_enabled(state.settings.waiting_times_enabled);
-        let opts = vec![
-            sys_msg(Msg::LanguageOption1, Language::En).to_string(),
-            sys_msg(Msg::LanguageOption2, Language::En).to_string(),
-        ];
-        (state, Screen::LanguageSelect, opts)
-    };
+        time::set_waiting_times_enabled(false);
+        let mut app = App::new(state, story_data);
+        return line::replay(&mut app, &choices, &mut io::stdout());
+    }
+
+    // Handle --check-lang
+    if !args.check_lang.is_empty() {
+        let mut total = 0;
+        for &lang in &args.check_lang {
+            let missing = story_data.missing_translations(lang);
+            for entry in &missing {
+                println!("{}: {}", lang.code(), entry);
+            }
+            total += missing.len();
+        }
+        if total == 0 {
+            println!("All strings are translated.");
+        } else {
+            println!("{} missing translation(s), shown in English.", total);
+        }
+        return Ok(());
+    }
 
-    // Build the App
-    let mut app = App::new(game_state, story_data);
-    app.screen = start_screen.clone();
-    app.prompt_options = prompt_options;
-
-    // If resuming, load backlog into chat
-    if start_screen == Screen::ContinueOrNew || start_screen == Screen::Game {
-        app.load_backlog();
-    }
-
-    // Set up wait message if currently waiting
-    if time::is_waiting(&app.game_state) {
-        if app.game_state.waiting_until.is_some() {
-            let lang = app.lang();
-            app.wait_message = Some(format!(
-                "{} {}",
-                sys_msg(Msg::ElaraUnavailable, lang),
-                sys_msg(Msg::ElaraBackAround, lang),
-            ));
+    // Pick the save slot: the one asked for, the only one in use, or let
+    // the player choose when several hold a run
+    let saves = game::list_saves();
+    let newest = saves
+        .iter()
+        .max_by_key(|info| info.modified)
+        .map(|info| info.slot);
+    let lang = args.language.unwrap_or(Language::En);
+    let mut app = App::new(GameState::from_story(lang, &story_data), story_data);
+    app.ledger = EndingsLedger::load();
+    app.dev_mode = args.dev;
+    app.debug_delays = args.debug || time::is_debug_mode();
+    app.launch_language = args.language;
+    app.launch_ui = args.ui;
+    app.launch_reduce_motion = args.no_animation;
+    match args.slot {
+        Some(slot) => app.open_save_slot(slot),
+        None if saves.len() > 1 => {
+            // Ask in the language and frontend of the run played last
+            if let Some(Ok(Some(state))) = newest.map(game::load_game_slot) {
+                app.game_state.language = args.language.unwrap_or(state.language);
+                app.game_state.settings.ui = args.ui.unwrap_or(state.settings.ui);
+            }
+            app.show_slot_select(saves);
         }
+        None => app.open_save_slot(saves.first().map_or(0, |info| info.slot)),
+    }
+
+    // The classic frontend reads numbered choices from stdin, which also
+    // makes it suitable for piping choices in from scripts.
+    if args.stdin_choices || app.game_state.settings.ui == UiMode::Classic {
+        let stdin = io::stdin();
+        let mut stdout = io::stdout();
+        return line::run(&mut app, stdin.lock(), &mut stdout);
+    }
+
+    // Fail with guidance rather than inside terminal setup
+    if let Err(problem) = tui::check_terminal() {
+        return Err(io::Error::other(problem.message(app.lang())));
     }
 
     // Initialize ratatui terminal and run
@@ -114,3 +253,27 @@ fn run() -> io::Result<()> {
 
     result
 }
+
+/// Report a broken on-disk story and decide whether to play the embedded one:
+/// always with `--fallback-embedded`, after asking on a terminal, never otherwise.
+fn offer_embedded_story(external: &Path, errors: &[String], fallback: bool) -> bool {
+    eprintln!("{} could not be loaded:", external.display());
+    for e in errors {
+        eprintln!("  - {}", e);
+    }
+    let accepted = if fallback {
+        true
+    } else if io::stdin().is_terminal() {
+        eprint!("Play the built-in story instead? [y/N] ");
+        let _ = io::stderr().flush();
+        let mut answer = String::new();
+        let _ = io::stdin().lock().read_line(&mut answer);
+        matches!(answer.trim(), "y" | "Y" | "yes")
+    } else {
+        false
+    };
+    if accepted {
+        eprintln!("Using the built-in story.");
+    }
+    accepted
+}
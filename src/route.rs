@@ -0,0 +1,160 @@
+//! Compact "route codes" for sharing a run.
+//!
+//! A code is a fingerprint of the story it was played on followed by one
+//! character per decision and a tag of the story's content, e.g.
+//! `K3QD-0101120-7MZA`. Each decision character is the source index of the
+//! choice taken, so the whole run replays from the start node without
+//! shipping a save. Codes from before the content tag still decode.
+
+use crate::game::DecisionRecord;
+use crate::story::StoryData;
+
+/// Crockford base32: no I, L, O or U, so codes survive being read aloud.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Characters in the story fingerprint prefix.
+const FINGERPRINT_LEN: usize = 4;
+
+/// Characters in the content tag suffix.
+const CONTENT_TAG_LEN: usize = 4;
+
+/// A decoded route code.
+#[derive(Debug, PartialEq)]
+pub struct Route {
+    /// Source indices of the choices to take, in order.
+    pub choices: Vec<usize>,
+    /// The code was made on the same story and version but different
+    /// content (edited text or routing), so the replay may wander off.
+    pub content_changed: bool,
+}
+
+/// Short fingerprint of the story's identity, so a code from another version
+/// is rejected instead of wandering off on a different graph.
+pub fn fingerprint(story: &StoryData) -> String {
+    let meta = &story.meta;
+    let hash = [&meta.title, &meta.version, &meta.start_node]
+        .iter()
+        .flat_map(|s| s.bytes().chain(std::iter::once(0)))
+        .fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    (0..FINGERPRINT_LEN)
+        .map(|i| ALPHABET[((hash >> (5 * i)) & 31) as usize] as char)
+        .collect()
+}
+
+/// Short tag of the story's exact content, from `StoryData::content_hash`.
+pub fn content_tag(story: &StoryData) -> String {
+    let hash = u64::from_str_radix(&story.content_hash(), 16).unwrap_or_default();
+    (0..CONTENT_TAG_LEN)
+        .map(|i| ALPHABET[((hash >> (5 * i)) & 31) as usize] as char)
+        .collect()
+}
+
+/// Encode a run's decisions. None if a choice index doesn't fit in one character.
+pub fn encode(story: &StoryData, path: &[DecisionRecord]) -> Option<String> {
+    let mut code = fingerprint(story);
+    code.push('-');
+    for record in path {
+        code.push(*ALPHABET.get(record.choice)? as char);
+    }
+    code.push('-');
+    code.push_str(&content_tag(story));
+    Some(code)
+}
+
+/// Decode a code into the choices to take, checking it was made for `story`.
+pub fn decode(story: &StoryData, code: &str) -> Result<Route, String> {
+    let code = code.trim().to_ascii_uppercase();
+    let (prefix, rest) = code
+        .split_once('-')
+        .ok_or_else(|| format!("'{}' is not a route code", code))?;
+    let (choices, tag) = match rest.split_once('-') {
+        Some((choices, tag)) => (choices, Some(tag)),
+        None => (rest, None),
+    };
+    if prefix != fingerprint(story) {
+        return Err(format!(
+            "route code was made for a different version of the story (expected {}, got {})",
+            fingerprint(story),
+            prefix
+        ));
+    }
+    let choices = choices
+        .chars()
+        .map(|c| {
+            ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .ok_or_else(|| format!("invalid character '{}' in route code", c))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Route {
+        choices,
+        content_changed: tag.is_some_and(|tag| tag != content_tag(story)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::story::embedded_story;
+
+    #[test]
+    fn test_route_code_roundtrip() {
+        let story = embedded_story();
+        let path: Vec<DecisionRecord> = [0, 1, 1, 2, 0]
+            .iter()
+            .map(|&choice| DecisionRecord {
+                node: String::new(),
+                choice,
+            })
+            .collect();
+        let code = encode(&story, &path).unwrap();
+        assert_eq!(code.len(), FINGERPRINT_LEN + 1 + 5 + 1 + CONTENT_TAG_LEN);
+        assert!(code.ends_with(&format!("-01120-{}", content_tag(&story))));
+        assert_eq!(
+            decode(&story, &code.to_lowercase()),
+            Ok(Route {
+                choices: vec![0, 1, 1, 2, 0],
+                content_changed: false,
+            })
+        );
+
+        let mut other = story.clone();
+        other.meta.version = "0.1".to_string();
+        assert!(decode(&other, &code)
+            .unwrap_err()
+            .contains("different version"));
+        assert!(decode(&story, "nonsense").is_err());
+        assert!(decode(&story, &format!("{}-0I", fingerprint(&story))).is_err());
+    }
+
+    #[test]
+    fn test_route_code_flags_edited_content() {
+        let story = embedded_story();
+        let path = vec![DecisionRecord {
+            node: String::new(),
+            choice: 1,
+        }];
+        let code = encode(&story, &path).unwrap();
+
+        // Same title and version, one line of text changed
+        let mut edited = story.clone();
+        edited
+            .nodes
+            .get_mut(&edited.meta.start_node.clone())
+            .unwrap()
+            .messages[0]
+            .text
+            .en
+            .push_str("...");
+        let route = decode(&edited, &code).unwrap();
+        assert_eq!(route.choices, vec![1]);
+        assert!(route.content_changed);
+
+        // Codes from before the content tag carry nothing to compare
+        let old = format!("{}-1", fingerprint(&story));
+        assert!(!decode(&edited, &old).unwrap().content_changed);
+    }
+}
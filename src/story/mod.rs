@@ -1,13 +1,20 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 
-use crate::i18n::LocalizedString;
+use crate::i18n::{Language, LocalizedString};
 
 /// The default story JSON, embedded at compile time from data/story.json.
 const EMBEDDED_STORY: &str = include_str!("../../data/story.json");
 
+/// Slowest per-character reveal a message may ask for
+pub const MAX_REVEAL_MS: u64 = 1_000;
+/// Longest extra pause a message may ask for after it lands
+pub const MAX_PAUSE_AFTER_MS: u64 = 10_000;
+
 // ── Top-level story data ─────────────────────────────────────
 
 /// Story metadata (title, version, configuration)
@@ -20,6 +27,43 @@ pub struct StoryMeta {
     pub default_typing_delay_ms: u64,
     #[serde(default = "default_debug_delay")]
     pub debug_delay_override_seconds: u64,
+    /// Optional trust penalty for staying away long after a wait (off when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_decay: Option<TrustDecayRule>,
+    /// Per-act radio signal descriptor shown in the status bar (none when empty)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub signal: BTreeMap<u32, LocalizedString>,
+    /// Per-act title shown on a card when the story moves into that act
+    /// (no card for acts without one)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub acts: BTreeMap<u32, LocalizedString>,
+    /// Node to recover to when a node has nowhere to go (no matching branch,
+    /// choice or next_node) instead of stopping the game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_node: Option<String>,
+    /// Node entered once, the first time supplies run out; its paths lead
+    /// back to the interrupted story through `RETURN_NODE`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplies_depleted_node: Option<String>,
+}
+
+/// Pseudo node id that returns from an interrupt to where the story was heading
+pub const RETURN_NODE: &str = "@return";
+
+/// Lose trust when the player quits during a wait and returns much later
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustDecayRule {
+    /// Hours of absence after the wait ended before decay fires
+    pub after_hours: u64,
+    /// Trust points lost when it fires
+    pub amount: i32,
+    /// Flag set when decay fires, so Elara can comment on it
+    #[serde(default = "default_trust_decay_flag")]
+    pub flag: String,
+}
+
+fn default_trust_decay_flag() -> String {
+    "felt_abandoned".to_string()
 }
 
 fn default_typing_delay() -> u64 {
@@ -58,10 +102,16 @@ pub struct EndingConditions {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EndingInfo {
     pub title: LocalizedString,
+    /// One or two lines shown under the title on the ending screen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<LocalizedString>,
     #[serde(rename = "type", default)]
     pub ending_type: String,
     #[serde(default)]
     pub conditions: Option<EndingConditions>,
+    /// Flags that every later playthrough starts with once this ending is reached
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unlocks: Vec<String>,
 }
 
 /// Global death check rule: if health reaches 0, route to a specific ending
@@ -76,6 +126,84 @@ pub struct DeathCheck {
     pub override_next_node: String,
 }
 
+/// Documentation and optional display name for a story flag.
+///
+/// In JSON a flag is either a plain description string, or an object with a
+/// `description` and a localized `label`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "FlagInfoRepr")]
+pub struct FlagInfo {
+    pub description: String,
+    pub label: Option<LocalizedString>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FlagInfoRepr {
+    Description(String),
+    Detailed {
+        #[serde(default)]
+        description: String,
+        #[serde(default)]
+        label: Option<LocalizedString>,
+    },
+}
+
+impl From<FlagInfoRepr> for FlagInfo {
+    fn from(repr: FlagInfoRepr) -> Self {
+        match repr {
+            FlagInfoRepr::Description(description) => Self {
+                description,
+                label: None,
+            },
+            FlagInfoRepr::Detailed { description, label } => Self { description, label },
+        }
+    }
+}
+
+/// Turn a snake_case flag id into a readable title ("has_shielding" -> "Has Shielding").
+pub fn humanize_flag(flag: &str) -> String {
+    flag.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// A world term the player can look up once they have come across it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    /// The term as displayed
+    pub term: LocalizedString,
+    /// What the term means
+    pub definition: LocalizedString,
+    /// Flag that unlocks the entry; entries without one are known from the start
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unlock_flag: Option<String>,
+}
+
+/// One possible epilogue line for a character, shown when its condition holds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpilogueVariant {
+    #[serde(default)]
+    pub condition: BranchCondition,
+    pub text: LocalizedString,
+}
+
+/// "Where are they now" blurbs for a named character, tried in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterEpilogue {
+    pub name: LocalizedString,
+    #[serde(default)]
+    pub variants: Vec<EpilogueVariant>,
+}
+
 /// Top-level story data loaded from JSON.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryData {
@@ -85,7 +213,7 @@ pub struct StoryData {
     pub stats: HashMap<String, StatDef>,
     /// Flag documentation keyed by flag name
     #[serde(default)]
-    pub flags: HashMap<String, String>,
+    pub flags: HashMap<String, FlagInfo>,
     /// Ending metadata keyed by ending key (e.g. "still_here", "gone_dark")
     #[serde(default)]
     pub endings: HashMap<String, EndingInfo>,
@@ -94,13 +222,231 @@ pub struct StoryData {
     /// Global death check rule
     #[serde(default)]
     pub death_check: Option<DeathCheck>,
+    /// Glossary of world terms keyed by entry id
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub glossary: BTreeMap<String, GlossaryEntry>,
+    /// Per-character epilogues shown under the ending, keyed by character id
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub epilogues: BTreeMap<String, CharacterEpilogue>,
+    /// Character display names keyed by id, substituted for `{char:id}` in story text
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub characters: BTreeMap<String, LocalizedString>,
+}
+
+/// Opening of a character name placeholder, e.g. `{char:lina}`.
+const CHARACTER_PLACEHOLDER: &str = "{char:";
+
+/// Character ids referenced by `{char:id}` placeholders in `text`.
+fn character_refs(text: &str) -> impl Iterator<Item = &str> {
+    text.split(CHARACTER_PLACEHOLDER)
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(id, _)| id))
 }
 
 impl StoryData {
+    /// Replace `{char:id}` placeholders with the character's name in `lang`.
+    /// Unknown ids are left as written; `validate` reports them.
+    pub fn resolve_text<'a>(&self, text: &'a str, lang: Language) -> Cow<'a, str> {
+        if !text.contains(CHARACTER_PLACEHOLDER) {
+            return Cow::Borrowed(text);
+        }
+        let mut resolved = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(CHARACTER_PLACEHOLDER) {
+            resolved.push_str(&rest[..start]);
+            let after = &rest[start + CHARACTER_PLACEHOLDER.len()..];
+            match after
+                .split_once('}')
+                .and_then(|(id, tail)| Some((self.characters.get(id)?, tail)))
+            {
+                Some((name, tail)) => {
+                    resolved.push_str(name.get(lang));
+                    rest = tail;
+                }
+                None => {
+                    resolved.push_str(CHARACTER_PLACEHOLDER);
+                    rest = after;
+                }
+            }
+        }
+        resolved.push_str(rest);
+        Cow::Owned(resolved)
+    }
+
+    /// A story string in `lang` with its placeholders resolved.
+    pub fn localized<'a>(&self, text: &'a LocalizedString, lang: Language) -> Cow<'a, str> {
+        self.resolve_text(text.get(lang), lang)
+    }
+
     /// Look up ending info by string key
     pub fn ending_info(&self, key: &str) -> Option<&EndingInfo> {
         self.endings.get(key)
     }
+
+    /// An ending's title and description, as every ending screen shows them.
+    /// Unknown keys get the key itself so the screen never shows a blank title.
+    pub fn ending_text(&self, key: &str, lang: Language) -> (String, Option<String>) {
+        match self.endings.get(key) {
+            Some(info) => (
+                self.localized(&info.title, lang).into_owned(),
+                info.description
+                    .as_ref()
+                    .map(|d| self.localized(d, lang).into_owned()),
+            ),
+            None => (key.to_string(), None),
+        }
+    }
+
+    /// Stable hash of the story's content, as 16 hex digits. It covers only
+    /// what plays back (each node's texts, targets, conditions and effects,
+    /// plus the story-wide routing) in a canonical serialization, so
+    /// reformatting the JSON or editing author-only fields such as node
+    /// titles and acts leaves it alone while any change to text or routing
+    /// shows up.
+    pub fn content_hash(&self) -> String {
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        let nodes: Vec<serde_json::Value> = node_ids
+            .into_iter()
+            .map(|id| {
+                let node = &self.nodes[id];
+                let messages = node.trust_bonus_messages.iter().chain(&node.messages);
+                let choices = node.choices.iter().flatten();
+                let branches = node.branch.iter().flatten();
+                serde_json::json!({
+                    "id": id,
+                    "texts": node.texts().collect::<Vec<_>>(),
+                    "targets": node.targets().collect::<Vec<_>>(),
+                    "ending": node.ending,
+                    "conditions": {
+                        "messages": messages.map(|m| &m.condition).collect::<Vec<_>>(),
+                        "choices": choices.clone().map(|c| &c.condition).collect::<Vec<_>>(),
+                        "branches": branches.map(|b| &b.condition).collect::<Vec<_>>(),
+                    },
+                    "effects": {
+                        "on_enter": node.on_enter,
+                        "choices": choices.map(|c| &c.on_choose).collect::<Vec<_>>(),
+                    },
+                })
+            })
+            .collect();
+        let mut playback = serde_json::json!({
+            "start_node": self.meta.start_node,
+            "fallback_node": self.meta.fallback_node,
+            "supplies_depleted_node": self.meta.supplies_depleted_node,
+            "death_check": self.death_check,
+            "nodes": nodes,
+        });
+        prune_defaults(&mut playback);
+        let canonical = playback.to_string();
+        // FNV-1a, so the hash is the same on every platform and build
+        let hash = canonical.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        format!("{:016x}", hash)
+    }
+
+    /// Highest act number used by any node, if acts are set at all
+    pub fn max_act(&self) -> Option<u32> {
+        self.nodes.values().filter_map(|n| n.act).max()
+    }
+
+    /// Record glossary entries whose unlock flag is now set. Unlocks stick
+    /// even if the flag is later removed. Returns the newly unlocked ids.
+    pub fn unlock_glossary(&self, state: &mut crate::game::GameState) -> Vec<String> {
+        let mut unlocked = Vec::new();
+        for (id, entry) in &self.glossary {
+            if state.glossary_unlocked.contains(id) {
+                continue;
+            }
+            if entry.unlock_flag.as_ref().is_none_or(|f| state.has_flag(f)) {
+                state.glossary_unlocked.insert(id.clone());
+                unlocked.push(id.clone());
+            }
+        }
+        unlocked
+    }
+
+    /// The first matching epilogue of each character, in character id order.
+    /// Characters with no matching variant were never really met and are left out.
+    pub fn epilogues_for(
+        &self,
+        state: &crate::game::GameState,
+    ) -> Vec<(&LocalizedString, &LocalizedString)> {
+        self.epilogues
+            .values()
+            .filter_map(|character| {
+                character
+                    .variants
+                    .iter()
+                    .find(|variant| variant.condition.evaluate(state))
+                    .map(|variant| (&character.name, &variant.text))
+            })
+            .collect()
+    }
+
+    /// Apply the optional trust decay rule for a player returning at `now`.
+    /// Returns true if decay fired.
+    pub fn apply_trust_decay(
+        &self,
+        state: &mut crate::game::GameState,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        let Some(ref rule) = self.meta.trust_decay else {
+            return false;
+        };
+        let Some(absence) = crate::time::absence_duration(state, now) else {
+            return false;
+        };
+        if absence < chrono::Duration::hours(rule.after_hours as i64) {
+            return false;
+        }
+
+        let min = self.stats.get("trust").map(|def| def.min).unwrap_or(0);
+        state.stats.trust = (state.stats.trust - rule.amount).max(min);
+        state.set_flag(&rule.flag);
+        log::info!(
+            "trust_decay absence_hours={} amount={} trust={}",
+            absence.num_hours(),
+            rule.amount,
+            state.stats.trust
+        );
+        true
+    }
+
+    /// Whether the flag has a localized display label (i.e. it is a key plot flag).
+    pub fn flag_has_label(&self, flag: &str) -> bool {
+        self.flags.get(flag).is_some_and(|f| f.label.is_some())
+    }
+
+    /// Display name for a flag in the given language, falling back to a
+    /// title-cased version of the flag id.
+    pub fn flag_label(&self, flag: &str, lang: Language) -> String {
+        match self.flags.get(flag).and_then(|f| f.label.as_ref()) {
+            Some(label) => label.get(lang).to_string(),
+            None => humanize_flag(flag),
+        }
+    }
+}
+
+/// Drop object entries that read back as their serde default (null, false,
+/// empty), so a field added with a default doesn't change `content_hash`.
+fn prune_defaults(value: &mut serde_json::Value) {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => {
+            map.values_mut().for_each(prune_defaults);
+            map.retain(|_, v| match v {
+                Value::Null | Value::Bool(false) => false,
+                Value::String(s) => !s.is_empty(),
+                Value::Array(items) => !items.is_empty(),
+                Value::Object(fields) => !fields.is_empty(),
+                _ => true,
+            });
+        }
+        Value::Array(items) => items.iter_mut().for_each(prune_defaults),
+        _ => {}
+    }
 }
 
 // ── Node types ───────────────────────────────────────────────
@@ -118,9 +464,21 @@ pub struct Effects {
     pub flags_set: Vec<String>,
     #[serde(default)]
     pub flags_remove: Vec<String>,
-    /// Conditional medicine (ignored in gameplay — handled by flags)
+    /// When true, `health_change` only applies if the `has_medicine` flag is set
     #[serde(default)]
     pub has_medicine_conditional: Option<bool>,
+    /// Scale the stat changes by Elara's current trust (see `trust_scale`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub scale_with_trust: bool,
+}
+
+/// Flag checked by `has_medicine_conditional` effects
+pub const MEDICINE_FLAG: &str = "has_medicine";
+
+/// Multiplier for `scale_with_trust` effects: half strength at zero trust,
+/// unchanged at the midpoint, one and a half times at full trust.
+pub fn trust_scale(trust: i32) -> f64 {
+    0.5 + f64::from(trust.clamp(0, 10)) / 10.0
 }
 
 impl Effects {
@@ -128,14 +486,31 @@ impl Effects {
     /// Returns true if health was changed (for death check).
     pub fn apply(&self, state: &mut crate::game::GameState) -> bool {
         let mut health_changed = false;
-        if let Some(delta) = self.trust_change {
+        // Scaled from trust as it was before this effect
+        let factor = if self.scale_with_trust {
+            trust_scale(state.stats.trust)
+        } else {
+            1.0
+        };
+        let scale = |delta: i32| (f64::from(delta) * factor).round() as i32;
+        let trust_change = self.trust_change.map(scale);
+        // Medicine-gated healing, checked before this effect sets any flags
+        let health_change = self
+            .health_change
+            .filter(|_| {
+                self.has_medicine_conditional != Some(true) || state.has_flag(MEDICINE_FLAG)
+            })
+            .map(scale);
+        let supplies_change = self.supplies_change.map(scale);
+
+        if let Some(delta) = trust_change {
             state.stats.modify("trust", delta);
         }
-        if let Some(delta) = self.health_change {
+        if let Some(delta) = health_change {
             state.stats.modify("health", delta);
             health_changed = true;
         }
-        if let Some(delta) = self.supplies_change {
+        if let Some(delta) = supplies_change {
             state.stats.modify("supplies", delta);
         }
         for flag in &self.flags_set {
@@ -144,6 +519,14 @@ impl Effects {
         for flag in &self.flags_remove {
             state.remove_flag(flag);
         }
+        log::debug!(
+            "effects_applied trust={:?} health={:?} supplies={:?} flags_set={:?} flags_remove={:?}",
+            trust_change,
+            health_change,
+            supplies_change,
+            self.flags_set,
+            self.flags_remove
+        );
         health_changed
     }
 }
@@ -168,6 +551,12 @@ pub struct BranchCondition {
     pub min_health: Option<i32>,
     #[serde(default)]
     pub max_health: Option<i32>,
+    /// Flags that must all be unset
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags_forbidden: Vec<String>,
+    /// At least one of these must hold (ignored when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<BranchCondition>,
     /// If true, this is the fallback/default branch
     #[serde(default)]
     pub default: bool,
@@ -180,12 +569,18 @@ impl BranchCondition {
             return true;
         }
 
-        // Check required flags
+        // Check required and forbidden flags
         for flag in &self.flags_required {
             if !state.has_flag(flag) {
                 return false;
             }
         }
+        if self.flags_forbidden.iter().any(|flag| state.has_flag(flag)) {
+            return false;
+        }
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|c| c.evaluate(state)) {
+            return false;
+        }
 
         // Check stat thresholds
         if let Some(min) = self.min_trust {
@@ -211,6 +606,20 @@ impl BranchCondition {
 
         true
     }
+
+    /// Every flag this condition looks at, including inside `any_of`.
+    pub fn flags(&self) -> Vec<&str> {
+        let mut flags: Vec<&str> = self
+            .flags_required
+            .iter()
+            .chain(&self.flags_forbidden)
+            .map(String::as_str)
+            .collect();
+        for sub in &self.any_of {
+            flags.extend(sub.flags());
+        }
+        flags
+    }
 }
 
 /// A conditional branch entry (evaluated in order; first match wins)
@@ -220,6 +629,38 @@ pub struct Branch {
     pub next_node: String,
 }
 
+/// A single message within a node, optionally shown only when a condition holds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryMessage {
+    /// The message text in every language
+    #[serde(flatten)]
+    pub text: LocalizedString,
+    /// If set, the message is only displayed when this condition holds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<BranchCondition>,
+    /// Milliseconds per revealed character, overriding the text speed setting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reveal_ms: Option<u64>,
+    /// Pause after this message finishes, replacing the default gap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pause_after_ms: Option<u64>,
+    /// Reveal through radio static that clears once the line is complete
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub garbled: bool,
+}
+
+impl StoryMessage {
+    /// Get the message text for the given language
+    pub fn get(&self, lang: Language) -> &str {
+        self.text.get(lang)
+    }
+
+    /// Whether this message should be displayed for the given state
+    pub fn is_visible(&self, state: &crate::game::GameState) -> bool {
+        self.condition.as_ref().is_none_or(|c| c.evaluate(state))
+    }
+}
+
 /// A player choice within a story node
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Choice {
@@ -230,6 +671,28 @@ pub struct Choice {
     /// Effects applied when this choice is made
     #[serde(default)]
     pub on_choose: Option<Effects>,
+    /// Position in the displayed list; choices without one keep source order after those with one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_order: Option<u32>,
+    /// If set, this prompt must be confirmed before the choice is committed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<LocalizedString>,
+    /// If set, the choice can only be taken when this condition holds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<BranchCondition>,
+    /// Show the choice dimmed while its condition fails instead of hiding it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_locked: bool,
+    /// Why a locked choice can't be taken, e.g. "needs supplies 2+"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_hint: Option<LocalizedString>,
+}
+
+impl Choice {
+    /// Whether this choice's condition fails for the given state
+    pub fn is_locked(&self, state: &crate::game::GameState) -> bool {
+        self.condition.as_ref().is_some_and(|c| !c.evaluate(state))
+    }
 }
 
 /// A single story node in the narrative tree
@@ -245,7 +708,7 @@ pub struct StoryNode {
     pub title: Option<String>,
     /// Ordered list of messages at this node
     #[serde(default)]
-    pub messages: Vec<LocalizedString>,
+    pub messages: Vec<StoryMessage>,
     /// Player choices (null/absent = no choices)
     pub choices: Option<Vec<Choice>>,
     /// For linear nodes: the next node to auto-advance to
@@ -260,50 +723,559 @@ pub struct StoryNode {
     /// Conditional branching (evaluated in order; first match wins)
     #[serde(default)]
     pub branch: Option<Vec<Branch>>,
+    /// Messages Elara adds before the regular ones when trust is high enough
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trust_bonus_messages: Vec<StoryMessage>,
+    /// Minimum trust for `trust_bonus_messages` to be shown
+    #[serde(default = "default_trust_bonus_threshold")]
+    pub trust_bonus_threshold: i32,
+    /// Offer a "Re-read that" pseudo-choice that replays this node's messages
+    #[serde(default)]
+    pub allow_reread: bool,
+    /// Makes this node a checkpoint: entering it stores a snapshot under this name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<LocalizedString>,
+    /// Author's note shown in commentary mode on replays after an ending
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commentary: Option<LocalizedString>,
+}
+
+fn default_trust_bonus_threshold() -> i32 {
+    7
+}
+
+impl StoryNode {
+    /// The messages whose conditions hold for the given state, in order.
+    /// Trust bonus messages come first when trust reaches the node's threshold.
+    pub fn visible_messages(&self, state: &crate::game::GameState) -> Vec<&StoryMessage> {
+        let bonus = if state.stats.trust >= self.trust_bonus_threshold {
+            self.trust_bonus_messages.as_slice()
+        } else {
+            &[]
+        };
+        bonus
+            .iter()
+            .chain(self.messages.iter())
+            .filter(|m| m.is_visible(state))
+            .collect()
+    }
+
+    /// Choices in display order, each paired with its index in `choices`.
+    /// Sorting by `display_order` is stable, so ties keep source order.
+    pub fn ordered_choices(&self) -> Vec<(usize, &Choice)> {
+        let mut ordered: Vec<(usize, &Choice)> =
+            self.choices.iter().flatten().enumerate().collect();
+        ordered.sort_by_key(|(_, c)| c.display_order.unwrap_or(u32::MAX));
+        ordered
+    }
+
+    /// The ordered choices offered in this state: unlocked ones, plus locked ones marked `show_locked`.
+    pub fn shown_choices(&self, state: &crate::game::GameState) -> Vec<(usize, &Choice)> {
+        self.ordered_choices()
+            .into_iter()
+            .filter(|(_, c)| c.show_locked || !c.is_locked(state))
+            .collect()
+    }
+
+    /// Every node id this node can move to, through next_node, choices or branches.
+    pub fn targets(&self) -> impl Iterator<Item = &str> {
+        self.next_node
+            .iter()
+            .chain(self.choices.iter().flatten().map(|c| &c.next_node))
+            .chain(self.branch.iter().flatten().map(|b| &b.next_node))
+            .map(String::as_str)
+    }
+
+    /// Every player-facing string of this node: messages, choices, confirmations,
+    /// the delay message and the commentary note.
+    pub fn texts(&self) -> impl Iterator<Item = &LocalizedString> {
+        self.messages
+            .iter()
+            .chain(&self.trust_bonus_messages)
+            .map(|m| &m.text)
+            .chain(self.choices.iter().flatten().flat_map(|c| {
+                std::iter::once(&c.label)
+                    .chain(&c.confirm)
+                    .chain(&c.locked_hint)
+            }))
+            .chain(self.delay.iter().map(|d| &d.message))
+            .chain(&self.commentary)
+    }
+
+    /// Whether the "Re-read that" pseudo-choice is offered (never on endings or waits)
+    pub fn offers_reread(&self) -> bool {
+        self.allow_reread && self.ending.is_none() && self.delay.is_none()
+    }
 }
 
 // ── Story loading ────────────────────────────────────────────
 
 /// Load the story data.
 ///
-/// 1. If `data/story.json` exists on disk (next to the working directory), load it.
+/// 1. If a story file exists on disk (see `game::story_path`), load it.
 /// 2. Otherwise, fall back to the compile-time embedded copy.
 ///
 /// Panics if the JSON is malformed or the story graph is invalid.
 pub fn load_story() -> StoryData {
-    let story_data: StoryData = {
-        let external = Path::new("data/story.json");
-        if external.exists() {
-            let json = std::fs::read_to_string(external).expect("Failed to read data/story.json");
-            serde_json::from_str(&json).expect("Failed to parse data/story.json")
-        } else {
-            serde_json::from_str(EMBEDDED_STORY).expect("Failed to parse embedded story data")
+    let external = crate::game::story_path();
+    match load_story_with(&external, |_| false) {
+        Ok(story_data) => story_data,
+        Err(errors) => {
+            eprintln!("Story validation errors:");
+            for e in &errors {
+                eprintln!("  - {}", e);
+            }
+            panic!(
+                "Story data has {} validation error(s). Fix {} and try again.",
+                errors.len(),
+                external.display()
+            );
+        }
+    }
+}
+
+/// Read and parse a story file without validating it.
+pub fn read_story_file(path: &Path) -> Result<StoryData, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Load the story, preferring the file at `external` when it exists.
+///
+/// If that file can't be read, parsed or validated, `use_embedded` is given
+/// the errors and decides whether to carry on with the embedded copy instead.
+/// Returns the errors when it declines. The embedded copy is checked by the
+/// test suite, so it is trusted to load.
+pub fn load_story_with(
+    external: &Path,
+    use_embedded: impl FnOnce(&[String]) -> bool,
+) -> Result<StoryData, Vec<String>> {
+    if external.exists() {
+        let source = external.display();
+        let errors = match read_story_file(external) {
+            Err(e) => vec![e],
+            Ok(story_data) => {
+                let errors = story_data.validate();
+                if errors.is_empty() {
+                    log::info!("story_source path={}", source);
+                    return Ok(story_data);
+                }
+                errors
+            }
+        };
+        log::warn!("story_invalid path={} errors={}", source, errors.len());
+        if !use_embedded(&errors) {
+            return Err(errors);
         }
-    };
+        log::info!("story_source embedded fallback_from={}", source);
+    } else {
+        log::info!("story_source embedded");
+    }
+    Ok(embedded_story())
+}
+
+/// The story built into the binary, whatever is on disk.
+pub fn embedded_story() -> StoryData {
+    serde_json::from_str(EMBEDDED_STORY).expect("Failed to parse embedded story data")
+}
+
+/// Story file under the working directory, used when the data directory has none.
+pub const EXTERNAL_STORY_PATH: &str = "data/story.json";
 
-    let errors = story_data.validate();
-    if !errors.is_empty() {
-        eprintln!("Story validation errors:");
-        for e in &errors {
-            eprintln!("  - {}", e);
+/// Timings of repeated story loads from one source (`--bench-load`).
+#[derive(Debug, Clone)]
+pub struct LoadBench {
+    /// "embedded" or the file path
+    pub source: String,
+    /// Number of nodes in the parsed story
+    pub nodes: usize,
+    /// Time spent parsing the JSON, one sample per iteration
+    pub parse: Vec<Duration>,
+    /// Time spent in `validate`, one sample per iteration
+    pub validate: Vec<Duration>,
+}
+
+impl LoadBench {
+    /// Min, median and max of a set of samples.
+    pub fn summary(samples: &[Duration]) -> (Duration, Duration, Duration) {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        match (sorted.first(), sorted.last()) {
+            (Some(&min), Some(&max)) => (min, sorted[sorted.len() / 2], max),
+            _ => Default::default(),
+        }
+    }
+}
+
+/// Time parsing and validating the embedded story, and the on-disk one if
+/// present, `iterations` times each. Reading the file is not timed.
+pub fn bench_load(iterations: usize) -> Result<Vec<LoadBench>, String> {
+    let mut sources = vec![("embedded".to_string(), EMBEDDED_STORY.to_string())];
+    let external = crate::game::story_path();
+    if external.exists() {
+        let json = std::fs::read_to_string(&external)
+            .map_err(|e| format!("{}: {}", external.display(), e))?;
+        sources.push((external.display().to_string(), json));
+    }
+
+    let mut results = Vec::new();
+    for (source, json) in sources {
+        let mut bench = LoadBench {
+            source,
+            nodes: 0,
+            parse: Vec::with_capacity(iterations),
+            validate: Vec::with_capacity(iterations),
+        };
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let story: StoryData =
+                serde_json::from_str(&json).map_err(|e| format!("{}: {}", bench.source, e))?;
+            bench.parse.push(start.elapsed());
+
+            let start = Instant::now();
+            std::hint::black_box(story.validate());
+            bench.validate.push(start.elapsed());
+            bench.nodes = story.nodes.len();
+        }
+        results.push(bench);
+    }
+    Ok(results)
+}
+
+// ── Graph export ─────────────────────────────────────────────
+
+/// Quote a string for a DOT label, keeping line breaks as `\n`.
+fn dot_quote(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+/// The story graph in Graphviz DOT, in node id order so the output diffs
+/// cleanly. `next_node` edges are plain, choice edges bold and labelled,
+/// branch edges dotted, and the death check a dashed edge from a "health 0"
+/// marker. Nodes are labelled with their title when they have one; ending nodes
+/// are filled and titled with their ending.
+pub fn export_dot(story: &StoryData) -> String {
+    let mut out = String::from("digraph story {\n    rankdir=LR;\n    node [shape=box];\n");
+    let mut ids: Vec<&String> = story.nodes.keys().collect();
+    ids.sort();
+
+    for id in &ids {
+        let node = &story.nodes[*id];
+        match node.ending {
+            Some(ref ending) => {
+                let title = story
+                    .endings
+                    .get(ending)
+                    .map(|info| info.title.en.as_str())
+                    .unwrap_or(ending);
+                out.push_str(&format!(
+                    "    {} [label={}, style=filled, fillcolor=lightgoldenrod];\n",
+                    dot_quote(id),
+                    dot_quote(&format!("{}\n{}", id, title))
+                ));
+            }
+            None => {
+                let mut attrs = Vec::new();
+                if let Some(ref title) = node.title {
+                    attrs.push(format!(
+                        "label={}",
+                        dot_quote(&format!("{}\n{}", id, title))
+                    ));
+                }
+                if **id == story.meta.start_node {
+                    attrs.push("penwidth=2".to_string());
+                }
+                if attrs.is_empty() {
+                    out.push_str(&format!("    {};\n", dot_quote(id)));
+                } else {
+                    out.push_str(&format!("    {} [{}];\n", dot_quote(id), attrs.join(", ")));
+                }
+            }
         }
-        panic!(
-            "Story data has {} validation error(s). Fix data/story.json and try again.",
-            errors.len()
-        );
+    }
+    if story
+        .nodes
+        .values()
+        .any(|n| n.targets().any(|t| t == RETURN_NODE))
+    {
+        out.push_str(&format!(
+            "    {} [shape=plaintext];\n",
+            dot_quote(RETURN_NODE)
+        ));
     }
 
-    story_data
+    for id in &ids {
+        let node = &story.nodes[*id];
+        let from = dot_quote(id);
+        if let Some(ref next) = node.next_node {
+            out.push_str(&format!("    {} -> {};\n", from, dot_quote(next)));
+        }
+        for choice in node.choices.iter().flatten() {
+            out.push_str(&format!(
+                "    {} -> {} [style=bold, label={}];\n",
+                from,
+                dot_quote(&choice.next_node),
+                dot_quote(&choice.label.en)
+            ));
+        }
+        for branch in node.branch.iter().flatten() {
+            out.push_str(&format!(
+                "    {} -> {} [style=dotted];\n",
+                from,
+                dot_quote(&branch.next_node)
+            ));
+        }
+    }
+    if let Some(ref death) = story.death_check {
+        out.push_str("    \"health 0\" [shape=plaintext];\n");
+        out.push_str(&format!(
+            "    \"health 0\" -> {} [style=dashed];\n",
+            dot_quote(&death.override_next_node)
+        ));
+    }
+    out.push_str("}\n");
+    out
 }
 
 // ── Validation ───────────────────────────────────────────────
 
 impl StoryData {
+    /// List every story string with no text for `lang` (shown in English instead).
+    pub fn missing_translations(&self, lang: Language) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        for id in node_ids {
+            let node = &self.nodes[id];
+            for (i, msg) in node.messages.iter().enumerate() {
+                if msg.text.is_missing(lang) {
+                    missing.push(format!("node '{}': message {}", id, i + 1));
+                }
+            }
+            for (i, msg) in node.trust_bonus_messages.iter().enumerate() {
+                if msg.text.is_missing(lang) {
+                    missing.push(format!("node '{}': trust bonus message {}", id, i + 1));
+                }
+            }
+            for (i, choice) in node.choices.iter().flatten().enumerate() {
+                if choice.label.is_missing(lang) {
+                    missing.push(format!("node '{}': choice {}", id, i + 1));
+                }
+                if choice.confirm.as_ref().is_some_and(|c| c.is_missing(lang)) {
+                    missing.push(format!("node '{}': choice {} confirm", id, i + 1));
+                }
+                if choice
+                    .locked_hint
+                    .as_ref()
+                    .is_some_and(|h| h.is_missing(lang))
+                {
+                    missing.push(format!("node '{}': choice {} locked hint", id, i + 1));
+                }
+            }
+            if let Some(ref delay) = node.delay {
+                if delay.message.is_missing(lang) {
+                    missing.push(format!("node '{}': delay message", id));
+                }
+            }
+            if node.checkpoint.as_ref().is_some_and(|c| c.is_missing(lang)) {
+                missing.push(format!("node '{}': checkpoint", id));
+            }
+            if node.commentary.as_ref().is_some_and(|c| c.is_missing(lang)) {
+                missing.push(format!("node '{}': commentary", id));
+            }
+        }
+
+        let mut ending_keys: Vec<&String> = self.endings.keys().collect();
+        ending_keys.sort();
+        for key in ending_keys {
+            let info = &self.endings[key];
+            if info.title.is_missing(lang) {
+                missing.push(format!("ending '{}': title", key));
+            }
+            if info
+                .description
+                .as_ref()
+                .is_some_and(|d| d.is_missing(lang))
+            {
+                missing.push(format!("ending '{}': description", key));
+            }
+        }
+
+        for (act, text) in &self.meta.signal {
+            if text.is_missing(lang) {
+                missing.push(format!("signal act {}", act));
+            }
+        }
+        for (act, title) in &self.meta.acts {
+            if title.is_missing(lang) {
+                missing.push(format!("act {} title", act));
+            }
+        }
+
+        let mut flag_keys: Vec<&String> = self.flags.keys().collect();
+        flag_keys.sort();
+        for key in flag_keys {
+            if let Some(ref label) = self.flags[key].label {
+                if label.is_missing(lang) {
+                    missing.push(format!("flag '{}': label", key));
+                }
+            }
+        }
+
+        for (id, entry) in &self.glossary {
+            if entry.term.is_missing(lang) {
+                missing.push(format!("glossary '{}': term", id));
+            }
+            if entry.definition.is_missing(lang) {
+                missing.push(format!("glossary '{}': definition", id));
+            }
+        }
+
+        for (id, character) in &self.epilogues {
+            if character.name.is_missing(lang) {
+                missing.push(format!("epilogue '{}': name", id));
+            }
+            for (i, variant) in character.variants.iter().enumerate() {
+                if variant.text.is_missing(lang) {
+                    missing.push(format!("epilogue '{}': variant {}", id, i + 1));
+                }
+            }
+        }
+
+        for (id, name) in &self.characters {
+            if name.is_missing(lang) {
+                missing.push(format!("character '{}': name", id));
+            }
+        }
+
+        missing
+    }
+
+    /// Every node's text side by side in the given languages, grouped by
+    /// node and ordered by node id so translation passes diff cleanly.
+    pub fn text_dump(&self, langs: &[Language]) -> String {
+        fn block(out: &mut String, heading: &str, text: &LocalizedString, langs: &[Language]) {
+            out.push_str(heading);
+            out.push('\n');
+            for &lang in langs {
+                let raw = text.raw(lang);
+                let shown = if raw.is_empty() { "(missing)" } else { raw };
+                out.push_str(&format!("  {}: {}\n", lang.code(), shown));
+            }
+        }
+
+        let mut out = String::new();
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        for id in node_ids {
+            let node = &self.nodes[id];
+            out.push_str(&format!("== node {} ==\n", id));
+            for (i, msg) in node.trust_bonus_messages.iter().enumerate() {
+                block(
+                    &mut out,
+                    &format!("trust bonus message {}", i + 1),
+                    &msg.text,
+                    langs,
+                );
+            }
+            for (i, msg) in node.messages.iter().enumerate() {
+                block(&mut out, &format!("message {}", i + 1), &msg.text, langs);
+            }
+            for (i, choice) in node.choices.iter().flatten().enumerate() {
+                block(&mut out, &format!("choice {}", i + 1), &choice.label, langs);
+                if let Some(ref confirm) = choice.confirm {
+                    block(
+                        &mut out,
+                        &format!("choice {} confirm", i + 1),
+                        confirm,
+                        langs,
+                    );
+                }
+                if let Some(ref hint) = choice.locked_hint {
+                    block(
+                        &mut out,
+                        &format!("choice {} locked hint", i + 1),
+                        hint,
+                        langs,
+                    );
+                }
+            }
+            if let Some(ref delay) = node.delay {
+                block(&mut out, "delay message", &delay.message, langs);
+            }
+            if let Some(ref checkpoint) = node.checkpoint {
+                block(&mut out, "checkpoint", checkpoint, langs);
+            }
+            if let Some(ref commentary) = node.commentary {
+                block(&mut out, "commentary", commentary, langs);
+            }
+            out.push('\n');
+        }
+        let mut ending_keys: Vec<&String> = self.endings.keys().collect();
+        ending_keys.sort();
+        for key in ending_keys {
+            let info = &self.endings[key];
+            out.push_str(&format!("== ending {} ==\n", key));
+            block(&mut out, "title", &info.title, langs);
+            if let Some(ref description) = info.description {
+                block(&mut out, "description", description, langs);
+            }
+            out.push('\n');
+        }
+        let mut flag_keys: Vec<&String> = self.flags.keys().collect();
+        flag_keys.sort();
+        for key in flag_keys {
+            if let Some(ref label) = self.flags[key].label {
+                out.push_str(&format!("== flag {} ==\n", key));
+                block(&mut out, "label", label, langs);
+                out.push('\n');
+            }
+        }
+        for (id, entry) in &self.glossary {
+            out.push_str(&format!("== glossary {} ==\n", id));
+            block(&mut out, "term", &entry.term, langs);
+            block(&mut out, "definition", &entry.definition, langs);
+            out.push('\n');
+        }
+        for (id, character) in &self.epilogues {
+            out.push_str(&format!("== epilogue {} ==\n", id));
+            block(&mut out, "name", &character.name, langs);
+            for (i, variant) in character.variants.iter().enumerate() {
+                block(
+                    &mut out,
+                    &format!("variant {}", i + 1),
+                    &variant.text,
+                    langs,
+                );
+            }
+            out.push('\n');
+        }
+        for (id, name) in &self.characters {
+            out.push_str(&format!("== character {} ==\n", id));
+            block(&mut out, "name", name, langs);
+            out.push('\n');
+        }
+        for (act, title) in &self.meta.acts {
+            out.push_str(&format!("== act {} ==\n", act));
+            block(&mut out, "title", title, langs);
+            out.push('\n');
+        }
+        for (act, signal) in &self.meta.signal {
+            out.push_str(&format!("== signal act {} ==\n", act));
+            block(&mut out, "signal", signal, langs);
+            out.push('\n');
+        }
+        out
+    }
+
     /// Validate the story graph for structural integrity.
     /// Returns a list of errors (empty = valid).
     pub fn validate(&self) -> Vec<String> {
-        use std::collections::{HashSet, VecDeque};
-
         let mut errors = Vec::new();
         let start = &self.meta.start_node;
 
@@ -314,6 +1286,34 @@ impl StoryData {
         }
 
         // 2. All referenced nodes must exist
+        if let Some(ref fallback) = self.meta.fallback_node {
+            if !self.nodes.contains_key(fallback) {
+                errors.push(format!("meta.fallback_node '{}' doesn't exist", fallback));
+            }
+        }
+        let target_exists = |id: &String| id == RETURN_NODE || self.nodes.contains_key(id);
+        if let Some(ref interrupt) = self.meta.supplies_depleted_node {
+            if !self.nodes.contains_key(interrupt) {
+                errors.push(format!(
+                    "meta.supplies_depleted_node '{}' doesn't exist",
+                    interrupt
+                ));
+            } else if !self.leads_to_return(interrupt) {
+                errors.push(format!(
+                    "meta.supplies_depleted_node '{}' never leads back (no \"{}\" reachable)",
+                    interrupt, RETURN_NODE
+                ));
+            }
+        } else if self
+            .nodes
+            .values()
+            .any(|n| n.targets().any(|t| t == RETURN_NODE))
+        {
+            errors.push(format!(
+                "\"{}\" is used but meta.supplies_depleted_node is not set",
+                RETURN_NODE
+            ));
+        }
         for (id, node) in &self.nodes {
             if node.delay.is_some() && node.choices.as_ref().is_some_and(|c| !c.is_empty()) {
                 errors.push(format!(
@@ -323,7 +1323,7 @@ impl StoryData {
             }
 
             if let Some(ref next) = node.next_node {
-                if !self.nodes.contains_key(next) {
+                if !target_exists(next) {
                     errors.push(format!(
                         "Node '{}' references next_node '{}' which doesn't exist",
                         id, next
@@ -332,7 +1332,7 @@ impl StoryData {
             }
             if let Some(ref choices) = node.choices {
                 for choice in choices {
-                    if !self.nodes.contains_key(&choice.next_node) {
+                    if !target_exists(&choice.next_node) {
                         errors.push(format!(
                             "Node '{}' has choice pointing to '{}' which doesn't exist",
                             id, choice.next_node
@@ -340,15 +1340,48 @@ impl StoryData {
                     }
                 }
             }
-            if let Some(ref branches) = node.branch {
-                for branch in branches {
-                    if !self.nodes.contains_key(&branch.next_node) {
-                        errors.push(format!(
-                            "Node '{}' has branch pointing to '{}' which doesn't exist",
-                            id, branch.next_node
-                        ));
-                    }
-                }
+            for (index, message) in node.messages.iter().enumerate() {
+                if message.reveal_ms.is_some_and(|ms| ms > MAX_REVEAL_MS) {
+                    errors.push(format!(
+                        "Node '{}' message {} has reveal_ms above {}",
+                        id, index, MAX_REVEAL_MS
+                    ));
+                }
+                if message
+                    .pause_after_ms
+                    .is_some_and(|ms| ms > MAX_PAUSE_AFTER_MS)
+                {
+                    errors.push(format!(
+                        "Node '{}' message {} has pause_after_ms above {}",
+                        id, index, MAX_PAUSE_AFTER_MS
+                    ));
+                }
+            }
+            for character in self.unknown_characters(node.texts()) {
+                errors.push(format!(
+                    "Node '{}' refers to unknown character '{}'",
+                    id, character
+                ));
+            }
+            if let Some(ref branches) = node.branch {
+                for branch in branches {
+                    if !target_exists(&branch.next_node) {
+                        errors.push(format!(
+                            "Node '{}' has branch pointing to '{}' which doesn't exist",
+                            id, branch.next_node
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (id, epilogue) in &self.epilogues {
+            let texts = epilogue.variants.iter().map(|v| &v.text);
+            for character in self.unknown_characters(texts) {
+                errors.push(format!(
+                    "Epilogue '{}' refers to unknown character '{}'",
+                    id, character
+                ));
             }
         }
 
@@ -365,12 +1398,80 @@ impl StoryData {
                     id
                 ));
             }
+
+            // An ending must always have something to say
+            let all_conditional =
+                !node.messages.is_empty() && node.messages.iter().all(|m| m.condition.is_some());
+            if has_ending && all_conditional {
+                errors.push(format!(
+                    "Ending node '{}' only has conditional messages and could display nothing",
+                    id
+                ));
+            }
         }
 
         // 4. All nodes reachable from start
+        let visited = self.reachable();
+        let unreachable: Vec<_> = self
+            .nodes
+            .keys()
+            .filter(|k| !visited.contains(*k))
+            .collect();
+        if !unreachable.is_empty() {
+            errors.push(format!("Unreachable nodes: {:?}", unreachable));
+        }
+
+        // 5. At least one ending node exists
+        let ending_count = self.nodes.values().filter(|n| n.ending.is_some()).count();
+        if ending_count == 0 {
+            errors.push("No ending nodes found in the story".to_string());
+        }
+
+        // 6. No loops the player can never leave
+        let looped = self.endless_loops(&visited);
+        if ending_count > 0 && !looped.is_empty() {
+            errors.push(format!(
+                "Nodes loop forever without a way to an ending: {:?}",
+                looped
+            ));
+        }
+
+        errors
+    }
+
+    /// One-line overview for `--validate`: node count, how many are
+    /// reachable, and the endings.
+    pub fn summary(&self) -> String {
+        let reachable = self.reachable();
+        let endings: BTreeSet<&str> = self
+            .nodes
+            .values()
+            .filter_map(|n| n.ending.as_deref())
+            .collect();
+        format!(
+            "{} nodes, {} reachable from '{}', {} ending(s): {}",
+            self.nodes.len(),
+            self.nodes.keys().filter(|k| reachable.contains(*k)).count(),
+            self.meta.start_node,
+            endings.len(),
+            endings.into_iter().collect::<Vec<_>>().join(", ")
+        )
+    }
+
+    /// Node ids the story can get to: from the start node, plus the fallback,
+    /// supplies and death nodes, which can be entered from anywhere.
+    fn reachable(&self) -> HashSet<String> {
+        let start = &self.meta.start_node;
         let mut visited = HashSet::new();
         let mut queue = VecDeque::new();
         queue.push_back(start.clone());
+        // The fallback and supplies nodes can be entered from anywhere
+        if let Some(ref fallback) = self.meta.fallback_node {
+            queue.push_back(fallback.clone());
+        }
+        if let Some(ref interrupt) = self.meta.supplies_depleted_node {
+            queue.push_back(interrupt.clone());
+        }
 
         while let Some(id) = queue.pop_front() {
             if visited.contains(&id) {
@@ -399,29 +1500,189 @@ impl StoryData {
         if let Some(ref dc) = self.death_check {
             visited.insert(dc.override_next_node.clone());
         }
+        visited
+    }
 
-        let unreachable: Vec<_> = self
+    /// Character ids used in `texts` (either language) that aren't defined.
+    fn unknown_characters<'a>(
+        &self,
+        texts: impl Iterator<Item = &'a LocalizedString>,
+    ) -> BTreeSet<&'a str> {
+        texts
+            .flat_map(|text| {
+                character_refs(&text.en)
+                    .chain(character_refs(&text.fr))
+                    .chain(character_refs(&text.de))
+            })
+            .filter(|id| !self.characters.contains_key(*id))
+            .collect()
+    }
+
+    /// Reachable nodes that sit on a cycle from which no path leads to an
+    /// ending (or back out through `RETURN_NODE`). Loops with an exit are fine.
+    fn endless_loops(&self, reachable: &HashSet<String>) -> Vec<&str> {
+        // Grow the set of nodes that can still get out, starting from the exits
+        let mut escapes: BTreeSet<&str> = self
+            .nodes
+            .iter()
+            .filter(|(_, n)| n.ending.is_some() || n.targets().any(|t| t == RETURN_NODE))
+            .map(|(id, _)| id.as_str())
+            .collect();
+        loop {
+            let before = escapes.len();
+            for (id, node) in &self.nodes {
+                if node.targets().any(|t| escapes.contains(t)) {
+                    escapes.insert(id);
+                }
+            }
+            if escapes.len() == before {
+                break;
+            }
+        }
+
+        let trapped: BTreeSet<&str> = self
             .nodes
             .keys()
-            .filter(|k| !visited.contains(*k))
+            .map(String::as_str)
+            .filter(|id| reachable.contains(*id) && !escapes.contains(id))
             .collect();
-        if !unreachable.is_empty() {
-            errors.push(format!("Unreachable nodes: {:?}", unreachable));
+        // Only report nodes that lead back to themselves; the rest just feed
+        // into a loop (or a dead end, which is reported on its own)
+        trapped
+            .iter()
+            .copied()
+            .filter(|&start| {
+                let mut seen = BTreeSet::new();
+                let mut stack: Vec<&str> = self.nodes[start].targets().collect();
+                while let Some(id) = stack.pop() {
+                    if id == start {
+                        return true;
+                    }
+                    if trapped.contains(id) && seen.insert(id) {
+                        stack.extend(self.nodes[id].targets());
+                    }
+                }
+                false
+            })
+            .collect()
+    }
+
+    /// Whether some path from `from` reaches a `RETURN_NODE` edge.
+    fn leads_to_return(&self, from: &str) -> bool {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![from];
+        while let Some(id) = stack.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(id) else {
+                continue;
+            };
+            for target in node.targets() {
+                if target == RETURN_NODE {
+                    return true;
+                }
+                stack.push(target);
+            }
         }
+        false
+    }
 
-        // 5. At least one ending node exists
-        let ending_count = self.nodes.values().filter(|n| n.ending.is_some()).count();
-        if ending_count == 0 {
-            errors.push("No ending nodes found in the story".to_string());
+    /// Flag bookkeeping warnings: flags set but never read, flags read but
+    /// never set (likely typos), and flags missing from the `flags` table.
+    /// These never make a story invalid.
+    pub fn flag_warnings(&self) -> Vec<String> {
+        let mut set: BTreeSet<&str> = BTreeSet::new();
+        let mut read: BTreeSet<&str> = BTreeSet::new();
+
+        if let Some(ref rule) = self.meta.trust_decay {
+            set.insert(&rule.flag);
+        }
+        for info in self.endings.values() {
+            set.extend(info.unlocks.iter().map(String::as_str));
+        }
+        for node in self.nodes.values() {
+            let effects = node.on_enter.iter().chain(
+                node.choices
+                    .iter()
+                    .flatten()
+                    .filter_map(|c| c.on_choose.as_ref()),
+            );
+            for effects in effects {
+                set.extend(effects.flags_set.iter().map(String::as_str));
+                if effects.has_medicine_conditional == Some(true) {
+                    read.insert(MEDICINE_FLAG);
+                }
+            }
+
+            let conditions = node.branch.iter().flatten().map(|b| &b.condition).chain(
+                node.messages
+                    .iter()
+                    .chain(&node.trust_bonus_messages)
+                    .filter_map(|m| m.condition.as_ref())
+                    .chain(
+                        node.choices
+                            .iter()
+                            .flatten()
+                            .filter_map(|c| c.condition.as_ref()),
+                    ),
+            );
+            for condition in conditions {
+                read.extend(condition.flags());
+            }
         }
+        for character in self.epilogues.values() {
+            for variant in &character.variants {
+                read.extend(variant.condition.flags());
+            }
+        }
+        for info in self.endings.values() {
+            if let Some(ref conditions) = info.conditions {
+                read.extend(conditions.flags_required.iter().map(String::as_str));
+            }
+        }
+        if let Some(flags) = self
+            .death_check
+            .as_ref()
+            .and_then(|dc| dc.condition.as_ref())
+            .and_then(|c| c.get("flags_required"))
+            .and_then(|f| f.as_array())
+        {
+            read.extend(flags.iter().filter_map(|f| f.as_str()));
+        }
+        read.extend(
+            self.glossary
+                .values()
+                .filter_map(|entry| entry.unlock_flag.as_deref()),
+        );
+        // Labeled flags are read by the ending recap
+        read.extend(
+            self.flags
+                .iter()
+                .filter(|(_, info)| info.label.is_some())
+                .map(|(key, _)| key.as_str()),
+        );
 
-        errors
+        let mut warnings = Vec::new();
+        for flag in set.difference(&read) {
+            warnings.push(format!("flag '{}' is set but never read", flag));
+        }
+        for flag in read.difference(&set) {
+            warnings.push(format!("flag '{}' is read but never set", flag));
+        }
+        for flag in set.union(&read) {
+            if !self.flags.contains_key(*flag) {
+                warnings.push(format!("flag '{}' is not documented in \"flags\"", flag));
+            }
+        }
+        warnings
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::GameStateBuilder;
 
     #[test]
     fn test_embedded_json_parses() {
@@ -442,6 +1703,188 @@ mod tests {
         );
     }
 
+    /// Paths of fields in `source` that are missing from `serialized`.
+    fn dropped_fields(
+        source: &serde_json::Value,
+        serialized: &serde_json::Value,
+        path: &str,
+    ) -> Vec<String> {
+        use serde_json::Value;
+        match (source, serialized) {
+            (Value::Object(a), Value::Object(b)) => a
+                .iter()
+                .flat_map(|(key, value)| {
+                    let path = format!("{}/{}", path, key);
+                    match b.get(key) {
+                        Some(other) => dropped_fields(value, other, &path),
+                        None if value.is_null() => Vec::new(),
+                        None => vec![path],
+                    }
+                })
+                .collect(),
+            (Value::Array(a), Value::Array(b)) => a
+                .iter()
+                .zip(b)
+                .enumerate()
+                .flat_map(|(i, (x, y))| dropped_fields(x, y, &format!("{}/{}", path, i)))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_embedded_story_roundtrips_through_story_node() {
+        // Every field the story file uses is part of the one node model, so
+        // writing the loaded story back out loses nothing and is stable
+        let source: serde_json::Value = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let written = serde_json::to_value(&story).unwrap();
+        assert_eq!(dropped_fields(&source, &written, ""), Vec::<String>::new());
+
+        let reloaded: StoryData = serde_json::from_value(written.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&reloaded).unwrap(), written);
+        assert_eq!(reloaded.content_hash(), story.content_hash());
+        assert!(reloaded.validate().is_empty());
+    }
+
+    #[test]
+    fn test_content_hash_ignores_formatting() {
+        let story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let hash = story.content_hash();
+        assert_eq!(hash.len(), 16);
+
+        // Same content, laid out differently
+        let value: serde_json::Value = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        for json in [
+            value.to_string(),
+            serde_json::to_string_pretty(&value).unwrap(),
+            EMBEDDED_STORY.replace("\n", "\n\n  "),
+        ] {
+            let reformatted: StoryData = serde_json::from_str(&json).unwrap();
+            assert_eq!(reformatted.content_hash(), hash);
+        }
+
+        let mut edited = story.clone();
+        let node = edited.nodes.get_mut("a1_first_contact").unwrap();
+        node.messages[0].text.en.push('!');
+        assert_ne!(edited.content_hash(), hash);
+
+        let mut rerouted = story.clone();
+        rerouted.nodes.get_mut("a1_oriented").unwrap().next_node = Some("a1_warehouse".into());
+        assert_ne!(rerouted.content_hash(), hash);
+
+        // Author-only fields don't play back, so they don't count
+        let mut annotated = story.clone();
+        let node = annotated.nodes.get_mut("a1_oriented").unwrap();
+        node.title = Some("Getting bearings".into());
+        node.act = Some(9);
+        annotated.meta.title.push_str(" (draft)");
+        assert_eq!(annotated.content_hash(), hash);
+
+        let mut gated = story.clone();
+        let choices = gated.nodes.get_mut("a1_oriented").unwrap().choices.as_mut();
+        choices.unwrap()[0].condition = Some(BranchCondition {
+            min_trust: Some(5),
+            ..Default::default()
+        });
+        assert_ne!(gated.content_hash(), hash);
+    }
+
+    fn story_with_trust_decay() -> StoryData {
+        let mut story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        story.meta.trust_decay = Some(TrustDecayRule {
+            after_hours: 12,
+            amount: 2,
+            flag: "felt_abandoned".to_string(),
+        });
+        story
+    }
+
+    #[test]
+    fn test_trust_decay_off_by_default() {
+        let story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        assert!(story.meta.trust_decay.is_none());
+
+        let now = chrono::Utc::now();
+        let mut state = GameStateBuilder::new().trust(3).build();
+        state.waiting_until = Some(now - chrono::Duration::days(30));
+        assert!(!story.apply_trust_decay(&mut state, now));
+        assert_eq!(state.stats.trust, 3);
+    }
+
+    #[test]
+    fn test_trust_decay_short_absence() {
+        let story = story_with_trust_decay();
+        let now = chrono::Utc::now();
+        let mut state = GameStateBuilder::new().trust(3).build();
+        state.waiting_until = Some(now - chrono::Duration::hours(11));
+
+        assert!(!story.apply_trust_decay(&mut state, now));
+        assert_eq!(state.stats.trust, 3);
+        assert!(!state.has_flag("felt_abandoned"));
+    }
+
+    #[test]
+    fn test_trust_decay_long_absence() {
+        let story = story_with_trust_decay();
+        let now = chrono::Utc::now();
+        let mut state = GameStateBuilder::new().trust(3).build();
+        state.waiting_until = Some(now - chrono::Duration::hours(12));
+
+        assert!(story.apply_trust_decay(&mut state, now));
+        assert_eq!(state.stats.trust, 1);
+        assert!(state.has_flag("felt_abandoned"));
+
+        // Clamped to the stat's minimum
+        assert!(story.apply_trust_decay(&mut state, now));
+        assert_eq!(state.stats.trust, 0);
+    }
+
+    #[test]
+    fn test_embedded_json_fully_translated() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        assert!(story_data.missing_translations(Language::Fr).is_empty());
+    }
+
+    #[test]
+    fn test_missing_translations_listed() {
+        let story_data: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "start" },
+                "nodes": {
+                    "start": {
+                        "id": "start",
+                        "messages": [{ "en": "Hi", "fr": "Salut" }, { "en": "There", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "Go", "fr": "" }, "next_node": "start" },
+                            {
+                                "label": { "en": "Climb", "fr": "Grimper" },
+                                "next_node": "start",
+                                "condition": { "flags_required": ["has_rope"] },
+                                "show_locked": true,
+                                "locked_hint": { "en": "needs a rope", "fr": "" }
+                            }
+                        ]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            story_data.missing_translations(Language::Fr),
+            vec![
+                "node 'start': message 2",
+                "node 'start': choice 1",
+                "node 'start': choice 2 locked hint"
+            ]
+        );
+        assert!(story_data
+            .text_dump(&[Language::Fr])
+            .contains("choice 2 locked hint\n  fr: (missing)\n"));
+        let node = &story_data.nodes["start"];
+        assert_eq!(node.messages[1].get(Language::Fr), "There");
+    }
+
     #[test]
     fn test_embedded_json_has_all_endings() {
         let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
@@ -453,6 +1896,28 @@ mod tests {
         assert!(story_data.ending_info("echo").is_some());
     }
 
+    #[test]
+    fn test_every_ending_has_a_title_and_description_in_both_languages() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        for (key, info) in &story_data.endings {
+            let description = info.description.as_ref();
+            for lang in [Language::En, Language::Fr] {
+                assert!(!info.title.is_missing(lang), "{key}: no {lang:?} title");
+                assert!(
+                    description.is_some_and(|d| !d.is_missing(lang)),
+                    "{key}: no {lang:?} description"
+                );
+                let (title, description) = story_data.ending_text(key, lang);
+                assert_eq!(title, info.title.get(lang));
+                assert!(description.is_some());
+            }
+        }
+        assert_eq!(
+            story_data.ending_text("nowhere", Language::Fr),
+            ("nowhere".to_string(), None)
+        );
+    }
+
     #[test]
     fn test_embedded_json_meta() {
         let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
@@ -486,7 +1951,7 @@ mod tests {
             default: true,
             ..Default::default()
         };
-        let state = crate::game::GameState::new(crate::i18n::Language::En, "test", 3, 10, 3);
+        let state = GameStateBuilder::new().build();
         assert!(cond.evaluate(&state));
     }
 
@@ -496,10 +1961,74 @@ mod tests {
             flags_required: vec!["has_shielding".to_string()],
             ..Default::default()
         };
-        let mut state = crate::game::GameState::new(crate::i18n::Language::En, "test", 3, 10, 3);
+        let state = GameStateBuilder::new().build();
+        assert!(!cond.evaluate(&state));
+        let state = GameStateBuilder::new().flag("has_shielding").build();
+        assert!(cond.evaluate(&state));
+    }
+
+    #[test]
+    fn test_branch_condition_flags_forbidden() {
+        let cond = BranchCondition {
+            flags_forbidden: vec!["felt_abandoned".to_string()],
+            ..Default::default()
+        };
+        let state = GameStateBuilder::new().build();
+        assert!(cond.evaluate(&state));
+        let state = GameStateBuilder::new().flag("felt_abandoned").build();
         assert!(!cond.evaluate(&state));
-        state.set_flag("has_shielding");
+    }
+
+    #[test]
+    fn test_branch_condition_any_of() {
+        let cond: BranchCondition = serde_json::from_str(
+            r#"{ "min_health": 3,
+                 "any_of": [{ "flags_required": ["has_shielding"] }, { "min_trust": 7 }] }"#,
+        )
+        .unwrap();
+        let state = GameStateBuilder::new().trust(3).health(10).build();
+        assert!(!cond.evaluate(&state));
+        let state = GameStateBuilder::new()
+            .trust(3)
+            .health(10)
+            .flag("has_shielding")
+            .build();
+        assert!(cond.evaluate(&state));
+        let state = GameStateBuilder::new().trust(8).health(10).build();
         assert!(cond.evaluate(&state));
+        // The group is ANDed with the rest of the condition
+        let state = GameStateBuilder::new().trust(8).health(1).build();
+        assert!(!cond.evaluate(&state));
+        assert_eq!(cond.flags(), vec!["has_shielding"]);
+    }
+
+    #[test]
+    fn test_validate_follows_branches_with_new_operators() {
+        let story: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "start" },
+                "flags": { "lost": "Got lost", "found": "Found the way" },
+                "nodes": {
+                    "start": { "id": "start", "messages": [],
+                               "on_enter": { "flags_set": ["lost", "found"] },
+                               "branch": [
+                        { "condition": { "flags_forbidden": ["lost"] }, "next_node": "road" },
+                        { "condition": { "any_of": [{ "flags_required": ["found"] }] },
+                          "next_node": "trail" },
+                        { "condition": { "default": true }, "next_node": "road" }
+                    ] },
+                    "road": { "id": "road", "messages": [{ "en": "Home.", "fr": "" }], "ending": "a" },
+                    "trail": { "id": "trail", "messages": [{ "en": "Away.", "fr": "" }], "ending": "b" }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(story.validate().is_empty(), "{:?}", story.validate());
+        assert!(
+            story.flag_warnings().is_empty(),
+            "{:?}",
+            story.flag_warnings()
+        );
     }
 
     #[test]
@@ -508,10 +2037,10 @@ mod tests {
             min_trust: Some(7),
             ..Default::default()
         };
-        let mut state = crate::game::GameState::new(crate::i18n::Language::En, "test", 3, 10, 3);
-        assert!(!cond.evaluate(&state)); // trust is 3
-        state.stats.trust = 8;
-        assert!(cond.evaluate(&state)); // trust is 8
+        let state = GameStateBuilder::new().trust(3).build();
+        assert!(!cond.evaluate(&state));
+        let state = GameStateBuilder::new().trust(8).build();
+        assert!(cond.evaluate(&state));
     }
 
     #[test]
@@ -523,8 +2052,9 @@ mod tests {
             flags_set: vec!["test_flag".to_string()],
             flags_remove: vec![],
             has_medicine_conditional: None,
+            scale_with_trust: false,
         };
-        let mut state = crate::game::GameState::new(crate::i18n::Language::En, "test", 3, 10, 3);
+        let mut state = GameStateBuilder::new().trust(3).health(10).build();
         let health_changed = effects.apply(&mut state);
         assert!(health_changed);
         assert_eq!(state.stats.trust, 5);
@@ -532,6 +2062,264 @@ mod tests {
         assert!(state.has_flag("test_flag"));
     }
 
+    #[test]
+    fn test_effects_medicine_conditional() {
+        let effects = Effects {
+            health_change: Some(2),
+            supplies_change: Some(1),
+            has_medicine_conditional: Some(true),
+            ..Default::default()
+        };
+
+        let mut without = GameStateBuilder::new().health(5).supplies(2).build();
+        assert!(!effects.apply(&mut without));
+        assert_eq!(without.stats.health, 5);
+        assert_eq!(without.stats.supplies, 3);
+
+        let mut with = GameStateBuilder::new()
+            .health(5)
+            .supplies(2)
+            .flag("has_medicine")
+            .build();
+        assert!(effects.apply(&mut with));
+        assert_eq!(with.stats.health, 7);
+        assert_eq!(with.stats.supplies, 3);
+    }
+
+    #[test]
+    fn test_effects_scale_with_trust() {
+        let effects = Effects {
+            trust_change: Some(2),
+            supplies_change: Some(3),
+            scale_with_trust: true,
+            ..Default::default()
+        };
+
+        // Advice lands softly when Elara barely trusts you
+        let mut wary = GameStateBuilder::new().trust(1).supplies(2).build();
+        effects.apply(&mut wary);
+        assert_eq!(wary.stats.trust, 2);
+        assert_eq!(wary.stats.supplies, 4);
+
+        // ...and lands hard when she does, clamped to the stat bounds
+        let mut close = GameStateBuilder::new().trust(8).supplies(2).build();
+        effects.apply(&mut close);
+        assert_eq!(close.stats.trust, 10);
+        assert_eq!(close.stats.supplies, 6);
+
+        assert_eq!(trust_scale(5), 1.0);
+        assert_eq!(trust_scale(-3), 0.5);
+    }
+
+    #[test]
+    fn test_text_dump_groups_by_sorted_node() {
+        let story: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "b" },
+                "nodes": {
+                    "b": {
+                        "id": "b",
+                        "messages": [{ "en": "Hi", "fr": "Salut" }],
+                        "choices": [{ "label": { "en": "Go", "fr": "" }, "next_node": "a" }]
+                    },
+                    "a": { "id": "a", "messages": [{ "en": "Bye", "fr": "Ciao" }], "ending": "x" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let dump = story.text_dump(&[Language::En, Language::Fr]);
+        assert_eq!(
+            dump,
+            "== node a ==\nmessage 1\n  en: Bye\n  fr: Ciao\n\n\
+             == node b ==\nmessage 1\n  en: Hi\n  fr: Salut\n\
+             choice 1\n  en: Go\n  fr: (missing)\n\n"
+        );
+
+        // Story-wide strings follow the nodes
+        let embedded: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let dump = embedded.text_dump(&[Language::En]);
+        assert!(dump.contains("== act 2 ==\ntitle\n  en: "));
+        assert!(dump.contains("== signal act 1 ==\nsignal\n  en: "));
+        assert!(dump.contains("== ending echo ==\ntitle\n  en: "));
+        assert!(dump.contains("\ndescription\n  en: "));
+        assert!(dump.contains("== flag has_shielding ==\nlabel\n  en: Found shielding\n"));
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_message_timing() {
+        let mut story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let node = story_data.nodes.get_mut("a1_first_contact").unwrap();
+        node.messages[0].reveal_ms = Some(MAX_REVEAL_MS + 1);
+        node.messages[1].pause_after_ms = Some(MAX_PAUSE_AFTER_MS + 1);
+
+        let errors = story_data.validate();
+        assert!(errors.iter().any(|e| e.contains("reveal_ms above")));
+        assert!(errors.iter().any(|e| e.contains("pause_after_ms above")));
+    }
+
+    #[test]
+    fn test_validate_checks_fallback_node() {
+        let mut story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        story_data.meta.fallback_node = Some("nowhere".to_string());
+        assert!(story_data
+            .validate()
+            .iter()
+            .any(|e| e.contains("fallback_node 'nowhere'")));
+
+        story_data.meta.fallback_node = Some(story_data.meta.start_node.clone());
+        assert!(story_data.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_checks_supplies_depleted_node() {
+        let json = r#"{
+            "meta": { "title": "Test", "version": "1", "start_node": "start" META },
+            "nodes": {
+                "start": { "id": "start", "messages": [], "next_node": "end" },
+                "end": { "id": "end", "messages": [{ "en": "Bye", "fr": "" }], "ending": "x" },
+                "hungry": { "id": "hungry", "messages": [], "choices": [
+                    { "label": { "en": "Eat", "fr": "" }, "next_node": "TARGET" }
+                ] }
+            }
+        }"#;
+        let validate = |meta: &str, target: &str| {
+            let story: StoryData =
+                serde_json::from_str(&json.replace("META", meta).replace("TARGET", target))
+                    .unwrap();
+            story.validate()
+        };
+        let meta = r#", "supplies_depleted_node": "hungry""#;
+
+        assert!(validate(meta, RETURN_NODE).is_empty());
+        assert!(validate(meta, "end")
+            .iter()
+            .any(|e| e.contains("'hungry' never leads back")));
+        assert!(
+            validate(r#", "supplies_depleted_node": "famine""#, RETURN_NODE)
+                .iter()
+                .any(|e| e.contains("supplies_depleted_node 'famine' doesn't exist"))
+        );
+        assert!(validate("", RETURN_NODE)
+            .iter()
+            .any(|e| e.contains("supplies_depleted_node is not set")));
+    }
+
+    #[test]
+    fn test_validate_reports_loops_without_an_ending() {
+        let json = r#"{
+            "meta": { "title": "Test", "version": "1", "start_node": "start" },
+            "nodes": {
+                "start": { "id": "start", "messages": [], "choices": [
+                    { "label": { "en": "Rest", "fr": "" }, "next_node": "camp" },
+                    { "label": { "en": "Leave", "fr": "" }, "next_node": "end" }
+                ] },
+                "camp": { "id": "camp", "messages": [], "next_node": "sleep" },
+                "sleep": { "id": "sleep", "messages": [],
+                           "delay": { "seconds": 600, "message": { "en": "Zzz", "fr": "" } },
+                           REST },
+                "end": { "id": "end", "messages": [{ "en": "Bye", "fr": "" }], "ending": "x" }
+            }
+        }"#;
+        let validate = |rest: &str| {
+            let story: StoryData = serde_json::from_str(&json.replace("REST", rest)).unwrap();
+            story.validate()
+        };
+
+        let errors = validate(r#""next_node": "camp""#);
+        assert_eq!(
+            errors,
+            vec![r#"Nodes loop forever without a way to an ending: ["camp", "sleep"]"#]
+        );
+
+        // A loop through a branch with a way out is fine
+        let errors = validate(
+            r#""branch": [
+                { "condition": { "min_trust": 8 }, "next_node": "end" },
+                { "condition": { "default": true }, "next_node": "camp" }
+            ]"#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_summary_counts_reachable_nodes_and_endings() {
+        let story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let summary = story.summary();
+        assert!(summary.starts_with(&format!(
+            "{} nodes, {} reachable from '{}', ",
+            story.nodes.len(),
+            story.nodes.len(),
+            story.meta.start_node
+        )));
+        assert!(summary.contains("still_here"));
+
+        let path = std::env::temp_dir().join("eshara_summary_island.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "start" },
+                "nodes": {
+                    "start": { "id": "start", "messages": [{ "en": "Bye", "fr": "" }], "ending": "x" },
+                    "island": { "id": "island", "messages": [{ "en": "Alone", "fr": "" }], "ending": "y" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let island = read_story_file(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            island.summary(),
+            "2 nodes, 1 reachable from 'start', 2 ending(s): x, y"
+        );
+        assert!(read_story_file(&path)
+            .unwrap_err()
+            .contains("eshara_summary_island"));
+    }
+
+    #[test]
+    fn test_export_dot_styles_each_kind_of_edge() {
+        let story: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "start" },
+                "endings": { "home": { "title": { "en": "Home \"safe\"", "fr": "" } } },
+                "death_check": { "override_next_node": "gone" },
+                "nodes": {
+                    "start": { "id": "start", "title": "Wake up", "messages": [], "choices": [
+                        { "label": { "en": "Go on", "fr": "" }, "next_node": "fork" }
+                    ] },
+                    "fork": { "id": "fork", "messages": [], "branch": [
+                        { "condition": { "min_trust": 5 }, "next_node": "end" },
+                        { "condition": { "default": true }, "next_node": "walk" }
+                    ] },
+                    "walk": { "id": "walk", "title": "The \"long\" walk", "messages": [], "next_node": "end" },
+                    "end": { "id": "end", "messages": [{ "en": "Bye", "fr": "" }], "ending": "home" },
+                    "gone": { "id": "gone", "messages": [{ "en": "...", "fr": "" }], "ending": "dark" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let dot = export_dot(&story);
+        assert!(dot.starts_with("digraph story {"));
+        assert!(dot.trim_end().ends_with('}'));
+        for line in [
+            r#""start" [label="start\nWake up", penwidth=2];"#,
+            r#""walk" [label="walk\nThe \"long\" walk"];"#,
+            r#""end" [label="end\nHome \"safe\"", style=filled, fillcolor=lightgoldenrod];"#,
+            r#""gone" [label="gone\ndark", style=filled, fillcolor=lightgoldenrod];"#,
+            r#""start" -> "fork" [style=bold, label="Go on"];"#,
+            r#""fork" -> "end" [style=dotted];"#,
+            r#""fork" -> "walk" [style=dotted];"#,
+            r#""walk" -> "end";"#,
+            r#""health 0" -> "gone" [style=dashed];"#,
+        ] {
+            assert!(dot.contains(line), "missing {} in\n{}", line, dot);
+        }
+        // Stable output for diffing
+        assert_eq!(dot, export_dot(&story));
+        assert!(dot.find("\"end\" [").unwrap() < dot.find("\"fork\";").unwrap());
+    }
+
     #[test]
     fn test_validate_rejects_choices_with_delay() {
         let mut story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
@@ -542,10 +2330,7 @@ mod tests {
             .unwrap();
         node.delay = Some(DelayInfo {
             seconds: 1,
-            message: LocalizedString {
-                en: "test".to_string(),
-                fr: "test".to_string(),
-            },
+            message: LocalizedString::new("test", "test"),
         });
 
         let errors = story_data.validate();
@@ -557,4 +2342,290 @@ mod tests {
             errors
         );
     }
+
+    #[test]
+    fn test_bench_load_samples_embedded_story() {
+        let results = bench_load(3).unwrap();
+        let embedded = &results[0];
+        assert_eq!(embedded.source, "embedded");
+        assert!(embedded.nodes > 0);
+        assert_eq!(embedded.parse.len(), 3);
+        assert_eq!(embedded.validate.len(), 3);
+
+        let samples = [3, 1, 2].map(Duration::from_millis);
+        assert_eq!(
+            LoadBench::summary(&samples),
+            (
+                Duration::from_millis(1),
+                Duration::from_millis(2),
+                Duration::from_millis(3)
+            )
+        );
+    }
+
+    #[test]
+    fn test_unlock_glossary_on_flag() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let mut state = GameStateBuilder::new().build();
+
+        // Nothing in the shipped glossary is known before it comes up
+        assert!(story_data.unlock_glossary(&mut state).is_empty());
+
+        state.set_flag("knows_truth");
+        assert_eq!(
+            story_data.unlock_glossary(&mut state),
+            vec!["resonance_emitter", "vasik"]
+        );
+        assert!(story_data.unlock_glossary(&mut state).is_empty());
+
+        // Unlocks outlive the flag
+        state.remove_flag("knows_truth");
+        story_data.unlock_glossary(&mut state);
+        assert!(state.glossary_unlocked.contains("vasik"));
+    }
+
+    #[test]
+    fn test_epilogues_pick_first_matching_variant() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let lang = Language::En;
+        let names = |state: &crate::game::GameState| -> Vec<String> {
+            story_data
+                .epilogues_for(state)
+                .iter()
+                .map(|(name, _)| name.get(lang).to_string())
+                .collect()
+        };
+
+        // Characters never brought up get no epilogue
+        assert!(names(&GameStateBuilder::new().build()).is_empty());
+
+        let state = GameStateBuilder::new()
+            .flag("lina_asked")
+            .flag("lina_opened_up")
+            .build();
+        assert_eq!(names(&state), vec!["Lina"]);
+        let (_, text) = story_data.epilogues_for(&state)[0];
+        assert!(text.get(lang).contains("melody"));
+
+        let state = GameStateBuilder::new().flag("knows_truth").build();
+        let (_, text) = story_data.epilogues_for(&state)[0];
+        assert!(text.get(lang).contains("journal"));
+    }
+
+    #[test]
+    fn test_flag_warnings() {
+        let story_data: StoryData = serde_json::from_str(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "start" },
+                "flags": { "orphan": "Set and forgotten", "shown": { "description": "Recapped",
+                           "label": { "en": "Shown", "fr": "Montré" } } },
+                "nodes": {
+                    "start": {
+                        "id": "start",
+                        "on_enter": { "flags_set": ["shown", "checked"] },
+                        "choices": [{ "label": { "en": "Go", "fr": "Va" }, "next_node": "start",
+                                      "on_choose": { "flags_set": ["orphan"] } }],
+                        "branch": [{ "condition": { "flags_required": ["checked", "chekced"] },
+                                     "next_node": "start" }]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            story_data.flag_warnings(),
+            vec![
+                "flag 'orphan' is set but never read",
+                "flag 'chekced' is read but never set",
+                "flag 'checked' is not documented in \"flags\"",
+                "flag 'chekced' is not documented in \"flags\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flag_info_accepts_plain_and_labeled_entries() {
+        let flags: HashMap<String, FlagInfo> = serde_json::from_str(
+            r#"{
+                "plain": "Just a description",
+                "labeled": {
+                    "description": "With a label",
+                    "label": { "en": "Labeled", "fr": "Étiqueté" }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(flags["plain"].description, "Just a description");
+        assert!(flags["plain"].label.is_none());
+        assert_eq!(flags["labeled"].label.as_ref().unwrap().fr, "Étiqueté");
+    }
+
+    #[test]
+    fn test_flag_label_localized_and_fallback() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        assert_eq!(
+            story_data.flag_label("has_shielding", Language::En),
+            "Found shielding"
+        );
+        assert_eq!(
+            story_data.flag_label("has_shielding", Language::Fr),
+            "Blindage trouvé"
+        );
+        assert_eq!(
+            story_data.flag_label("not_a_real_flag", Language::Fr),
+            "Not A Real Flag"
+        );
+    }
+
+    #[test]
+    fn test_humanize_flag() {
+        assert_eq!(humanize_flag("reached_settlement"), "Reached Settlement");
+        assert_eq!(humanize_flag("has__lab_keycard"), "Has Lab Keycard");
+        assert_eq!(humanize_flag(""), "");
+    }
+
+    #[test]
+    fn test_visible_messages_filters_by_condition() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let node = &story_data.nodes["a2_camp"];
+        let mut state = GameStateBuilder::new().at_node("a2_camp").build();
+        let without = node.visible_messages(&state).len();
+        assert_eq!(without, node.messages.len() - 1);
+
+        state.set_flag("has_medicine");
+        assert_eq!(node.visible_messages(&state).len(), node.messages.len());
+    }
+
+    #[test]
+    fn test_trust_bonus_messages_need_high_trust() {
+        let story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let node = &story_data.nodes["a3_fork"];
+        assert!(!node.trust_bonus_messages.is_empty());
+        let mut state = GameStateBuilder::new().at_node("a3_fork").trust(6).build();
+        assert_eq!(node.visible_messages(&state).len(), node.messages.len());
+
+        state.stats.trust = node.trust_bonus_threshold;
+        let visible = node.visible_messages(&state);
+        assert_eq!(
+            visible.len(),
+            node.messages.len() + node.trust_bonus_messages.len()
+        );
+        assert_eq!(
+            visible[0].get(Language::En),
+            node.trust_bonus_messages[0].get(Language::En)
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_ending_with_only_conditional_messages() {
+        let mut story_data: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        let node = story_data.nodes.get_mut("ending_echo").unwrap();
+        for msg in &mut node.messages {
+            msg.condition = Some(BranchCondition {
+                min_trust: Some(11),
+                ..Default::default()
+            });
+        }
+
+        let errors = story_data.validate();
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.contains("'ending_echo' only has conditional messages")),
+            "Expected conditional-ending validation error, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_invalid_external_story_falls_back_to_embedded() {
+        let dir = std::env::temp_dir().join("eshara_test_external_story");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("story.json");
+
+        // Parses, but the start node doesn't exist
+        let mut broken: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        broken.meta.start_node = "nowhere".to_string();
+        std::fs::write(&path, serde_json::to_string(&broken).unwrap()).unwrap();
+
+        let errors = load_story_with(&path, |_| false).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("nowhere")));
+
+        let mut offered = Vec::new();
+        let story = load_story_with(&path, |errors| {
+            offered = errors.to_vec();
+            true
+        })
+        .unwrap();
+        assert_eq!(offered, errors);
+        assert_ne!(story.meta.start_node, "nowhere");
+
+        // Unparseable files are offered the fallback too
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(load_story_with(&path, |_| true).is_ok());
+        let errors = load_story_with(&path, |_| false).unwrap_err();
+        assert!(errors[0].starts_with(&path.display().to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_character_placeholders() {
+        let mut story: StoryData = serde_json::from_str(EMBEDDED_STORY).unwrap();
+        story.characters.insert(
+            "osei".to_string(),
+            LocalizedString::new("Dr. Osei", "Docteure Osei"),
+        );
+
+        let text = LocalizedString::new(
+            "Ask {char:osei} about {char:lina}.",
+            "Demande \u{00e0} {char:osei} pour {char:lina}.",
+        );
+        assert_eq!(
+            story.localized(&text, Language::En),
+            "Ask Dr. Osei about Lina."
+        );
+        assert_eq!(
+            story.localized(&text, Language::Fr),
+            "Demande \u{00e0} Docteure Osei pour Lina."
+        );
+        // Plain text and unknown or unterminated placeholders pass through
+        assert!(matches!(
+            story.resolve_text("No names here.", Language::En),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            story.resolve_text("{char:nobody} and {char:osei", Language::En),
+            "{char:nobody} and {char:osei"
+        );
+
+        // Shipped text goes through the map
+        let node = &story.nodes["a4f_lina_deep"];
+        let shown: Vec<_> = node
+            .messages
+            .iter()
+            .map(|m| story.localized(&m.text, Language::Fr))
+            .collect();
+        assert!(shown
+            .iter()
+            .any(|m| m.contains("Lina \u{00e9}tait ma s\u{0153}ur")));
+        assert!(shown.iter().all(|m| !m.contains("{char:")));
+
+        story.nodes.get_mut("a4f_lina_deep").unwrap().messages[0]
+            .text
+            .fr = "{char:kai}".to_string();
+        assert!(story
+            .validate()
+            .iter()
+            .any(|e| e.contains("a4f_lina_deep") && e.contains("unknown character 'kai'")));
+
+        // Names are story strings like any other for translators
+        assert!(story
+            .missing_translations(Language::De)
+            .contains(&"character 'osei': name".to_string()));
+        assert!(story
+            .text_dump(&[Language::En, Language::Fr])
+            .contains("== character osei ==\nname\n  en: Dr. Osei\n  fr: Docteure Osei\n"));
+    }
 }
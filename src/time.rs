@@ -24,26 +24,27 @@ pub fn waiting_times_enabled() -> bool {
 }
 
 /// Check if debug mode is enabled (ESHARA_DEBUG=1)
-/// In debug mode, all delays are reduced to 5 seconds
+/// In debug mode, delays are capped by the story's `debug_delay_override_seconds`
 pub fn is_debug_mode() -> bool {
     env::var("ESHARA_DEBUG")
         .map(|v| v == "1" || v.to_lowercase() == "true")
         .unwrap_or(false)
 }
 
-/// Get the effective delay in seconds (respects debug mode)
+/// Get the effective delay in seconds (none when waits are switched off)
 pub fn effective_delay(seconds: u64) -> u64 {
     if skip_waiting() {
-        return 0;
-    }
-
-    if is_debug_mode() {
-        5 // All delays become 5 seconds in debug mode
+        0
     } else {
         seconds
     }
 }
 
+/// A story delay as played in debug mode: never longer than `cap` seconds
+pub fn debug_delay(seconds: u64, cap: u64) -> u64 {
+    seconds.min(cap)
+}
+
 /// Schedule Elara to be "busy" for the given number of seconds
 /// Sets `waiting_until` on the game state
 pub fn schedule_wait(state: &mut GameState, seconds: u64) {
@@ -69,38 +70,92 @@ pub fn is_waiting(state: &GameState) -> bool {
     }
 }
 
+/// How long the player stayed away after a pending wait ended.
+/// None if no wait was pending or it has not finished yet.
+pub fn absence_duration(state: &GameState, now: DateTime<Utc>) -> Option<ChronoDuration> {
+    let until = state.waiting_until?;
+    if now >= until {
+        Some(now - until)
+    } else {
+        None
+    }
+}
+
+/// Below this many seconds the remaining time is shown down to the second.
+const SECONDS_PRECISION_BELOW: u64 = 5 * 60;
+
 /// Get the remaining wait time as a human-readable string
 pub fn remaining_time_str(until: DateTime<Utc>, lang: Language) -> String {
-    let now = Utc::now();
-    if now >= until {
+    let seconds = (until - Utc::now()).num_seconds().max(0) as u64;
+    humanize_remaining(seconds, lang)
+}
+
+/// Remaining time in words: hours and minutes for long waits, minutes and
+/// seconds under five minutes ("2m30s"), seconds under one ("45s").
+fn humanize_remaining(seconds: u64, lang: Language) -> String {
+    if seconds == 0 {
         return match lang {
             Language::En => "any moment now".to_string(),
             Language::Fr => "d'un moment \u{00e0} l'autre".to_string(),
+            Language::De => "jeden Moment".to_string(),
         };
     }
 
-    let diff = until - now;
-    let hours = diff.num_hours();
-    let minutes = diff.num_minutes() % 60;
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+    let minute_unit = match lang {
+        Language::En => "m",
+        Language::Fr | Language::De => "min",
+    };
 
-    if hours > 0 {
-        match lang {
-            Language::En => format!("{}h {}min", hours, minutes),
-            Language::Fr => format!("{}h {}min", hours, minutes),
+    if seconds < 60 {
+        format!("{}s", secs)
+    } else if seconds < SECONDS_PRECISION_BELOW {
+        if secs == 0 {
+            format!("{}{}", minutes, minute_unit)
+        } else {
+            format!("{}{}{:02}s", minutes, minute_unit, secs)
         }
-    } else if minutes > 0 {
+    } else if hours > 0 {
         match lang {
-            Language::En => format!("{} minute{}", minutes, if minutes > 1 { "s" } else { "" }),
-            Language::Fr => format!("{} minute{}", minutes, if minutes > 1 { "s" } else { "" }),
+            Language::En => format!("{}h {}min", hours, minutes),
+            Language::Fr | Language::De => format!("{}h {}min", hours, minutes),
         }
     } else {
         match lang {
-            Language::En => "less than a minute".to_string(),
-            Language::Fr => "moins d'une minute".to_string(),
+            Language::En => format!("{} minutes", minutes),
+            Language::Fr => format!("{} minutes", minutes),
+            Language::De => format!("{} Minuten", minutes),
         }
     }
 }
 
+/// Time left as a ticking clock: "04:09", or "1:04:09" past an hour.
+pub fn countdown_clock(until: DateTime<Utc>) -> String {
+    let seconds = (until - Utc::now()).num_seconds().max(0) as u64;
+    let (hours, minutes, secs) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{:02}:{:02}", minutes, secs)
+    }
+}
+
+/// Format a play duration compactly (e.g., "1h 02m 05s", "4m 10s", "12s")
+pub fn format_duration(seconds: u64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, secs)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
 /// Format a DateTime as a local time string for display (e.g., "14:30")
 #[allow(dead_code)]
 pub fn format_local_time(dt: DateTime<Utc>) -> String {
@@ -114,12 +169,14 @@ mod tests {
 
     #[test]
     fn test_effective_delay_normal() {
-        // Without ESHARA_DEBUG set, should return the original value
-        // (This test assumes ESHARA_DEBUG is not set in the test env)
         set_waiting_times_enabled(true);
-        if !is_debug_mode() {
-            assert_eq!(effective_delay(300), 300);
-        }
+        assert_eq!(effective_delay(300), 300);
+    }
+
+    #[test]
+    fn test_debug_delay_caps_long_waits() {
+        assert_eq!(debug_delay(600, 5), 5);
+        assert_eq!(debug_delay(3, 5), 3);
     }
 
     #[test]
@@ -129,6 +186,24 @@ mod tests {
         set_waiting_times_enabled(true);
     }
 
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(12), "12s");
+        assert_eq!(format_duration(250), "4m 10s");
+        assert_eq!(format_duration(3725), "1h 02m 05s");
+    }
+
+    #[test]
+    fn test_countdown_clock() {
+        let at = |secs: i64| Utc::now() + ChronoDuration::milliseconds(secs * 1000 + 500);
+        assert_eq!(countdown_clock(at(249)), "04:09");
+        assert_eq!(countdown_clock(at(3849)), "1:04:09");
+        assert_eq!(
+            countdown_clock(Utc::now() - ChronoDuration::hours(1)),
+            "00:00"
+        );
+    }
+
     #[test]
     fn test_remaining_time_str_past() {
         let past = Utc::now() - ChronoDuration::hours(1);
@@ -146,6 +221,28 @@ mod tests {
         assert!(result.contains("h"));
     }
 
+    #[test]
+    fn test_remaining_time_boundaries() {
+        let en = |s| humanize_remaining(s, Language::En);
+        let fr = |s| humanize_remaining(s, Language::Fr);
+        assert_eq!(en(1), "1s");
+        assert_eq!(en(59), "59s");
+        assert_eq!(en(60), "1m");
+        assert_eq!(en(61), "1m01s");
+        assert_eq!(en(150), "2m30s");
+        assert_eq!(en(299), "4m59s");
+        assert_eq!(en(300), "5 minutes");
+        assert_eq!(en(3600 + 5 * 60), "1h 5min");
+        assert_eq!(fr(45), "45s");
+        assert_eq!(fr(60), "1min");
+        assert_eq!(fr(150), "2min30s");
+        assert_eq!(fr(300), "5 minutes");
+        assert_eq!(en(0), "any moment now");
+
+        let soon = Utc::now() + ChronoDuration::seconds(45) + ChronoDuration::milliseconds(500);
+        assert_eq!(remaining_time_str(soon, Language::En), "45s");
+    }
+
     #[test]
     fn test_schedule_wait() {
         let mut state = GameState::new(Language::En, "test", 3, 10, 3);
@@ -154,6 +251,22 @@ mod tests {
         assert!(state.waiting_until.is_some());
     }
 
+    #[test]
+    fn test_absence_duration() {
+        let mut state = GameState::new(Language::En, "test", 3, 10, 3);
+        let now = Utc::now();
+        assert!(absence_duration(&state, now).is_none());
+
+        state.waiting_until = Some(now + ChronoDuration::minutes(5));
+        assert!(absence_duration(&state, now).is_none());
+
+        state.waiting_until = Some(now - ChronoDuration::hours(3));
+        assert_eq!(
+            absence_duration(&state, now),
+            Some(ChronoDuration::hours(3))
+        );
+    }
+
     #[test]
     fn test_is_waiting() {
         let mut state = GameState::new(Language::En, "test", 3, 10, 3);
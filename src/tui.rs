@@ -4,6 +4,10 @@
 //! rendering model. The `App` struct holds all UI state; the `run()` function
 //! drives the event loop.
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use ratatui::{
@@ -14,24 +18,60 @@ use ratatui::{
     DefaultTerminal, Frame,
 };
 
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, MouseEvent, MouseEventKind};
+use ratatui::crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
 
-use crate::game::{save_game, GameState, LogEntry, Sender, TextSpeed};
+use crate::game::{
+    save_game_slot, saved_run_label, ChatLayout, DecisionRecord, EndingsLedger, GameState,
+    LanguagePace, LogEntry, RewindBlocked, SaveScheduler, SaveSlotInfo, SavedRuns, Sender,
+    SessionMarkers, TextSpeed, Theme, TypingProgress, UiMode, IDLE_NUDGE_PRESETS,
+    IDLE_TIMEOUT_PRESETS, LOG_RETENTION_PRESETS, MAX_SAVED_RUNS, MIN_READ_PRESETS, PACE_PRESETS,
+    SAVED_RUN_LABEL_MAX, SAVE_INTERVAL_PRESETS, SAVE_SLOTS,
+};
 use crate::i18n::{sys_msg, Language, Msg};
-use crate::story::{Choice, StoryData};
+use crate::story::{Choice, StoryData, StoryMessage, StoryNode, RETURN_NODE};
+use crate::time::format_duration;
 
 // ── Constants ────────────────────────────────────────────────
 
 /// Milliseconds between each character reveal in typewriter mode.
+const TYPEWRITER_TICK_SLOW_MS: u64 = 80;
 const TYPEWRITER_TICK_NORMAL_MS: u64 = 45;
 const TYPEWRITER_TICK_FAST_MS: u64 = 18;
 
+/// Milliseconds of quiet between two messages unless a message asks for more.
+const POST_MESSAGE_PAUSE_MS: u64 = 300;
+
 /// Milliseconds to show the "Elara is typing..." indicator.
 const TYPING_INDICATOR_MS: u64 = 1500;
 
+/// Milliseconds fast-forward stays engaged after the last Space press.
+/// Longer than the usual key-repeat delay, so a held key keeps it going.
+const FAST_FORWARD_LULL_MS: u64 = 600;
+
+/// Milliseconds between messages while fast-forwarding.
+const FAST_FORWARD_PAUSE_MS: u64 = 40;
+
+/// Milliseconds an act title card stays up unless a key dismisses it.
+const ACT_CARD_MS: u64 = 3000;
+
 /// Milliseconds between animation frames (dot cycling).
 const ANIM_FRAME_MS: u64 = 400;
 
+/// Caret drawn after the text Elara is currently typing.
+const TYPEWRITER_CARET: &str = "\u{258c}";
+
+/// Width of the speaker column in the gutter chat layout.
+const GUTTER_WIDTH: u16 = 6;
+
+/// Drawn in place of the first column of a bookmarked message.
+const BOOKMARK_MARKER: &str = "\u{2605}";
+
+/// Smallest terminal size the full UI is drawn in; below it only a notice is shown.
+const MIN_TERMINAL_WIDTH: u16 = 20;
+const MIN_TERMINAL_HEIGHT: u16 = 6;
+
 // ── Chat entries ─────────────────────────────────────────────
 
 /// A single entry in the visible chat log.
@@ -41,6 +81,48 @@ pub enum ChatEntry {
     Player(String),
     System(String),
     Separator(String),
+    /// Author's note on the node just entered; only drawn in commentary mode.
+    Commentary(String),
+}
+
+/// Chat layout measured on the last draw, used by scroll navigation.
+#[derive(Clone, Debug, Default)]
+pub struct ChatMetrics {
+    /// Total wrapped rows of chat content.
+    pub total_rows: usize,
+    /// Rows visible in the chat area.
+    pub view_height: usize,
+    /// Row and label of each session separator, top to bottom.
+    pub sessions: Vec<(usize, String)>,
+    /// Row of each bookmarked message, top to bottom.
+    pub bookmarks: Vec<usize>,
+    /// Width of the chat area.
+    pub width: u16,
+    /// Rows added below a scrolled-up view since the last tick. The view is
+    /// drawn this much further up so it stays put until `tick` folds them
+    /// into `chat_scroll`.
+    pub held_rows: usize,
+}
+
+impl ChatMetrics {
+    fn max_scroll(&self) -> usize {
+        self.total_rows.saturating_sub(self.view_height)
+    }
+
+    /// First visible row for a given scroll offset from the bottom.
+    fn top_row(&self, chat_scroll: u16) -> usize {
+        let max_scroll = self.max_scroll();
+        max_scroll - (chat_scroll as usize).min(max_scroll)
+    }
+
+    /// Label of the session the given row belongs to.
+    fn session_at(&self, row: usize) -> Option<&str> {
+        self.sessions
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= row)
+            .map(|(_, label)| label.as_str())
+    }
 }
 
 // ── Screen / overlay state ───────────────────────────────────
@@ -60,6 +142,10 @@ pub enum Screen {
     Waiting,
     /// Ending summary screen.
     Ending,
+    /// The story pointed at a node that doesn't exist; offers a way back.
+    StoryError,
+    /// Several save slots hold a run; pick which one to play.
+    SlotSelect,
 }
 
 /// Overlay that renders on top of the current screen.
@@ -67,6 +153,131 @@ pub enum Screen {
 pub enum Overlay {
     None,
     PauseMenu,
+    /// Glossary of world terms, opened from the pause menu.
+    Glossary,
+    /// Where the story stands: day, act, key flags and the last reply.
+    Recap,
+    /// "Are you sure?" for a choice marked with a confirmation prompt.
+    ConfirmChoice,
+    /// Read-only look at the choices not taken, opened from the ending screen.
+    Explorer,
+    /// Title card for the act the story just moved into.
+    ActCard,
+    /// Named snapshots of whole runs, opened from the pause menu.
+    SavedRuns,
+}
+
+/// Entries of the pause menu, in display order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MenuItem {
+    Resume,
+    Language,
+    TextSpeed,
+    LanguagePace,
+    TypingRhythm,
+    WaitingTimes,
+    AutomaticDialogs,
+    FastForward,
+    MinReadTime,
+    IdleTimeout,
+    IdleNudge,
+    ReduceMotion,
+    ConsequenceHints,
+    ShowStats,
+    TrustCues,
+    Commentary,
+    ChatLayout,
+    SessionMarkers,
+    ActCards,
+    Theme,
+    LogRetention,
+    SaveInterval,
+    Glossary,
+    Recap,
+    Rewind,
+    ReturnToCheckpoint,
+    SavedRuns,
+    ExportTranscript,
+    ReplayIntro,
+    Validate,
+    SaveQuit,
+}
+
+/// The pause menu entries currently available.
+pub fn pause_menu_items(app: &App) -> Vec<MenuItem> {
+    let mut items = vec![
+        MenuItem::Resume,
+        MenuItem::Language,
+        MenuItem::TextSpeed,
+        MenuItem::LanguagePace,
+        MenuItem::TypingRhythm,
+        MenuItem::WaitingTimes,
+        MenuItem::AutomaticDialogs,
+        MenuItem::FastForward,
+        MenuItem::MinReadTime,
+        MenuItem::IdleTimeout,
+        MenuItem::IdleNudge,
+        MenuItem::ReduceMotion,
+        MenuItem::ConsequenceHints,
+        MenuItem::ShowStats,
+        MenuItem::TrustCues,
+        MenuItem::Commentary,
+        MenuItem::ChatLayout,
+        MenuItem::SessionMarkers,
+        MenuItem::ActCards,
+        MenuItem::Theme,
+        MenuItem::LogRetention,
+        MenuItem::SaveInterval,
+        MenuItem::Glossary,
+        MenuItem::Recap,
+        MenuItem::Rewind,
+        MenuItem::ReturnToCheckpoint,
+        MenuItem::SavedRuns,
+        MenuItem::ExportTranscript,
+        MenuItem::ReplayIntro,
+        MenuItem::Validate,
+        MenuItem::SaveQuit,
+    ];
+    if !app.commentary_unlocked() {
+        items.retain(|item| *item != MenuItem::Commentary);
+    }
+    if app.game_state.checkpoints.is_empty() {
+        items.retain(|item| *item != MenuItem::ReturnToCheckpoint);
+    }
+    items
+}
+
+/// Entries of the prompt under an ending, in display order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EndingItem {
+    PlayAgain,
+    NewGamePlus,
+    Quit,
+    Checkpoint,
+}
+
+impl EndingItem {
+    fn label(self) -> Msg {
+        match self {
+            EndingItem::PlayAgain => Msg::YesOption,
+            EndingItem::NewGamePlus => Msg::NewGamePlusOption,
+            EndingItem::Quit => Msg::NoOption,
+            EndingItem::Checkpoint => Msg::CheckpointOption,
+        }
+    }
+}
+
+/// The ending prompt entries currently available.
+pub fn ending_items(app: &App) -> Vec<EndingItem> {
+    let mut items = vec![
+        EndingItem::PlayAgain,
+        EndingItem::NewGamePlus,
+        EndingItem::Quit,
+    ];
+    if !app.game_state.checkpoints.is_empty() {
+        items.push(EndingItem::Checkpoint);
+    }
+    items
 }
 
 // ── Animation state ──────────────────────────────────────────
@@ -86,11 +297,20 @@ pub struct TypewriterState {
     pub indicator_start: Instant,
     /// Milliseconds between each character reveal.
     pub char_tick_ms: u64,
+    /// Milliseconds to wait after this message before the next one starts.
+    pub pause_after_ms: u64,
+    /// Seed for radio static over the revealed text, if the line is garbled.
+    pub garble_seed: Option<u64>,
+    /// Language pace in percent; above 100 reveals faster than `char_tick_ms`.
+    pub pace_percent: u32,
+    /// Seed for the typing rhythm, if the reveal pace varies like a person typing.
+    pub rhythm_seed: Option<u64>,
 }
 
 impl TypewriterState {
     pub fn new(text: String, speed: TextSpeed) -> Self {
         let char_tick_ms = match speed {
+            TextSpeed::Slow => TYPEWRITER_TICK_SLOW_MS,
             TextSpeed::Normal => TYPEWRITER_TICK_NORMAL_MS,
             TextSpeed::Fast => TYPEWRITER_TICK_FAST_MS,
             TextSpeed::Instant => 0,
@@ -105,9 +325,32 @@ impl TypewriterState {
             show_typing_indicator: !instant,
             indicator_start: Instant::now(),
             char_tick_ms,
+            pause_after_ms: POST_MESSAGE_PAUSE_MS,
+            garble_seed: None,
+            pace_percent: 100,
+            rhythm_seed: None,
         }
     }
 
+    /// FNV-1a over the text, so the same line always plays out the same way.
+    fn text_seed(&self) -> u64 {
+        self.full_text
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+                (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    /// Reveal this line through static; the pattern is seeded by the text.
+    pub fn garble(&mut self) {
+        self.garble_seed = Some(self.text_seed());
+    }
+
+    /// Reveal this line with a typing rhythm; the variation is seeded by the text.
+    pub fn humanize(&mut self) {
+        self.rhythm_seed = Some(self.text_seed());
+    }
+
     /// Is the typing indicator phase still active?
     pub fn is_indicating(&self) -> bool {
         self.show_typing_indicator
@@ -119,19 +362,24 @@ impl TypewriterState {
         !self.show_typing_indicator && self.revealed >= self.full_text.len()
     }
 
-    /// Reset timing after a pause so the animation doesn't fast-forward.
-    pub fn resume(&mut self) {
-        let now = Instant::now();
-        self.last_tick = now;
-        if self.show_typing_indicator {
-            // Preserve how much indicator time was left
-            let elapsed = self.indicator_start.elapsed();
-            let indicator_dur = Duration::from_millis(TYPING_INDICATOR_MS);
-            if elapsed < indicator_dur {
-                // Reset indicator_start so the remaining time is preserved
-                self.indicator_start = now - elapsed;
-            }
+    /// Push the timers back by the length of a pause, so the indicator and
+    /// the next character keep exactly the time they had left. A timer set
+    /// during the pause is only moved up to now.
+    pub fn shift(&mut self, paused: Duration) {
+        self.last_tick += paused.min(self.last_tick.elapsed());
+        self.indicator_start += paused.min(self.indicator_start.elapsed());
+    }
+
+    /// Continue a reveal that was interrupted, with `revealed` bytes already shown.
+    pub fn resume_from(&mut self, revealed: usize) {
+        let mut revealed = revealed.min(self.full_text.len());
+        // The language may have changed since; land on a character boundary
+        while !self.full_text.is_char_boundary(revealed) {
+            revealed -= 1;
         }
+        self.show_typing_indicator = false;
+        self.revealed = self.revealed.max(revealed);
+        self.last_tick = Instant::now();
     }
 
     /// Skip to completion: reveal all text immediately.
@@ -140,6 +388,37 @@ impl TypewriterState {
         self.revealed = self.full_text.len();
     }
 
+    /// Time between two revealed characters, after the language pace.
+    pub fn char_interval(&self) -> Duration {
+        Duration::from_millis(self.char_tick_ms) * 100 / self.pace_percent.max(1)
+    }
+
+    /// Time before the character at byte `pos` appears. With a typing
+    /// rhythm, the pause follows the character just before it.
+    pub fn interval_at(&self, pos: usize) -> Duration {
+        let base = self.char_interval();
+        let Some(seed) = self.rhythm_seed else {
+            return base;
+        };
+        let percent = match self.full_text[..pos].chars().next_back() {
+            Some('.' | '!' | '?' | '\u{2026}') => 400,
+            Some(',' | ';' | ':') => 220,
+            Some(c) if c.is_whitespace() => 100,
+            None => 100,
+            // Within a word: 70–95% of the base pace, varying per position
+            Some(_) => 70 + (rhythm_noise(seed, pos) % 26) as u32,
+        };
+        base * percent / 100
+    }
+
+    /// How long the whole text takes to reveal once the indicator is gone.
+    pub fn reveal_duration(&self) -> Duration {
+        self.full_text
+            .char_indices()
+            .map(|(pos, _)| self.interval_at(pos))
+            .sum()
+    }
+
     /// Advance the animation by one tick if enough time has passed.
     pub fn tick(&mut self) {
         if self.show_typing_indicator {
@@ -151,7 +430,7 @@ impl TypewriterState {
         }
         if self.revealed < self.full_text.len()
             && (self.char_tick_ms == 0
-                || self.last_tick.elapsed() >= Duration::from_millis(self.char_tick_ms))
+                || self.last_tick.elapsed() >= self.interval_at(self.revealed))
         {
             // Reveal one character (handle multi-byte)
             let remaining = &self.full_text[self.revealed..];
@@ -166,6 +445,52 @@ impl TypewriterState {
     pub fn visible_text(&self) -> &str {
         &self.full_text[..self.revealed]
     }
+
+    /// The visible text as drawn: garbled lines show static until fully revealed.
+    pub fn display_text(&self) -> Cow<'_, str> {
+        match self.garble_seed {
+            Some(seed) if !self.is_done() => {
+                Cow::Owned(static_overlay(self.visible_text(), seed, self.revealed))
+            }
+            _ => Cow::Borrowed(self.visible_text()),
+        }
+    }
+}
+
+/// Glyphs substituted for characters lost to static.
+const STATIC_GLYPHS: [char; 5] = ['░', '▒', '▓', '#', '%'];
+
+/// splitmix64 finalizer over (seed, position), for the typing rhythm.
+fn rhythm_noise(seed: u64, pos: usize) -> u64 {
+    let mut h = seed ^ (pos as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
+
+/// Replace roughly a quarter of the non-space characters with static. The
+/// pattern depends only on `seed` and `frame`, so it flickers as the reveal
+/// advances but is reproducible.
+fn static_overlay(text: &str, seed: u64, frame: usize) -> String {
+    text.chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            if ch.is_whitespace() {
+                return ch;
+            }
+            // splitmix64 finalizer over (seed, position, frame)
+            let mut h =
+                seed ^ (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ ((frame as u64) << 32);
+            h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            h ^= h >> 31;
+            if h.is_multiple_of(4) {
+                STATIC_GLYPHS[(h >> 8) as usize % STATIC_GLYPHS.len()]
+            } else {
+                ch
+            }
+        })
+        .collect()
 }
 
 // ── App state ────────────────────────────────────────────────
@@ -178,14 +503,21 @@ pub struct App {
     pub overlay: Overlay,
     /// Visible chat entries.
     pub chat: Vec<ChatEntry>,
+    /// Positions of chat entries with no message log entry behind them (re-reads).
+    pub unlogged_chat: BTreeSet<usize>,
     /// Scroll offset for chat (0 = bottom).
     pub chat_scroll: u16,
+    /// Messages arrived below the view while scrolled up.
+    pub unseen_below: bool,
     /// Current typewriter animation (if any).
     pub typewriter: Option<TypewriterState>,
     /// Queue of messages still to be displayed for the current node.
-    pub message_queue: Vec<String>,
+    pub message_queue: Vec<StoryMessage>,
     /// Choices currently being presented to the player.
     pub choices: Vec<String>,
+    /// The story choice behind each presented label with its source index
+    /// (None for the "Re-read that" pseudo-choice).
+    pub choice_data: Vec<Option<(usize, Choice)>>,
     /// Selection index for the choice menu.
     pub choice_index: usize,
     /// Selection index for the pause menu.
@@ -203,10 +535,22 @@ pub struct App {
     pub story_data: StoryData,
     /// Whether we need to process the next story node.
     pub advance_story: bool,
+    /// Whether state changes are written to the save file.
+    pub persist: bool,
+    /// Holds back auto-saves that come faster than the save interval setting.
+    pub save_scheduler: SaveScheduler,
     /// Intro animation state.
     pub intro_typewriter: Option<TypewriterState>,
+    /// Screen to return to when a replayed intro finishes (None on first run).
+    pub intro_return: Option<Screen>,
     /// Post-message pause timer (small delay after a message finishes).
     pub post_message_pause: Option<Instant>,
+    /// When the animations were frozen by an overlay or a replayed intro, if they are.
+    pub paused_since: Option<Instant>,
+    /// Length of the current post-message pause in milliseconds.
+    pub post_message_pause_ms: u64,
+    /// Space was pressed recently enough to count as held down.
+    pub fast_forward_until: Option<Instant>,
     /// In --no-waiting mode, require Space before moving to the next message.
     pub wait_for_space: bool,
     /// Ending key reached (for the ending screen), e.g. "still_here", "gone_dark".
@@ -221,6 +565,107 @@ pub struct App {
     pub menu_waiting_times_enabled_draft: bool,
     /// Draft auto-dialog value shown in pause menu before validation.
     pub menu_automatic_dialogs_enabled_draft: bool,
+    /// Draft hold-to-fast-forward value shown in pause menu before validation.
+    pub menu_fast_forward_draft: bool,
+    /// Draft idle timeout (minutes) shown in pause menu before validation.
+    pub menu_idle_timeout_draft: u32,
+    /// Draft idle nudge delay (seconds) shown in pause menu before validation.
+    pub menu_idle_nudge_draft: u32,
+    /// Draft minimum read time (ms) shown in pause menu before validation.
+    pub menu_min_read_draft: u32,
+    /// Draft typing rhythm value shown in pause menu before validation.
+    pub menu_typing_rhythm_draft: bool,
+    /// Draft reduce-motion value shown in pause menu before validation.
+    pub menu_reduce_motion_draft: bool,
+    /// Draft consequence hints value shown in pause menu before validation.
+    pub menu_consequence_hints_draft: bool,
+    /// Draft stat gauges value shown in pause menu before validation.
+    pub menu_show_stats_draft: bool,
+    /// Draft trust cues value shown in pause menu before validation.
+    pub menu_trust_cues_draft: bool,
+    /// Draft commentary mode shown in pause menu before validation.
+    pub menu_commentary_draft: bool,
+    /// Draft per-language typing pace shown in pause menu before validation.
+    pub menu_language_pace_draft: LanguagePace,
+    /// Draft chat layout shown in pause menu before validation.
+    pub menu_chat_layout_draft: ChatLayout,
+    /// Draft session marker mode shown in pause menu before validation.
+    pub menu_session_markers_draft: SessionMarkers,
+    /// Draft act title cards value shown in pause menu before validation.
+    pub menu_act_cards_draft: bool,
+    /// Draft log retention shown in pause menu before validation.
+    pub menu_log_retention_draft: u32,
+    /// Draft save interval (ms) shown in pause menu before validation.
+    pub menu_save_interval_draft: u32,
+    /// Draft theme shown in pause menu before validation.
+    pub menu_theme_draft: Theme,
+    /// Time of the last key or mouse event, used for the idle auto-pause.
+    pub last_input: Instant,
+    /// When the play clock was last advanced by `tick`.
+    pub play_clock: Instant,
+    /// Start of the connected time not yet added to `total_playtime_secs`.
+    pub session_clock: Instant,
+    /// When the current choices were presented, until Elara has nudged once.
+    pub nudge_pending_since: Option<Instant>,
+    /// Chat measurements from the last draw (for session jumps).
+    pub chat_metrics: RefCell<ChatMetrics>,
+    /// Replaying the current node's messages for a re-read (no state changes).
+    pub rereading: bool,
+    /// Displayed index of a choice waiting on its confirmation prompt.
+    pub confirm_pending: Option<usize>,
+    /// Selected button in the confirmation overlay (0 = confirm, 1 = go back).
+    pub confirm_index: usize,
+    /// Selected decision in the branch explorer.
+    pub explore_index: usize,
+    /// The branch explorer shows the selected decision's alternatives.
+    pub explore_open: bool,
+    /// Where named runs are kept.
+    pub saved_runs_path: PathBuf,
+    /// Named runs, read from disk when their list is opened.
+    pub saved_runs: SavedRuns,
+    /// Selected row of the saved runs list (0 is "save this run").
+    pub saved_runs_index: usize,
+    /// Name being typed for a new saved run.
+    pub saved_run_label: Option<String>,
+    /// Outcome of the last action in the saved runs list.
+    pub saved_runs_notice: Option<String>,
+    /// Why the saved runs file couldn't be read; nothing is written over it
+    /// while this is set.
+    pub saved_runs_unreadable: Option<String>,
+    /// Act of the last entered node that declared one.
+    pub current_act: Option<u32>,
+    /// Act whose title card is showing, and when it appeared.
+    pub act_card: Option<(u32, Instant)>,
+    /// Endings discovered across runs.
+    pub ledger: EndingsLedger,
+    /// The ending just reached had never been seen before.
+    pub new_ending: bool,
+    /// When the current choices appeared (for the minimum read time gate).
+    pub choices_shown_at: Option<Instant>,
+    /// Where F5/F9 write and read the quick-save slot.
+    pub quicksave_path: PathBuf,
+    /// Where the run is auto-saved: the current save slot's file.
+    pub save_path: PathBuf,
+    /// Save slot the run is written to (0 is the original save file).
+    pub save_slot: u8,
+    /// Slots offered on the slot selection screen.
+    pub save_slots: Vec<SaveSlotInfo>,
+    /// `--lang` / `--ui` from the command line, applied to whichever slot is opened.
+    pub launch_language: Option<Language>,
+    pub launch_ui: Option<UiMode>,
+    /// `--no-animation`: turn reduce motion on for the run being opened.
+    pub launch_reduce_motion: bool,
+    /// A real-time wait just ended; the event loop rings the terminal bell.
+    pub bell_pending: bool,
+    /// F9 was pressed once and is waiting for a second press to confirm.
+    pub quickload_pending: bool,
+    /// Started with `--dev`: F12 toggles the timing panel.
+    pub dev_mode: bool,
+    /// Started with `--debug` or `ESHARA_DEBUG=1`: waits are capped at the
+    /// story's `debug_delay_override_seconds`.
+    pub debug_delays: bool,
+    /// The timing panel is shown.
+    pub dev_panel: bool,
 }
 
 impl App {
@@ -230,15 +675,39 @@ impl App {
         let menu_text_speed_draft = game_state.settings.text_speed;
         let menu_waiting_times_enabled_draft = game_state.settings.waiting_times_enabled;
         let menu_automatic_dialogs_enabled_draft = game_state.settings.automatic_dialogs_enabled;
+        let menu_fast_forward_draft = game_state.settings.fast_forward;
+        let menu_idle_timeout_draft = game_state.settings.idle_timeout_minutes;
+        let menu_idle_nudge_draft = game_state.settings.idle_nudge_seconds;
+        let menu_min_read_draft = game_state.settings.min_read_ms;
+        let menu_typing_rhythm_draft = game_state.settings.typing_rhythm;
+        let menu_reduce_motion_draft = game_state.settings.reduce_motion;
+        let menu_consequence_hints_draft = game_state.settings.consequence_hints;
+        let menu_show_stats_draft = game_state.settings.show_stats;
+        let menu_trust_cues_draft = game_state.settings.trust_cues;
+        let menu_commentary_draft = game_state.settings.commentary;
+        let menu_language_pace_draft = game_state.settings.language_pace;
+        let menu_chat_layout_draft = game_state.settings.chat_layout;
+        let menu_session_markers_draft = game_state.settings.session_markers;
+        let menu_act_cards_draft = game_state.settings.act_cards;
+        let menu_theme_draft = game_state.settings.theme;
+        let menu_log_retention_draft = game_state.settings.log_retention;
+        let menu_save_interval_draft = game_state.settings.save_interval_ms;
+        let current_act = story_data
+            .nodes
+            .get(&game_state.current_node)
+            .and_then(|n| n.act);
 
         Self {
             screen: Screen::Game,
             overlay: Overlay::None,
             chat: Vec::new(),
+            unlogged_chat: BTreeSet::new(),
             chat_scroll: 0,
+            unseen_below: false,
             typewriter: None,
             message_queue: Vec::new(),
             choices: Vec::new(),
+            choice_data: Vec::new(),
             choice_index: 0,
             menu_index: 0,
             prompt_index: 0,
@@ -247,8 +716,14 @@ impl App {
             game_state,
             story_data,
             advance_story: true,
+            persist: true,
+            save_scheduler: SaveScheduler::default(),
             intro_typewriter: None,
+            intro_return: None,
             post_message_pause: None,
+            paused_since: None,
+            post_message_pause_ms: POST_MESSAGE_PAUSE_MS,
+            fast_forward_until: None,
             wait_for_space: false,
             ending_reached: None,
             wait_message: None,
@@ -256,6 +731,56 @@ impl App {
             menu_text_speed_draft,
             menu_waiting_times_enabled_draft,
             menu_automatic_dialogs_enabled_draft,
+            menu_fast_forward_draft,
+            menu_idle_timeout_draft,
+            menu_idle_nudge_draft,
+            menu_min_read_draft,
+            menu_typing_rhythm_draft,
+            menu_reduce_motion_draft,
+            menu_consequence_hints_draft,
+            menu_show_stats_draft,
+            menu_trust_cues_draft,
+            menu_commentary_draft,
+            menu_language_pace_draft,
+            menu_chat_layout_draft,
+            menu_session_markers_draft,
+            menu_act_cards_draft,
+            menu_log_retention_draft,
+            menu_save_interval_draft,
+            menu_theme_draft,
+            last_input: Instant::now(),
+            play_clock: Instant::now(),
+            session_clock: Instant::now(),
+            nudge_pending_since: None,
+            chat_metrics: RefCell::new(ChatMetrics::default()),
+            rereading: false,
+            confirm_pending: None,
+            confirm_index: 1,
+            explore_index: 0,
+            explore_open: false,
+            saved_runs_path: SavedRuns::path(),
+            saved_runs: SavedRuns::default(),
+            saved_runs_index: 0,
+            saved_run_label: None,
+            saved_runs_notice: None,
+            saved_runs_unreadable: None,
+            current_act,
+            act_card: None,
+            ledger: EndingsLedger::default(),
+            new_ending: false,
+            quicksave_path: crate::game::quicksave_path(),
+            save_path: crate::game::save_path(),
+            save_slot: 0,
+            save_slots: Vec::new(),
+            launch_language: None,
+            launch_ui: None,
+            launch_reduce_motion: false,
+            bell_pending: false,
+            quickload_pending: false,
+            dev_mode: false,
+            debug_delays: false,
+            dev_panel: false,
+            choices_shown_at: None,
         }
     }
 
@@ -263,174 +788,974 @@ impl App {
         self.game_state.language
     }
 
+    /// Auto-save the game state (no-op when persistence is disabled).
+    /// Within the save interval of the last write the request is deferred;
+    /// `tick` writes it once the interval has passed.
+    pub fn save(&mut self) {
+        let interval = Duration::from_millis(u64::from(self.game_state.settings.save_interval_ms));
+        if self.persist && !self.save_scheduler.request(Instant::now(), interval) {
+            return;
+        }
+        self.save_now();
+    }
+
+    /// Write the save file right away, whatever the save interval.
+    /// Older log entries beyond the retention setting are trimmed first.
+    pub fn save_now(&mut self) {
+        self.flush_playtime();
+        self.game_state.typing = self.typing_progress();
+        if !self.persist {
+            return;
+        }
+        self.save_scheduler.written(Instant::now());
+        let keep = self.game_state.settings.log_retention as usize;
+        self.game_state.trim_log(keep);
+        let dir = self.save_path.parent().map(Path::to_path_buf);
+        let _ = dir
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| crate::game::save_game_to(&self.game_state, &self.save_path));
+    }
+
+    /// Write a deferred save before leaving. Nothing is written on the
+    /// ending screen, where the slot may just have been cleared.
+    pub fn flush_pending_save(&mut self) {
+        if self.save_scheduler.is_pending() && self.screen != Screen::Ending {
+            self.save_now();
+        }
+    }
+
+    /// Add the connected time since the last flush to the run, in whole
+    /// seconds (the rest carries over). Time spent inside a wait is dropped.
+    pub fn flush_playtime(&mut self) {
+        if crate::time::is_waiting(&self.game_state) {
+            self.session_clock = Instant::now();
+            return;
+        }
+        let secs = self.session_clock.elapsed().as_secs();
+        self.game_state.total_playtime_secs += secs;
+        self.session_clock += Duration::from_secs(secs);
+    }
+
+    /// Offer the save slots that hold a run, plus a fresh one if any is free.
+    pub fn show_slot_select(&mut self, saves: Vec<SaveSlotInfo>) {
+        let lang = self.lang();
+        self.prompt_options = saves
+            .iter()
+            .map(|info| {
+                let act = self
+                    .story_data
+                    .nodes
+                    .get(&info.current_node)
+                    .and_then(|node| node.act)
+                    .map(|act| format!("{} {} ", sys_msg(Msg::ActProgress, lang), act))
+                    .unwrap_or_default();
+                format!(
+                    "{} {} \u{2014} {}({}), {} {}, {}, {}",
+                    sys_msg(Msg::SaveSlot, lang),
+                    info.slot,
+                    act,
+                    info.current_node,
+                    sys_msg(Msg::DaySeparator, lang),
+                    info.day,
+                    info.language.code().to_uppercase(),
+                    info.modified
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                )
+            })
+            .collect();
+        self.save_slots = saves;
+        if let Some(slot) = self.free_save_slot() {
+            self.prompt_options
+                .push(format!("{} {}", sys_msg(Msg::SlotNewGame, lang), slot));
+        }
+        self.prompt_index = 0;
+        self.screen = Screen::SlotSelect;
+    }
+
+    /// The lowest slot not offered on the slot selection screen.
+    fn free_save_slot(&self) -> Option<u8> {
+        (0..SAVE_SLOTS).find(|slot| !self.save_slots.iter().any(|info| info.slot == *slot))
+    }
+
+    /// Play the run behind a slot selection option.
+    pub fn choose_save_slot(&mut self, index: usize) {
+        let slot = match self.save_slots.get(index) {
+            Some(info) => Some(info.slot),
+            None => self.free_save_slot(),
+        };
+        if let Some(slot) = slot {
+            self.open_save_slot(slot);
+        }
+    }
+
+    /// Load a slot's save (discarding it if unreadable) and resume it, or
+    /// start a new game in it when it is empty.
+    pub fn open_save_slot(&mut self, slot: u8) {
+        self.save_slot = slot;
+        self.quicksave_path = crate::game::quicksave_slot_path(slot);
+        self.save_path = crate::game::save_slot_path(slot);
+        let existing = if self.persist {
+            crate::game::load_game_slot(slot).unwrap_or_else(|_| {
+                // Incompatible or corrupted save — discard it silently
+                let _ = crate::game::delete_save_slot(slot);
+                None
+            })
+        } else {
+            None
+        };
+        self.resume_state(existing);
+    }
+
+    /// Pick up a loaded run: straight into the chat while Elara is away,
+    /// otherwise ask whether to continue. Without one, ask for a language.
+    pub fn resume_state(&mut self, existing: Option<GameState>) {
+        self.session_clock = Instant::now();
+        let Some(mut state) = existing else {
+            let lang = self.launch_language.unwrap_or(Language::En);
+            self.game_state = self.fresh_state(lang);
+            if let Some(ui) = self.launch_ui {
+                self.game_state.settings.ui = ui;
+            }
+            self.game_state.settings.reduce_motion |= self.launch_reduce_motion;
+            crate::time::set_waiting_times_enabled(self.game_state.settings.waiting_times_enabled);
+            self.screen = Screen::LanguageSelect;
+            self.prompt_options = vec![
+                sys_msg(Msg::LanguageOption1, Language::En).to_string(),
+                sys_msg(Msg::LanguageOption2, Language::En).to_string(),
+                sys_msg(Msg::LanguageOption3, Language::En).to_string(),
+            ];
+            self.prompt_index = 0;
+            return;
+        };
+
+        let lang = self.launch_language.unwrap_or(state.language);
+        state.language = lang;
+        let mut changed = self
+            .story_data
+            .apply_trust_decay(&mut state, chrono::Utc::now());
+        // --ui picks the frontend and becomes the remembered default
+        if let Some(ui) = self.launch_ui {
+            changed |= state.settings.ui != ui;
+            state.settings.ui = ui;
+        }
+        if self.launch_reduce_motion && !state.settings.reduce_motion {
+            state.settings.reduce_motion = true;
+            changed = true;
+        }
+        crate::time::set_waiting_times_enabled(state.settings.waiting_times_enabled);
+
+        if crate::time::is_waiting(&state) {
+            // Elara is still busy — keep the user in chat view.
+            self.screen = Screen::Game;
+            self.prompt_options.clear();
+        } else {
+            // Clear completed wait if any
+            changed |= state.waiting_until.take().is_some();
+            self.screen = Screen::ContinueOrNew;
+            self.prompt_options = vec![
+                sys_msg(Msg::ContinueOption, lang).to_string(),
+                sys_msg(Msg::NewGameOption, lang).to_string(),
+            ];
+        }
+        self.prompt_index = 0;
+        self.game_state = state;
+        if changed && self.persist {
+            let _ = save_game_slot(&self.game_state, self.save_slot);
+        }
+        self.load_backlog();
+
+        if self.game_state.waiting_until.is_some() {
+            self.wait_message = Some(format!(
+                "{} {}",
+                sys_msg(Msg::ElaraUnavailable, lang),
+                sys_msg(Msg::ElaraBackAround, lang),
+            ));
+        }
+    }
+
+    /// Where the current message reveal stands, if one is partway through.
+    fn typing_progress(&self) -> Option<TypingProgress> {
+        let tw = self.typewriter.as_ref()?;
+        if self.rereading || tw.is_done() {
+            return None;
+        }
+        Some(TypingProgress {
+            node: self.game_state.current_node.clone(),
+            message_index: self.game_state.node_message_index,
+            revealed: tw.revealed,
+        })
+    }
+
+    /// Show a chat entry that has no message log entry, like a re-read line.
+    fn push_unlogged(&mut self, entry: ChatEntry) {
+        self.unlogged_chat.insert(self.chat.len());
+        self.chat.push(entry);
+    }
+
+    /// Empty the chat.
+    pub fn clear_chat(&mut self) {
+        self.chat.clear();
+        self.unlogged_chat.clear();
+    }
+
     /// Load the backlog from the game state's message log into the chat.
     pub fn load_backlog(&mut self) {
-        for entry in &self.game_state.message_log {
+        let log = std::mem::take(&mut self.game_state.message_log);
+        for entry in &log {
             match entry.sender {
                 Sender::Elara => self.chat.push(ChatEntry::Elara(entry.text.clone())),
                 Sender::Player => self.chat.push(ChatEntry::Player(entry.text.clone())),
                 Sender::System => {
                     if entry.text.starts_with("SESSION:") {
                         let label = entry.text.trim_start_matches("SESSION:").to_string();
-                        self.chat.push(ChatEntry::Separator(label));
+                        self.push_separator(label);
                     } else {
                         self.chat.push(ChatEntry::System(entry.text.clone()));
                     }
                 }
             }
         }
+        self.game_state.message_log = log;
     }
 
-    fn move_to_node(&mut self, next_node: String) {
-        self.game_state.current_node = next_node;
-        self.game_state.node_message_index = 0;
+    /// Bookmark the latest message, or remove its bookmark (`b`).
+    pub fn toggle_bookmark(&mut self) {
+        let Some(added) = self.game_state.toggle_bookmark_latest() else {
+            return;
+        };
+        let msg = if added {
+            Msg::BookmarkAdded
+        } else {
+            Msg::BookmarkRemoved
+        };
+        self.chat
+            .push(ChatEntry::System(sys_msg(msg, self.lang()).to_string()));
+        self.chat_scroll = 0;
+        self.save();
     }
 
-    /// Process the current story node: apply on_enter effects, queue messages, prepare choices.
-    pub fn process_current_node(&mut self) {
-        self.advance_story = false;
+    /// Which chat entries are bookmarked. Logged Elara and player entries
+    /// line up with their log entries counting back from the latest, since
+    /// the chat may hold messages the log has since trimmed.
+    pub fn bookmarked_chat_entries(&self) -> Vec<bool> {
+        let conversational = |(i, entry): (usize, &ChatEntry)| {
+            matches!(entry, ChatEntry::Elara(_) | ChatEntry::Player(_))
+                && !self.unlogged_chat.contains(&i)
+        };
+        let logged: Vec<usize> = self
+            .game_state
+            .message_log
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.sender != Sender::System)
+            .map(|(i, _)| i)
+            .collect();
+        let in_chat = self
+            .chat
+            .iter()
+            .enumerate()
+            .filter(|&e| conversational(e))
+            .count();
+        let mut from_end = in_chat;
+        self.chat
+            .iter()
+            .enumerate()
+            .map(|entry| {
+                if !conversational(entry) {
+                    return false;
+                }
+                from_end -= 1;
+                logged
+                    .len()
+                    .checked_sub(from_end + 1)
+                    .is_some_and(|i| self.game_state.bookmarks.contains(&logged[i]))
+            })
+            .collect()
+    }
 
-        let node = match self.story_data.nodes.get(&self.game_state.current_node) {
-            Some(n) => n.clone(),
-            None => {
-                self.chat.push(ChatEntry::System(format!(
-                    "Error: story node '{}' not found.",
-                    self.game_state.current_node
-                )));
-                self.should_quit = true;
-                return;
-            }
+    /// Write the current state to the quick-save slot (F5).
+    pub fn quick_save(&mut self) {
+        self.quickload_pending = false;
+        let dir = self.quicksave_path.parent().map(Path::to_path_buf);
+        let result = dir
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| crate::game::save_game_to(&self.game_state, &self.quicksave_path));
+        let line = match result {
+            Ok(()) => sys_msg(Msg::QuickSaved, self.lang()).to_string(),
+            Err(e) => format!("{} {}", sys_msg(Msg::QuickSaveFailed, self.lang()), e),
         };
+        self.chat.push(ChatEntry::System(line));
+        self.chat_scroll = 0;
+    }
 
-        // Apply on_enter effects only the first time we enter a node.
-        if self.game_state.node_message_index == 0 {
-            if let Some(ref effects) = node.on_enter {
-                let health_changed = effects.apply(&mut self.game_state);
-                // Death check: if health dropped to 0, redirect to death node
-                if health_changed && self.check_death() {
-                    return;
-                }
+    /// Restore the quick-save slot (F9). Asks for a second press first when
+    /// the conversation has moved on since the quick-save was made.
+    pub fn quick_load(&mut self) {
+        let mut loaded = match crate::game::load_game_from(&self.quicksave_path) {
+            Ok(state) => state,
+            Err(e) => {
+                // A quick-save that exists but can't be read is not "no quick-save"
+                let line = if e.kind() == std::io::ErrorKind::NotFound {
+                    sys_msg(Msg::QuickSaveMissing, self.lang()).to_string()
+                } else {
+                    log::warn!("quickload_failed error={}", e);
+                    format!("{} {}", sys_msg(Msg::QuickSaveUnreadable, self.lang()), e)
+                };
+                self.quickload_pending = false;
+                self.chat.push(ChatEntry::System(line));
+                self.chat_scroll = 0;
+                return;
             }
+        };
+        // Preferences are not part of the checkpoint
+        loaded.language = self.game_state.language;
+        loaded.settings = self.game_state.settings.clone();
+
+        let diverged = loaded.message_log.len() != self.game_state.message_log.len()
+            || loaded.node_message_index != self.game_state.node_message_index
+            || !crate::game::diff_states(&self.game_state, &loaded).is_empty();
+        if diverged && !self.quickload_pending {
+            self.quickload_pending = true;
+            self.chat.push(ChatEntry::System(
+                sys_msg(Msg::QuickLoadConfirm, self.lang()).to_string(),
+            ));
+            self.chat_scroll = 0;
+            return;
         }
+        self.quickload_pending = false;
+
+        log::info!("quickload node={}", loaded.current_node);
+        self.adopt_state(loaded);
+        self.chat.push(ChatEntry::System(
+            sys_msg(Msg::QuickLoaded, self.lang()).to_string(),
+        ));
+        self.save();
+    }
 
-        let lang = self.lang();
-
-        // Queue all messages for typewriter display
-        self.message_queue.clear();
-        if self.game_state.node_message_index > node.messages.len() {
-            self.game_state.node_message_index = node.messages.len();
-        }
-        for msg in node
-            .messages
-            .iter()
-            .skip(self.game_state.node_message_index)
-        {
-            self.message_queue.push(msg.get(lang).to_string());
+    /// Roll back to the latest checkpoint, from the pause menu or the ending screen.
+    pub fn restore_checkpoint(&mut self) {
+        let mut state = self.game_state.clone();
+        if !state.restore_checkpoint() {
+            return;
         }
-
-        // Start the first message
-        self.start_next_message();
+        log::info!("checkpoint_restored node={}", state.current_node);
+        let name = self
+            .story_data
+            .nodes
+            .get(&state.current_node)
+            .and_then(|n| n.checkpoint.as_ref())
+            .map(|name| self.story_data.localized(name, self.lang()).into_owned())
+            .unwrap_or_default();
+        self.adopt_state(state);
+        self.overlay = Overlay::None;
+        self.ending_reached = None;
+        self.new_ending = false;
+        self.screen = Screen::Game;
+        self.chat.push(ChatEntry::System(format!(
+            "{} {}",
+            sys_msg(Msg::CheckpointRestored, self.lang()),
+            name
+        )));
+        self.save();
     }
 
-    /// Check if the player is dead (health <= 0) and redirect to death node if so.
-    /// Returns true if death was triggered.
-    fn check_death(&mut self) -> bool {
-        if self.game_state.stats.health <= 0 {
-            if let Some(ref dc) = self.story_data.death_check {
-                self.move_to_node(dc.override_next_node.clone());
-                let _ = save_game(&self.game_state);
-                self.advance_story = true;
-                return true;
+    /// Step back to just before the last choice, from the pause menu. Not
+    /// past a real-time wait, since that time has already gone by.
+    pub fn rewind(&mut self) {
+        let mut state = self.game_state.clone();
+        let msg = match state.undo() {
+            Ok(()) => {
+                log::info!("rewound node={}", state.current_node);
+                self.adopt_state(state);
+                self.screen = Screen::Game;
+                self.save();
+                Msg::Rewound
             }
-        }
-        false
+            Err(RewindBlocked::Wait) => Msg::RewindBlockedByWait,
+            Err(RewindBlocked::Nothing) => Msg::RewindNothing,
+        };
+        self.overlay = Overlay::None;
+        self.chat
+            .push(ChatEntry::System(sys_msg(msg, self.lang()).to_string()));
     }
 
-    /// Pop the next message from the queue and start its typewriter animation.
-    fn start_next_message(&mut self) {
-        self.wait_for_space = false;
-
-        if self.message_queue.is_empty() {
-            // All messages displayed — now handle the node's outcome
-            self.handle_node_outcome();
+    /// Cut the current real-time wait short, using up one of the run's skips.
+    /// Does nothing when there is no wait or no skip left.
+    pub fn skip_wait(&mut self) {
+        let Some(until) = self.game_state.waiting_until else {
+            return;
+        };
+        if self.game_state.wait_skips_remaining == 0 || !crate::time::is_waiting(&self.game_state) {
             return;
         }
+        self.game_state.wait_skips_remaining -= 1;
+        log::info!(
+            "wait_skipped skips_remaining={}",
+            self.game_state.wait_skips_remaining
+        );
+        // Only the part actually waited counts as endured
+        let unserved = (until - chrono::Utc::now()).num_seconds().max(0) as u64;
+        self.game_state.waited_secs = self.game_state.waited_secs.saturating_sub(unserved);
+        self.game_state.waiting_until = None;
+        self.wait_message = None;
+        self.session_clock = Instant::now();
+        self.save();
+        self.advance_story = true;
+    }
 
-        let text = self.message_queue.remove(0);
-        let mut tw = TypewriterState::new(text, self.game_state.settings.text_speed);
-        if self.game_state.settings.text_speed == TextSpeed::Instant {
-            tw.skip();
-        }
-        self.typewriter = Some(tw);
+    /// Save and leave; a pending wait carries on while the game is closed.
+    pub fn save_and_quit(&mut self) {
+        self.save_now();
+        self.chat.push(ChatEntry::System(
+            sys_msg(Msg::SavedAndQuit, self.lang()).to_string(),
+        ));
+        self.should_quit = true;
+        self.overlay = Overlay::None;
     }
 
-    /// Called when all messages for the current node have been displayed.
-    fn handle_node_outcome(&mut self) {
-        let node = match self.story_data.nodes.get(&self.game_state.current_node) {
-            Some(n) => n.clone(),
-            None => return,
+    /// Write the run's transcript as Markdown into `dir` and say where it went.
+    pub fn export_transcript(&mut self, dir: &Path) {
+        let line = match crate::game::write_transcript(
+            &self.game_state,
+            crate::game::TranscriptFormat::Markdown,
+            dir,
+        ) {
+            Ok(path) => {
+                log::info!("transcript_exported path={}", path.display());
+                format!(
+                    "{} {}",
+                    sys_msg(Msg::TranscriptExported, self.lang()),
+                    path.display()
+                )
+            }
+            Err(e) => format!("{} {}", sys_msg(Msg::TranscriptFailed, self.lang()), e),
         };
+        self.overlay = Overlay::None;
+        self.chat.push(ChatEntry::System(line));
+        self.chat_scroll = 0;
+    }
 
+    /// Stop on the error screen for a node the story is missing, offering
+    /// the last checkpoint (when there is one) or a new game.
+    fn show_story_error(&mut self) {
+        log::error!("node_missing node={}", self.game_state.current_node);
         let lang = self.lang();
+        self.typewriter = None;
+        self.message_queue.clear();
+        self.post_message_pause = None;
+        self.choices.clear();
+        self.prompt_options.clear();
+        if !self.game_state.checkpoints.is_empty() {
+            self.prompt_options
+                .push(sys_msg(Msg::StoryErrorCheckpoint, lang).to_string());
+        }
+        self.prompt_options
+            .push(sys_msg(Msg::StoryErrorNewGame, lang).to_string());
+        self.prompt_index = 0;
+        self.screen = Screen::StoryError;
+    }
 
-        // 1. Check for ending
-        if let Some(ref ending_key) = node.ending {
-            self.game_state.ending = Some(ending_key.clone());
-            let _ = save_game(&self.game_state);
-            self.ending_reached = Some(ending_key.clone());
-            self.screen = Screen::Ending;
-            self.prompt_options = vec![
-                sys_msg(Msg::YesOption, lang).to_string(),
-                sys_msg(Msg::NoOption, lang).to_string(),
-            ];
-            self.prompt_index = 0;
-            return;
+    /// Act on the option picked on the story error screen.
+    pub fn resolve_story_error(&mut self, index: usize) {
+        if !self.game_state.checkpoints.is_empty() && index == 0 {
+            self.restore_checkpoint();
+        } else {
+            self.start_over();
         }
+    }
 
-        // 2. Handle conditional branching (evaluated in order; first match wins)
-        if let Some(ref branches) = node.branch {
-            for branch in branches {
-                if branch.condition.evaluate(&self.game_state) {
-                    self.move_to_node(branch.next_node.clone());
-                    let _ = save_game(&self.game_state);
-                    self.advance_story = true;
-                    return;
-                }
-            }
-            // No branch matched — this shouldn't happen if story is well-formed,
-            // but fall through to choices/next_node
+    /// A new game's state, starting with the flags earlier endings unlocked.
+    pub fn fresh_state(&self, lang: Language) -> GameState {
+        let mut state = GameState::from_story(lang, &self.story_data);
+        for flag in &self.ledger.unlocked_flags {
+            state.set_flag(flag);
+        }
+        state
+    }
+
+    /// Drop the run and go back to the language prompt for a new game.
+    pub fn start_over(&mut self) {
+        if self.persist {
+            let _ = crate::game::delete_save_slot(self.save_slot);
         }
+        self.game_state = self.fresh_state(Language::En);
+        crate::time::set_waiting_times_enabled(self.game_state.settings.waiting_times_enabled);
+        self.clear_chat();
+        self.ending_reached = None;
+        self.screen = Screen::LanguageSelect;
+        self.prompt_options = vec![
+            sys_msg(Msg::LanguageOption1, Language::En).to_string(),
+            sys_msg(Msg::LanguageOption2, Language::En).to_string(),
+            sys_msg(Msg::LanguageOption3, Language::En).to_string(),
+        ];
+        self.prompt_index = 0;
+        self.wait_for_space = false;
+        self.typewriter = None;
+        self.post_message_pause = None;
+        self.message_queue.clear();
+        self.choices.clear();
+        self.choice_index = 0;
+    }
 
-        // 3. Handle choices
-        if let Some(ref choices) = node.choices {
-            if !choices.is_empty() {
-                let choice_labels: Vec<String> = choices
-                    .iter()
-                    .map(|c| c.label.get(lang).to_string())
-                    .collect();
+    /// Space is being held: reveals and pauses collapse until it's let go,
+    /// and choices on screen stop it.
+    pub fn fast_forwarding(&self) -> bool {
+        self.game_state.settings.fast_forward
+            && self.choices.is_empty()
+            && self
+                .fast_forward_until
+                .is_some_and(|until| Instant::now() < until)
+    }
 
-                self.choices = choice_labels;
-                self.choice_index = 0;
-                return;
+    /// Open the list of named runs from the pause menu.
+    pub fn open_saved_runs(&mut self) {
+        match SavedRuns::load_from(&self.saved_runs_path) {
+            Ok(runs) => {
+                self.saved_runs = runs;
+                self.saved_runs_unreadable = None;
+            }
+            Err(e) => {
+                log::warn!("saved_runs_unreadable error={}", e);
+                self.saved_runs = SavedRuns::default();
+                self.saved_runs_unreadable = Some(format!(
+                    "{} {}",
+                    sys_msg(Msg::SavedRunsUnreadable, self.lang()),
+                    e
+                ));
             }
         }
+        self.saved_runs_index = 0;
+        self.saved_run_label = None;
+        self.saved_runs_notice = None;
+        self.overlay = Overlay::SavedRuns;
+    }
 
-        // 4. Handle real-time delay
+    /// Store the current run under the name being typed.
+    pub fn save_named_run(&mut self) {
+        let Some(label) = self.saved_run_label.take() else {
+            return;
+        };
+        if self.saved_runs_unreadable.is_some() {
+            return;
+        }
+        let lang = self.lang();
+        if !self.saved_runs.add(&label, &self.game_state) {
+            // Blank names just go back to the list; a full list says so
+            if self.saved_runs.is_full() {
+                self.saved_runs_notice = Some(sys_msg(Msg::SavedRunsFull, lang).to_string());
+            }
+            return;
+        }
+        let label = saved_run_label(&label);
+        self.saved_runs_notice = Some(match self.saved_runs.save_to(&self.saved_runs_path) {
+            Ok(()) => {
+                log::info!("run_saved label={:?}", label);
+                format!("{} {}", sys_msg(Msg::SavedRunStored, lang), label)
+            }
+            Err(e) => format!("{} {}", sys_msg(Msg::SavedRunsError, lang), e),
+        });
+        self.saved_runs_index = self
+            .saved_runs
+            .runs
+            .iter()
+            .position(|r| r.label == label)
+            .map_or(0, |i| i + 1);
+    }
+
+    /// Delete the named run at `index` in the list.
+    pub fn delete_saved_run(&mut self, index: usize) {
+        let Some(run) = self.saved_runs.remove(index) else {
+            return;
+        };
+        let lang = self.lang();
+        self.saved_runs_notice = Some(match self.saved_runs.save_to(&self.saved_runs_path) {
+            Ok(()) => format!("{} {}", sys_msg(Msg::SavedRunDeleted, lang), run.label),
+            Err(e) => format!("{} {}", sys_msg(Msg::SavedRunsError, lang), e),
+        });
+        self.saved_runs_index = self.saved_runs_index.min(self.saved_runs.runs.len());
+    }
+
+    /// Play on from the named run at `index`, replacing the current one.
+    pub fn restore_saved_run(&mut self, index: usize) {
+        let Some(state) = self.saved_runs.restore(index, &self.game_state) else {
+            return;
+        };
+        let label = self.saved_runs.runs[index].label.clone();
+        log::info!("run_restored label={:?} node={}", label, state.current_node);
+        self.adopt_state(state);
+        self.overlay = Overlay::None;
+        self.ending_reached = None;
+        self.new_ending = false;
+        self.screen = Screen::Game;
+        self.chat.push(ChatEntry::System(format!(
+            "{} {}",
+            sys_msg(Msg::SavedRunRestored, self.lang()),
+            label
+        )));
+        self.save();
+    }
+
+    /// Replace the game state mid-run and reset the conversation around it.
+    fn adopt_state(&mut self, state: GameState) {
+        self.game_state = state;
+        self.current_act = self
+            .story_data
+            .nodes
+            .get(&self.game_state.current_node)
+            .and_then(|n| n.act);
+        self.typewriter = None;
+        self.message_queue.clear();
+        self.post_message_pause = None;
+        self.wait_for_space = false;
+        self.rereading = false;
+        self.choices.clear();
+        self.choice_index = 0;
+        self.wait_message = None;
+        self.clear_chat();
+        self.load_backlog();
+        self.chat_scroll = 0;
+        self.advance_story = true;
+        if self.screen == Screen::Waiting && !crate::time::is_waiting(&self.game_state) {
+            self.screen = Screen::Game;
+        }
+    }
+
+    fn move_to_node(&mut self, next_node: String) {
+        self.game_state.current_node = next_node;
+        self.game_state.node_message_index = 0;
+        self.game_state.node_entered = false;
+    }
+
+    /// Follow a story edge to `next`. `@return` resumes where an interrupt
+    /// cut in, and the first time supplies have run out the story detours
+    /// through the supplies-depleted node (never on the way to an ending).
+    fn follow_edge(&mut self, next: String) {
+        let next = if next == RETURN_NODE {
+            match self.game_state.interrupt_return.take() {
+                Some(target) => target,
+                None => {
+                    log::error!(
+                        "return_without_interrupt node={}",
+                        self.game_state.current_node
+                    );
+                    next
+                }
+            }
+        } else {
+            next
+        };
+
+        let heading_to_ending = self
+            .story_data
+            .nodes
+            .get(&next)
+            .is_some_and(|n| n.ending.is_some());
+        let interrupt = self
+            .story_data
+            .meta
+            .supplies_depleted_node
+            .clone()
+            .filter(|id| self.story_data.nodes.contains_key(id));
+        if let Some(interrupt) = interrupt {
+            if self.game_state.stats.supplies <= 0
+                && !self.game_state.supplies_interrupt_fired
+                && !heading_to_ending
+            {
+                log::info!(
+                    "supplies_depleted from={} to={} return={}",
+                    self.game_state.current_node,
+                    interrupt,
+                    next
+                );
+                self.game_state.supplies_interrupt_fired = true;
+                self.game_state.interrupt_return = Some(next);
+                self.move_to_node(interrupt);
+                return;
+            }
+        }
+        self.move_to_node(next);
+    }
+
+    /// Process the current story node: apply on_enter effects, queue messages, prepare choices.
+    pub fn process_current_node(&mut self) {
+        self.advance_story = false;
+        let previous_act = self.current_act;
+        if let Some(act) = self
+            .story_data
+            .nodes
+            .get(&self.game_state.current_node)
+            .and_then(|n| n.act)
+        {
+            self.current_act = Some(act);
+        }
+
+        let node = match self.story_data.nodes.get(&self.game_state.current_node) {
+            Some(n) => n.clone(),
+            None => {
+                self.show_story_error();
+                return;
+            }
+        };
+
+        log::info!(
+            "node_entered node={} message_index={} day={}",
+            node.id,
+            self.game_state.node_message_index,
+            self.game_state.day
+        );
+
+        // Apply on_enter effects only the first time we enter a node, even if
+        // the game was saved before its first message finished.
+        if self.game_state.node_message_index == 0 && !self.game_state.node_entered {
+            // Endings carry the last act's number; dying early shouldn't announce it
+            if let (Some(previous), Some(act)) = (previous_act, node.act) {
+                if act > previous && node.ending.is_none() {
+                    self.show_act_card(act);
+                }
+            }
+            if let Some(ref name) = node.checkpoint {
+                // Snapshot before on_enter so a restore replays the whole node
+                self.game_state.record_checkpoint();
+                log::info!("checkpoint_recorded node={}", node.id);
+                self.chat.push(ChatEntry::System(format!(
+                    "{} {}",
+                    sys_msg(Msg::CheckpointReached, self.lang()),
+                    self.story_data.localized(name, self.lang())
+                )));
+            }
+            self.game_state.node_entered = true;
+            if let Some(ref effects) = node.on_enter {
+                let health_changed = effects.apply(&mut self.game_state);
+                // Death check: if health dropped to 0, redirect to death node
+                if health_changed && self.check_death() {
+                    return;
+                }
+            }
+        }
+
+        // The author's note goes ahead of the messages on a fresh entry
+        if self.game_state.node_message_index == 0 && self.commentary_unlocked() {
+            if let Some(ref note) = node.commentary {
+                let note = self.story_data.localized(note, self.lang()).into_owned();
+                self.chat.push(ChatEntry::Commentary(note));
+            }
+        }
+
+        // Queue all messages whose conditions hold for typewriter display
+        self.message_queue.clear();
+        let messages = node.visible_messages(&self.game_state);
+        if self.game_state.node_message_index > messages.len() {
+            self.game_state.node_message_index = messages.len();
+        }
+        for msg in messages.iter().skip(self.game_state.node_message_index) {
+            self.message_queue.push((*msg).clone());
+        }
+
+        // Routing-only nodes (no messages left) advance silently, without
+        // starting a typewriter that would flash an empty typing indicator.
+        if self.message_queue.is_empty() {
+            self.typewriter = None;
+            self.handle_node_outcome();
+            return;
+        }
+
+        // Start the first message, picking up a reveal interrupted by a save
+        let typing = self.game_state.typing.take();
+        self.start_next_message();
+        if let (Some(progress), Some(tw)) = (typing, self.typewriter.as_mut()) {
+            if progress.node == node.id
+                && progress.message_index == self.game_state.node_message_index
+            {
+                tw.resume_from(progress.revealed);
+            }
+        }
+    }
+
+    /// Check if the player is dead (health <= 0) and redirect to death node if so.
+    /// Returns true if death was triggered. It fires once per run: the death
+    /// sequence is left to play out, even if it costs more health, instead of
+    /// being re-entered.
+    fn check_death(&mut self) -> bool {
+        if self.game_state.stats.health <= 0 && !self.game_state.death_triggered {
+            if let Some(ref dc) = self.story_data.death_check {
+                self.game_state.death_triggered = true;
+                log::info!(
+                    "death_check_triggered from={} to={}",
+                    self.game_state.current_node,
+                    dc.override_next_node
+                );
+                self.move_to_node(dc.override_next_node.clone());
+                self.save();
+                self.advance_story = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Pop the next message from the queue and start its typewriter animation.
+    fn start_next_message(&mut self) {
+        self.wait_for_space = false;
+
+        if self.message_queue.is_empty() {
+            // All messages displayed — now handle the node's outcome
+            self.handle_node_outcome();
+            return;
+        }
+
+        let msg = self.message_queue.remove(0);
+        let settings = &self.game_state.settings;
+        let text = self.story_data.localized(&msg.text, self.lang());
+        let mut tw = TypewriterState::new(text.into_owned(), settings.text_speed);
+        // Pace by the language the line is actually shown in
+        let shown_in = if msg.text.is_missing(self.lang()) {
+            Language::En
+        } else {
+            self.lang()
+        };
+        tw.pace_percent = settings.language_pace.get(shown_in);
+        if let Some(ms) = msg.pause_after_ms {
+            tw.pause_after_ms = ms;
+        }
+        if msg.garbled && !settings.reduce_motion {
+            tw.garble();
+        }
+        if settings.typing_rhythm && !settings.reduce_motion {
+            tw.humanize();
+        }
+        // Reduce motion lands every line whole, with no typing indicator
+        if settings.text_speed == TextSpeed::Instant || settings.reduce_motion {
+            tw.skip();
+        } else if let Some(ms) = msg.reveal_ms {
+            tw.char_tick_ms = ms;
+        }
+        self.typewriter = Some(tw);
+    }
+
+    /// Called when all messages for the current node have been displayed.
+    fn handle_node_outcome(&mut self) {
+        let node = match self.story_data.nodes.get(&self.game_state.current_node) {
+            Some(n) => n.clone(),
+            None => return,
+        };
+
+        let lang = self.lang();
+
+        // Terms Elara has now brought up become readable in the glossary
+        for id in self.story_data.unlock_glossary(&mut self.game_state) {
+            // Entries known from the start don't need announcing
+            let Some(entry) = self.story_data.glossary.get(&id) else {
+                continue;
+            };
+            if entry.unlock_flag.is_some() {
+                self.chat.push(ChatEntry::System(format!(
+                    "{} {}",
+                    sys_msg(Msg::GlossaryUnlocked, lang),
+                    entry.term.get(lang)
+                )));
+            }
+        }
+
+        // 1. Check for ending
+        if let Some(ref ending_key) = node.ending {
+            log::info!("ending_reached ending={} node={}", ending_key, node.id);
+            self.game_state.ending = Some(ending_key.clone());
+            self.save_now();
+            self.new_ending = self.ledger.record(ending_key, self.game_state.day);
+            let unlocks = self
+                .story_data
+                .endings
+                .get(ending_key)
+                .map(|info| info.unlocks.as_slice())
+                .unwrap_or_default();
+            let unlocked = self.ledger.unlock_flags(unlocks);
+            if (self.new_ending || unlocked) && self.persist {
+                let _ = self.ledger.save();
+            }
+            self.ending_reached = Some(ending_key.clone());
+            self.screen = Screen::Ending;
+            self.prompt_options = ending_items(self)
+                .into_iter()
+                .map(|item| sys_msg(item.label(), lang).to_string())
+                .collect();
+            self.prompt_index = 0;
+            return;
+        }
+
+        // 2. Handle conditional branching (evaluated in order; first match wins)
+        if let Some(ref branches) = node.branch {
+            for (index, branch) in branches.iter().enumerate() {
+                let matched = branch.condition.evaluate(&self.game_state);
+                log::debug!(
+                    "branch_evaluated node={} index={} next_node={} matched={}",
+                    node.id,
+                    index,
+                    branch.next_node,
+                    matched
+                );
+                if matched {
+                    self.follow_edge(branch.next_node.clone());
+                    self.save();
+                    self.advance_story = true;
+                    return;
+                }
+            }
+            // No branch matched — this shouldn't happen if story is well-formed,
+            // but fall through to choices/next_node
+        }
+
+        // 3. Handle choices
+        if let Some(ref choices) = node.choices {
+            // When every choice is hidden by its condition, fall through to next_node
+            let choice_labels = self.choice_labels(&node);
+            if !choices.is_empty() && !choice_labels.is_empty() {
+                self.rereading = false;
+
+                self.choices = choice_labels;
+                self.choice_data = self.choice_data(&node);
+                self.reset_choice_cursor();
+                self.confirm_pending = None;
+                self.nudge_pending_since = Some(Instant::now());
+                self.choices_shown_at = Some(Instant::now());
+                return;
+            }
+        }
+
+        // 4. Handle real-time delay
         if let Some(ref delay_info) = node.delay {
             let next = if let Some(ref next) = node.next_node {
                 next.clone()
             } else {
-                self.should_quit = true;
+                self.recover_from_dead_end(&node.id);
                 return;
             };
 
-            self.move_to_node(next);
-            crate::time::schedule_wait(&mut self.game_state, delay_info.seconds);
-            let _ = save_game(&self.game_state);
+            log::info!(
+                "wait_scheduled node={} seconds={} next_node={}",
+                node.id,
+                delay_info.seconds,
+                next
+            );
+            self.follow_edge(next);
+            let seconds = if self.debug_delays {
+                let cap = self.story_data.meta.debug_delay_override_seconds;
+                crate::time::debug_delay(delay_info.seconds, cap)
+            } else {
+                delay_info.seconds
+            };
+            // Count the connected time so far before the wait stops the clock
+            self.flush_playtime();
+            crate::time::schedule_wait(&mut self.game_state, seconds);
+            if self.game_state.waiting_until.is_some() {
+                self.game_state.waited_secs += seconds;
+                self.game_state.block_rewind();
+            }
+            self.save_now();
 
             if let Some(until) = self.game_state.waiting_until {
                 let remaining = crate::time::remaining_time_str(until, lang);
-                let delay_msg = delay_info.message.get(lang);
+                let delay_msg = self.story_data.localized(&delay_info.message, lang);
                 let line = format!("{} (~{})", delay_msg, remaining);
                 self.wait_message = Some(delay_msg.to_string());
                 self.chat.push(ChatEntry::System(line.clone()));
@@ -458,36 +1783,162 @@ impl App {
 
         // 5. Linear next_node
         if let Some(ref next) = node.next_node {
-            self.move_to_node(next.clone());
-            let _ = save_game(&self.game_state);
+            self.follow_edge(next.clone());
+            self.save();
             self.advance_story = true;
         } else {
             // Dead end — should not happen with a valid story
-            self.should_quit = true;
+            self.recover_from_dead_end(&node.id);
+        }
+    }
+
+    /// A node left the story nowhere to go. Route to the story's fallback
+    /// node if it has one, otherwise say so on screen and stay put.
+    fn recover_from_dead_end(&mut self, node_id: &str) {
+        let fallback = self
+            .story_data
+            .meta
+            .fallback_node
+            .clone()
+            .filter(|f| f != node_id && self.story_data.nodes.contains_key(f));
+        log::error!(
+            "dead_end node={} fallback={}",
+            node_id,
+            fallback.as_deref().unwrap_or("none")
+        );
+        match fallback {
+            Some(next) => {
+                self.move_to_node(next);
+                self.save();
+                self.advance_story = true;
+            }
+            None => {
+                self.chat.push(ChatEntry::System(
+                    sys_msg(Msg::StoryDeadEnd, self.lang()).to_string(),
+                ));
+                self.chat_scroll = 0;
+                self.advance_story = false;
+            }
         }
     }
 
     /// Apply a chosen choice: apply on_choose effects, advance node, check death.
     fn apply_choice(&mut self, choice: &Choice) {
         if let Some(ref effects) = choice.on_choose {
+            let trust_before = self.game_state.stats.trust;
             let health_changed = effects.apply(&mut self.game_state);
+            // Clamped or rounded-away changes stay silent
+            let cue = match self.game_state.stats.trust.cmp(&trust_before) {
+                std::cmp::Ordering::Greater => Some(Msg::TrustCueUp),
+                std::cmp::Ordering::Less => Some(Msg::TrustCueDown),
+                std::cmp::Ordering::Equal => None,
+            };
+            if let Some(cue) = cue.filter(|_| self.game_state.settings.trust_cues) {
+                self.chat
+                    .push(ChatEntry::System(sys_msg(cue, self.lang()).to_string()));
+            }
             if health_changed && self.check_death() {
                 return;
             }
         }
-        self.move_to_node(choice.next_node.clone());
-        let _ = save_game(&self.game_state);
+        self.follow_edge(choice.next_node.clone());
+        self.save();
         self.advance_story = true;
     }
 
+    /// Take the current node's choice at source `index` without asking,
+    /// confirming it if it needs confirmation. Used by replays and headless
+    /// runs; false when the node doesn't show that choice or it is locked.
+    pub fn take_choice(&mut self, index: usize) -> bool {
+        // The displayed position may differ from the source index
+        let position = self
+            .story_data
+            .nodes
+            .get(&self.game_state.current_node)
+            .and_then(|node| {
+                node.shown_choices(&self.game_state)
+                    .iter()
+                    .position(|&(i, c)| i == index && !c.is_locked(&self.game_state))
+            });
+        let Some(position) = position else {
+            return false;
+        };
+        self.choice_index = position;
+        self.select_choice();
+        if self.overlay == Overlay::ConfirmChoice {
+            self.confirm_choice();
+        }
+        true
+    }
+
     /// Called when the player selects a choice.
     pub fn select_choice(&mut self) {
         if self.choices.is_empty() {
             return;
         }
 
+        // Re-derive what the current node offers; if it no longer lines up
+        // with what was displayed, show the fresh list instead of guessing.
+        let node = self
+            .story_data
+            .nodes
+            .get(&self.game_state.current_node)
+            .cloned();
+        let expected = node
+            .as_ref()
+            .map(|n| self.choice_labels(n))
+            .unwrap_or_default();
+        if expected != self.choices || self.choice_index >= expected.len() {
+            log::error!(
+                "choice_mapping_failed node={} index={} shown={} expected={}",
+                self.game_state.current_node,
+                self.choice_index,
+                self.choices.len(),
+                expected.len()
+            );
+            self.choices = expected;
+            self.choice_data = node
+                .as_ref()
+                .map(|n| self.choice_data(n))
+                .unwrap_or_default();
+            self.reset_choice_cursor();
+            return;
+        }
+        let Some(node) = node else {
+            return;
+        };
+
         let label = self.choices[self.choice_index].clone();
 
+        // "Re-read that" sits after the node's real choices
+        let shown = node.shown_choices(&self.game_state);
+        if self.choice_index == shown.len() {
+            self.push_unlogged(ChatEntry::Player(label));
+            self.reread_current_node();
+            return;
+        }
+
+        // The displayed position may differ from the source index
+        let Some((index, chosen)) = shown.get(self.choice_index).map(|&(i, c)| (i, c.clone()))
+        else {
+            return;
+        };
+
+        // Locked choices are listed for information only
+        if chosen.is_locked(&self.game_state) {
+            return;
+        }
+
+        // Heavy choices ask first; nothing is applied until confirmed
+        if chosen.confirm.is_some() && self.confirm_pending != Some(self.choice_index) {
+            self.confirm_pending = Some(self.choice_index);
+            self.confirm_index = 1;
+            self.overlay = Overlay::ConfirmChoice;
+            return;
+        }
+        self.confirm_pending = None;
+        self.game_state.record_rewind_step();
+
         // Show player's choice in chat
         self.chat.push(ChatEntry::Player(label.clone()));
         self.game_state.message_log.push(LogEntry {
@@ -496,80 +1947,451 @@ impl App {
             timestamp: chrono::Utc::now(),
         });
 
-        // Find the original choice from the current node
-        let node = self
-            .story_data
-            .nodes
-            .get(&self.game_state.current_node)
-            .cloned();
-        if let Some(node) = node {
-            if let Some(ref choices) = node.choices {
-                if self.choice_index < choices.len() {
-                    let chosen = choices[self.choice_index].clone();
-                    self.choices.clear();
-                    self.apply_choice(&chosen);
-                }
-            }
+        self.game_state.decision_path.push(DecisionRecord {
+            node: node.id.clone(),
+            choice: index,
+        });
+        if self.ledger.record_choice(&node.id, index) && self.persist {
+            let _ = self.ledger.save();
         }
+        log::info!(
+            "choice_taken node={} index={} next_node={}",
+            node.id,
+            index,
+            chosen.next_node
+        );
+        self.choices.clear();
+        self.apply_choice(&chosen);
     }
 
-    /// Called when a typewriter animation finishes for a message.
-    pub fn on_message_complete(&mut self) {
-        if let Some(tw) = self.typewriter.take() {
-            let text = tw.full_text;
-            self.chat.push(ChatEntry::Elara(text.clone()));
-            self.game_state.message_log.push(LogEntry {
-                sender: Sender::Elara,
-                text,
-                timestamp: chrono::Utc::now(),
-            });
-            self.game_state.node_message_index =
-                self.game_state.node_message_index.saturating_add(1);
-            let _ = save_game(&self.game_state);
-        }
+    /// The confirmation prompt of the choice awaiting confirmation, if any.
+    pub fn confirm_prompt(&self) -> Option<String> {
+        let shown = self.confirm_pending?;
+        let node = self.story_data.nodes.get(&self.game_state.current_node)?;
+        let (_, choice) = *node.shown_choices(&self.game_state).get(shown)?;
+        let confirm = choice.confirm.as_ref()?;
+        Some(self.story_data.localized(confirm, self.lang()).into_owned())
+    }
 
-        if !self.game_state.settings.automatic_dialogs_enabled {
-            self.post_message_pause = None;
-            self.wait_for_space = true;
-        } else {
-            // Small pause before next message
-            self.post_message_pause = Some(Instant::now());
+    /// Commit the choice awaiting confirmation.
+    pub fn confirm_choice(&mut self) {
+        self.overlay = Overlay::None;
+        if let Some(shown) = self.confirm_pending {
+            self.choice_index = shown;
+            self.select_choice();
         }
     }
 
+    /// Drop the pending choice and go back to the choice list untouched.
+    pub fn cancel_choice_confirm(&mut self) {
+        self.confirm_pending = None;
+        self.overlay = Overlay::None;
+    }
+
+    /// Whether commentary notes can be shown: only on a New Game+ replay
+    /// after at least one ending, never on a first playthrough.
+    pub fn commentary_unlocked(&self) -> bool {
+        self.game_state.new_game_plus && !self.ledger.endings_seen.is_empty()
+    }
+
+    /// Themes unlocked by the endings discovered so far.
+    pub fn unlocked_themes(&self) -> Vec<Theme> {
+        self.ledger.unlocked_themes(self.story_data.endings.len())
+    }
+
+    /// Whether the minimum read time has passed since the choices appeared.
+    pub fn choices_ready(&self) -> bool {
+        let gate = Duration::from_millis(u64::from(self.game_state.settings.min_read_ms));
+        self.choices_shown_at
+            .is_none_or(|shown_at| shown_at.elapsed() >= gate)
+    }
+
+    /// Labels presented for a node's choices, including "Re-read that" when offered.
+    fn choice_labels(&self, node: &StoryNode) -> Vec<String> {
+        let lang = self.lang();
+        let mut labels: Vec<String> = node
+            .shown_choices(&self.game_state)
+            .into_iter()
+            .map(|(_, c)| {
+                let label = self.story_data.localized(&c.label, lang);
+                if !c.is_locked(&self.game_state) {
+                    return label.into_owned();
+                }
+                format!("{label} \u{2014} [{}]", self.lock_hint(c))
+            })
+            .collect();
+        if !labels.is_empty() && node.offers_reread() {
+            labels.push(sys_msg(Msg::RereadChoice, lang).to_string());
+        }
+        labels
+    }
+
+    /// Why a locked choice can't be taken: its own hint, else a generic "locked".
+    fn lock_hint(&self, choice: &Choice) -> String {
+        let lang = self.lang();
+        choice.locked_hint.as_ref().map_or_else(
+            || sys_msg(Msg::ChoiceLocked, lang).to_string(),
+            |h| self.story_data.localized(h, lang).into_owned(),
+        )
+    }
+
+    /// The lock hint of the choice shown at `index`, or None if it can be taken.
+    pub fn locked_choice_hint(&self, index: usize) -> Option<String> {
+        // Only trust the choice data if it belongs to the labels shown
+        if self.choice_data.len() != self.choices.len() {
+            return None;
+        }
+        let (_, choice) = self.choice_data.get(index)?.as_ref()?;
+        choice
+            .is_locked(&self.game_state)
+            .then(|| self.lock_hint(choice))
+    }
+
+    /// Move the choice cursor one entry up or down, wrapping around and
+    /// stepping over locked choices. It stays put when nothing else is open.
+    fn step_choice(&mut self, forward: bool) {
+        let count = self.choices.len();
+        let mut index = self.choice_index;
+        for _ in 0..count {
+            index = if forward {
+                (index + 1) % count
+            } else {
+                (index + count - 1) % count
+            };
+            if self.locked_choice_hint(index).is_none() {
+                self.choice_index = index;
+                return;
+            }
+        }
+    }
+
+    /// Put the cursor on the first choice that can be taken.
+    fn reset_choice_cursor(&mut self) {
+        self.choice_index = 0;
+        if self.locked_choice_hint(0).is_some() {
+            self.step_choice(true);
+        }
+    }
+
+    /// The story choices behind `choice_labels`, in the same order.
+    fn choice_data(&self, node: &StoryNode) -> Vec<Option<(usize, Choice)>> {
+        let mut data: Vec<Option<(usize, Choice)>> = node
+            .shown_choices(&self.game_state)
+            .into_iter()
+            .map(|(i, c)| Some((i, c.clone())))
+            .collect();
+        if !data.is_empty() && node.offers_reread() {
+            data.push(None);
+        }
+        data
+    }
+
+    /// Replay the current node's messages through the typewriter without
+    /// applying effects, logging, or moving the story forward.
+    fn reread_current_node(&mut self) {
+        let Some(node) = self.story_data.nodes.get(&self.game_state.current_node) else {
+            return;
+        };
+        self.message_queue = node
+            .visible_messages(&self.game_state)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.choices.clear();
+        self.rereading = true;
+        self.start_next_message();
+    }
+
+    /// Called when a typewriter animation finishes for a message.
+    pub fn on_message_complete(&mut self) {
+        let Some(tw) = self.typewriter.take() else {
+            return;
+        };
+        self.post_message_pause_ms = tw.pause_after_ms;
+        if self.rereading {
+            self.push_unlogged(ChatEntry::Elara(tw.full_text));
+        } else {
+            let text = tw.full_text;
+            self.chat.push(ChatEntry::Elara(text.clone()));
+            self.game_state.message_log.push(LogEntry {
+                sender: Sender::Elara,
+                text,
+                timestamp: chrono::Utc::now(),
+            });
+            self.game_state.node_message_index =
+                self.game_state.node_message_index.saturating_add(1);
+            self.save();
+        }
+
+        if !self.game_state.settings.automatic_dialogs_enabled {
+            self.post_message_pause = None;
+            self.wait_for_space = true;
+        } else {
+            // Small pause before next message
+            self.post_message_pause = Some(Instant::now());
+        }
+    }
+
+    /// Complete every pending message of the current node at once, then run
+    /// its outcome. Used by frontends that print text instead of animating it.
+    pub fn flush_messages(&mut self) {
+        while self.typewriter.is_some() {
+            self.on_message_complete();
+            self.post_message_pause = None;
+            self.start_next_message();
+        }
+    }
+
+    /// Have Elara check in once if the player has sat on the current choices
+    /// for longer than the idle nudge setting. Never picks a choice.
+    fn maybe_nudge(&mut self) {
+        let seconds = self.game_state.settings.idle_nudge_seconds;
+        let Some(shown_at) = self.nudge_pending_since else {
+            return;
+        };
+        if seconds == 0
+            || self.game_state.settings.reduce_motion
+            || self.screen != Screen::Game
+            || self.choices.is_empty()
+            || self.typewriter.is_some()
+            || self.post_message_pause.is_some()
+        {
+            return;
+        }
+        let delay = Duration::from_secs(u64::from(seconds));
+        if shown_at.elapsed() < delay || self.last_input.elapsed() < delay {
+            return;
+        }
+
+        self.nudge_pending_since = None;
+        let text = sys_msg(Msg::ElaraNudge, self.lang()).to_string();
+        self.chat.push(ChatEntry::Elara(text.clone()));
+        self.game_state.message_log.push(LogEntry {
+            sender: Sender::Elara,
+            text,
+            timestamp: chrono::Utc::now(),
+        });
+    }
+
+    /// Put up the title card for an act the story has just moved into,
+    /// unless it has no title or cards are switched off.
+    fn show_act_card(&mut self, act: u32) {
+        if !self.game_state.settings.act_cards
+            || !self.story_data.meta.acts.contains_key(&act)
+            || self.overlay != Overlay::None
+        {
+            return;
+        }
+        log::info!("act_card act={}", act);
+        self.act_card = Some((act, Instant::now()));
+        self.overlay = Overlay::ActCard;
+    }
+
+    /// The act card's heading ("ACT II") and title, while one is up.
+    pub fn act_card_text(&self) -> Option<(String, String)> {
+        let (act, _) = self.act_card?;
+        let lang = self.lang();
+        let title = self.story_data.meta.acts.get(&act)?;
+        let heading = format!(
+            "{} {}",
+            sys_msg(Msg::ActProgress, lang).to_uppercase(),
+            roman_numeral(act)
+        );
+        Some((heading, self.story_data.localized(title, lang).into_owned()))
+    }
+
+    /// Take down the act title card and carry on with the conversation.
+    pub fn close_act_card(&mut self) {
+        self.act_card = None;
+        self.resume_from_overlay();
+    }
+
     /// Close the overlay and reset animation timers so nothing fast-forwards.
     pub fn resume_from_overlay(&mut self) {
         self.overlay = Overlay::None;
+        self.resume_timers();
+    }
+
+    /// Freeze the animations; the time until `resume_timers` won't count.
+    pub fn suspend_timers(&mut self) {
+        self.paused_since.get_or_insert_with(Instant::now);
+    }
+
+    /// Take the time spent suspended off every animation timer, whichever
+    /// phase (typing indicator, reveal, post-message pause) was running.
+    fn resume_timers(&mut self) {
+        let Some(since) = self.paused_since.take() else {
+            return;
+        };
+        let paused = since.elapsed();
         if let Some(ref mut tw) = self.typewriter {
-            tw.resume();
+            tw.shift(paused);
         }
         if let Some(ref mut tw) = self.intro_typewriter {
-            tw.resume();
+            tw.shift(paused);
         }
-        // Reset post-message pause timer so it doesn't expire instantly
-        if self.post_message_pause.is_some() {
-            self.post_message_pause = Some(Instant::now());
+        if let Some(ref mut start) = self.post_message_pause {
+            *start += paused.min(start.elapsed());
+        }
+    }
+
+    /// Start the atmospheric radio intro on the intro screen.
+    fn start_intro(&mut self) {
+        self.screen = Screen::Intro;
+        let intro_text = sys_msg(Msg::IntroRadioCrackle, self.lang()).to_string();
+        let mut tw = TypewriterState::new(intro_text, TextSpeed::Normal);
+        tw.pace_percent = self.game_state.settings.language_pace.get(self.lang());
+        // No typing indicator for intro
+        tw.show_typing_indicator = false;
+        if self.game_state.settings.reduce_motion {
+            tw.skip();
+        }
+        self.intro_typewriter = Some(tw);
+    }
+
+    /// Start over from the ending screen keeping language, settings (and the
+    /// theme picked with them), and annotating choices explored in earlier runs.
+    pub fn start_new_game_plus(&mut self) {
+        if self.persist {
+            let _ = crate::game::delete_save_slot(self.save_slot);
+        }
+        let mut state = self.fresh_state(self.game_state.language);
+        state.settings = self.game_state.settings.clone();
+        state.new_game_plus = true;
+        self.game_state = state;
+        self.session_clock = Instant::now();
+        self.clear_chat();
+        self.ending_reached = None;
+        self.new_ending = false;
+        self.current_act = None;
+        self.wait_for_space = false;
+        self.typewriter = None;
+        self.post_message_pause = None;
+        self.message_queue.clear();
+        self.choices.clear();
+        self.choice_index = 0;
+        self.prompt_index = 0;
+        self.start_intro();
+    }
+
+    /// Open the branch explorer over the ending screen, if any choice was made.
+    pub fn open_explorer(&mut self) {
+        if self.screen != Screen::Ending || self.game_state.decision_path.is_empty() {
+            return;
+        }
+        self.explore_index = 0;
+        self.explore_open = false;
+        self.overlay = Overlay::Explorer;
+    }
+
+    /// Replay the intro from the pause menu, then return to the current screen.
+    /// The story position, chat and pending animations are left untouched.
+    pub fn replay_intro(&mut self) {
+        self.overlay = Overlay::None;
+        self.intro_return = Some(self.screen.clone());
+        self.start_intro();
+    }
+
+    /// Leave the intro screen once the player has read it.
+    pub fn finish_intro(&mut self) {
+        self.intro_typewriter = None;
+
+        if let Some(previous) = self.intro_return.take() {
+            // Replayed intro — restore the prior screen without touching the story.
+            self.screen =
+                if previous == Screen::Waiting && !crate::time::is_waiting(&self.game_state) {
+                    Screen::Game
+                } else {
+                    previous
+                };
+            self.resume_timers();
+            return;
+        }
+
+        self.screen = Screen::Game;
+        self.advance_story = true;
+
+        // Log session start
+        let now = chrono::Utc::now();
+        let label = now.format("%Y-%m-%d %H:%M").to_string();
+        self.game_state.message_log.push(LogEntry {
+            sender: Sender::System,
+            text: format!("SESSION:{}", label),
+            timestamp: now,
+        });
+        self.push_separator(label);
+    }
+
+    /// Show a session separator in the chat as the session markers setting asks.
+    /// Collapsed separators keep the latest session's label.
+    fn push_separator(&mut self, label: String) {
+        match self.game_state.settings.session_markers {
+            SessionMarkers::All => self.chat.push(ChatEntry::Separator(label)),
+            SessionMarkers::Collapsed => {
+                let resumed = sys_msg(Msg::SessionResumed, self.lang());
+                match self.chat.last_mut() {
+                    Some(ChatEntry::Separator(last)) => *last = format!("{} {}", label, resumed),
+                    _ => self.chat.push(ChatEntry::Separator(label)),
+                }
+            }
+            SessionMarkers::Hidden => {}
         }
     }
 
     pub fn open_pause_menu(&mut self) {
+        self.suspend_timers();
         self.menu_index = 0;
         self.menu_language_draft = self.game_state.language;
         self.menu_text_speed_draft = self.game_state.settings.text_speed;
         self.menu_waiting_times_enabled_draft = self.game_state.settings.waiting_times_enabled;
         self.menu_automatic_dialogs_enabled_draft =
             self.game_state.settings.automatic_dialogs_enabled;
+        self.menu_fast_forward_draft = self.game_state.settings.fast_forward;
+        self.menu_idle_timeout_draft = self.game_state.settings.idle_timeout_minutes;
+        self.menu_idle_nudge_draft = self.game_state.settings.idle_nudge_seconds;
+        self.menu_min_read_draft = self.game_state.settings.min_read_ms;
+        self.menu_typing_rhythm_draft = self.game_state.settings.typing_rhythm;
+        self.menu_reduce_motion_draft = self.game_state.settings.reduce_motion;
+        self.menu_consequence_hints_draft = self.game_state.settings.consequence_hints;
+        self.menu_show_stats_draft = self.game_state.settings.show_stats;
+        self.menu_trust_cues_draft = self.game_state.settings.trust_cues;
+        self.menu_commentary_draft = self.game_state.settings.commentary;
+        self.menu_language_pace_draft = self.game_state.settings.language_pace;
+        self.menu_chat_layout_draft = self.game_state.settings.chat_layout;
+        self.menu_session_markers_draft = self.game_state.settings.session_markers;
+        self.menu_act_cards_draft = self.game_state.settings.act_cards;
+        self.menu_theme_draft = self.game_state.settings.theme;
+        self.menu_log_retention_draft = self.game_state.settings.log_retention;
+        self.menu_save_interval_draft = self.game_state.settings.save_interval_ms;
         self.overlay = Overlay::PauseMenu;
     }
 
     fn validate_pause_menu_settings(&mut self) {
         let previous_lang = self.game_state.language;
+        let previous_markers = self.game_state.settings.session_markers;
 
         self.game_state.language = self.menu_language_draft;
         self.game_state.settings.text_speed = self.menu_text_speed_draft;
         self.game_state.settings.waiting_times_enabled = self.menu_waiting_times_enabled_draft;
         self.game_state.settings.automatic_dialogs_enabled =
             self.menu_automatic_dialogs_enabled_draft;
+        self.game_state.settings.fast_forward = self.menu_fast_forward_draft;
+        self.game_state.settings.idle_timeout_minutes = self.menu_idle_timeout_draft;
+        self.game_state.settings.idle_nudge_seconds = self.menu_idle_nudge_draft;
+        self.game_state.settings.min_read_ms = self.menu_min_read_draft;
+        self.game_state.settings.typing_rhythm = self.menu_typing_rhythm_draft;
+        self.game_state.settings.reduce_motion = self.menu_reduce_motion_draft;
+        self.game_state.settings.consequence_hints = self.menu_consequence_hints_draft;
+        self.game_state.settings.show_stats = self.menu_show_stats_draft;
+        self.game_state.settings.trust_cues = self.menu_trust_cues_draft;
+        self.game_state.settings.commentary = self.menu_commentary_draft;
+        self.game_state.settings.language_pace = self.menu_language_pace_draft;
+        self.game_state.settings.chat_layout = self.menu_chat_layout_draft;
+        self.game_state.settings.session_markers = self.menu_session_markers_draft;
+        self.game_state.settings.act_cards = self.menu_act_cards_draft;
+        self.game_state.settings.theme = self.menu_theme_draft;
+        self.game_state.settings.log_retention = self.menu_log_retention_draft;
+        self.game_state.settings.save_interval_ms = self.menu_save_interval_draft;
 
         crate::time::set_waiting_times_enabled(self.game_state.settings.waiting_times_enabled);
 
@@ -584,13 +2406,19 @@ impl App {
             self.post_message_pause = Some(Instant::now());
         }
 
+        // Separators are laid out as the backlog loads, so lay it out again
+        if previous_markers != self.game_state.settings.session_markers {
+            self.clear_chat();
+            self.load_backlog();
+        }
+
         if previous_lang != self.game_state.language {
             self.chat.push(ChatEntry::System(
                 sys_msg(Msg::LanguageSwitched, self.game_state.language).to_string(),
             ));
         }
 
-        let _ = save_game(&self.game_state);
+        self.save();
     }
 }
 
@@ -598,18 +2426,46 @@ impl App {
 
 /// Handle a key event. Returns true if the event was consumed.
 pub fn handle_key(app: &mut App, code: KeyCode) {
+    // The dev panel only reads state, so its key works everywhere
+    if app.dev_mode && code == KeyCode::F(12) {
+        app.dev_panel = !app.dev_panel;
+        return;
+    }
+
     // Overlay takes priority
     if app.overlay == Overlay::PauseMenu {
         handle_pause_menu_key(app, code);
         return;
     }
+    if matches!(app.overlay, Overlay::Glossary | Overlay::Recap) {
+        handle_glossary_key(app, code);
+        return;
+    }
+    if app.overlay == Overlay::ConfirmChoice {
+        handle_confirm_key(app, code);
+        return;
+    }
+    if app.overlay == Overlay::Explorer {
+        handle_explorer_key(app, code);
+        return;
+    }
+    if app.overlay == Overlay::ActCard {
+        app.close_act_card();
+        return;
+    }
+    if app.overlay == Overlay::SavedRuns {
+        handle_saved_runs_key(app, code);
+        return;
+    }
 
     match app.screen {
         Screen::Game => handle_game_key(app, code),
         Screen::LanguageSelect | Screen::ContinueOrNew => handle_prompt_key(app, code),
         Screen::Intro => handle_intro_key(app, code),
+        Screen::Ending if code == KeyCode::Char('e') => app.open_explorer(),
         Screen::Ending => handle_prompt_key(app, code),
         Screen::Waiting => handle_game_key(app, code),
+        Screen::StoryError | Screen::SlotSelect => handle_prompt_key(app, code),
     }
 }
 
@@ -622,7 +2478,7 @@ fn scroll_chat_down(app: &mut App, lines: u16) {
 }
 
 fn handle_mouse(app: &mut App, mouse: MouseEvent) {
-    if app.overlay == Overlay::PauseMenu {
+    if app.overlay != Overlay::None {
         return;
     }
 
@@ -633,7 +2489,88 @@ fn handle_mouse(app: &mut App, mouse: MouseEvent) {
     }
 }
 
+/// Keep a manual scroll offset within the chat as measured by the last draw,
+/// so a shrink-then-grow resize doesn't leave the view parked past the top.
+fn clamp_chat_scroll(app: &mut App) {
+    let max_scroll = app.chat_metrics.borrow().max_scroll();
+    app.chat_scroll = app
+        .chat_scroll
+        .min(max_scroll.min(u16::MAX as usize) as u16);
+}
+
+/// Scroll the chat so the previous/next session separator is at the top.
+/// Jumping forward past the last session returns to the latest messages.
+fn jump_to_session(app: &mut App, forward: bool) {
+    let metrics = app.chat_metrics.borrow();
+    let top = metrics.top_row(app.chat_scroll);
+    let mut rows = metrics.sessions.iter().map(|(row, _)| *row);
+    let target = if forward {
+        rows.find(|&row| row > top && row <= metrics.max_scroll())
+    } else {
+        rows.rev().find(|&row| row < top)
+    };
+    let scroll = match target {
+        Some(row) => metrics.max_scroll() - row,
+        None if forward => 0,
+        None => return,
+    };
+    drop(metrics);
+    app.chat_scroll = scroll.min(u16::MAX as usize) as u16;
+}
+
+/// Scroll to the nearest bookmark above the top of the view, wrapping
+/// around to the latest one.
+fn jump_to_bookmark(app: &mut App) {
+    let metrics = app.chat_metrics.borrow();
+    let top = metrics.top_row(app.chat_scroll);
+    let target = metrics
+        .bookmarks
+        .iter()
+        .rev()
+        .find(|&&row| row < top)
+        .or(metrics.bookmarks.last())
+        .copied();
+    let Some(row) = target else {
+        drop(metrics);
+        app.chat.push(ChatEntry::System(
+            sys_msg(Msg::BookmarksNone, app.lang()).to_string(),
+        ));
+        app.chat_scroll = 0;
+        return;
+    };
+    let scroll = metrics.max_scroll().saturating_sub(row);
+    drop(metrics);
+    app.chat_scroll = scroll.min(u16::MAX as usize) as u16;
+}
+
 fn handle_game_key(app: &mut App, code: KeyCode) {
+    // Terminals report a held key as repeated presses; each one extends the
+    // fast-forward window. It never picks a choice.
+    if code == KeyCode::Char(' ') && app.game_state.settings.fast_forward && app.choices.is_empty()
+    {
+        app.fast_forward_until = Some(Instant::now() + Duration::from_millis(FAST_FORWARD_LULL_MS));
+    }
+
+    // Session jumps, bookmarks and the quick-save slot work whatever the
+    // conversation is doing
+    match code {
+        KeyCode::Char('[') => return jump_to_session(app, false),
+        KeyCode::Char(']') => return jump_to_session(app, true),
+        KeyCode::Char('b') => return app.toggle_bookmark(),
+        KeyCode::Char('n') => return jump_to_bookmark(app),
+        KeyCode::F(5) => return app.quick_save(),
+        KeyCode::F(9) => return app.quick_load(),
+        _ => app.quickload_pending = false,
+    }
+
+    // Nothing to do until Elara is back; leave and let the save keep the wait
+    if code == KeyCode::Char('q') && crate::time::is_waiting(&app.game_state) {
+        return app.save_and_quit();
+    }
+    if code == KeyCode::Char('s') && crate::time::is_waiting(&app.game_state) {
+        return app.skip_wait();
+    }
+
     // If typewriter is active, any key skips (Esc opens menu)
     if let Some(ref mut tw) = app.typewriter {
         if !tw.is_done() {
@@ -686,16 +2623,8 @@ fn handle_game_key(app: &mut App, code: KeyCode) {
     // If we're showing choices
     if !app.choices.is_empty() {
         match code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                if app.choice_index > 0 {
-                    app.choice_index -= 1;
-                } else {
-                    app.choice_index = app.choices.len() - 1;
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                app.choice_index = (app.choice_index + 1) % app.choices.len();
-            }
+            KeyCode::Up | KeyCode::Char('k') => app.step_choice(false),
+            KeyCode::Down | KeyCode::Char('j') => app.step_choice(true),
             KeyCode::PageUp => {
                 scroll_chat_up(app, 3);
             }
@@ -708,9 +2637,19 @@ fn handle_game_key(app: &mut App, code: KeyCode) {
             KeyCode::End => {
                 app.chat_scroll = 0;
             }
+            // Too soon after the choices appeared: keep the selection, don't commit
+            KeyCode::Enter if !app.choices_ready() => {}
             KeyCode::Enter => {
                 app.select_choice();
             }
+            // 1-9 pick the matching choice at once; out-of-range numbers do nothing
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if index < app.choices.len() && app.choices_ready() {
+                    app.choice_index = index;
+                    app.select_choice();
+                }
+            }
             KeyCode::Esc => {
                 app.open_pause_menu();
             }
@@ -741,32 +2680,127 @@ fn handle_game_key(app: &mut App, code: KeyCode) {
 }
 
 fn handle_pause_menu_key(app: &mut App, code: KeyCode) {
-    let items = 7; // Resume, Language, Text speed, Waiting times, Automatic dialogs, Validate, Save & Quit
-
-    let mut apply_setting = |forward: bool| match app.menu_index {
-        1 => {
-            let new_lang = match app.menu_language_draft {
-                Language::En => Language::Fr,
-                Language::Fr => Language::En,
-            };
-            app.menu_language_draft = new_lang;
+    let items = pause_menu_items(app);
+    let count = items.len();
+    let selected = items[app.menu_index.min(count - 1)];
+
+    let mut apply_setting = |forward: bool| match selected {
+        MenuItem::Language => {
+            let all = Language::ALL;
+            let current = all
+                .iter()
+                .position(|&l| l == app.menu_language_draft)
+                .unwrap_or(0);
+            let step = if forward { 1 } else { all.len() - 1 };
+            app.menu_language_draft = all[(current + step) % all.len()];
         }
-        2 => {
+        MenuItem::TextSpeed => {
             app.menu_text_speed_draft = match (app.menu_text_speed_draft, forward) {
+                (TextSpeed::Slow, true) => TextSpeed::Normal,
                 (TextSpeed::Normal, true) => TextSpeed::Fast,
                 (TextSpeed::Fast, true) => TextSpeed::Instant,
-                (TextSpeed::Instant, true) => TextSpeed::Normal,
-                (TextSpeed::Normal, false) => TextSpeed::Instant,
+                (TextSpeed::Instant, true) => TextSpeed::Slow,
+                (TextSpeed::Slow, false) => TextSpeed::Instant,
+                (TextSpeed::Normal, false) => TextSpeed::Slow,
                 (TextSpeed::Fast, false) => TextSpeed::Normal,
                 (TextSpeed::Instant, false) => TextSpeed::Fast,
             };
         }
-        3 => {
+        MenuItem::LanguagePace => {
+            // Paces are per language; this adjusts the one being picked
+            let lang = app.menu_language_draft;
+            let pace = cycle_preset(
+                &PACE_PRESETS,
+                app.menu_language_pace_draft.get(lang),
+                forward,
+            );
+            app.menu_language_pace_draft.set(lang, pace);
+        }
+        MenuItem::WaitingTimes => {
             app.menu_waiting_times_enabled_draft = !app.menu_waiting_times_enabled_draft;
         }
-        4 => {
+        MenuItem::AutomaticDialogs => {
             app.menu_automatic_dialogs_enabled_draft = !app.menu_automatic_dialogs_enabled_draft;
         }
+        MenuItem::FastForward => {
+            app.menu_fast_forward_draft = !app.menu_fast_forward_draft;
+        }
+        MenuItem::IdleTimeout => {
+            app.menu_idle_timeout_draft =
+                cycle_preset(&IDLE_TIMEOUT_PRESETS, app.menu_idle_timeout_draft, forward);
+        }
+        MenuItem::IdleNudge => {
+            app.menu_idle_nudge_draft =
+                cycle_preset(&IDLE_NUDGE_PRESETS, app.menu_idle_nudge_draft, forward);
+        }
+        MenuItem::MinReadTime => {
+            app.menu_min_read_draft =
+                cycle_preset(&MIN_READ_PRESETS, app.menu_min_read_draft, forward);
+        }
+        MenuItem::LogRetention => {
+            app.menu_log_retention_draft = cycle_preset(
+                &LOG_RETENTION_PRESETS,
+                app.menu_log_retention_draft,
+                forward,
+            );
+        }
+        MenuItem::SaveInterval => {
+            app.menu_save_interval_draft = cycle_preset(
+                &SAVE_INTERVAL_PRESETS,
+                app.menu_save_interval_draft,
+                forward,
+            );
+        }
+        MenuItem::TypingRhythm => {
+            app.menu_typing_rhythm_draft = !app.menu_typing_rhythm_draft;
+        }
+        MenuItem::ReduceMotion => {
+            app.menu_reduce_motion_draft = !app.menu_reduce_motion_draft;
+        }
+        MenuItem::ConsequenceHints => {
+            app.menu_consequence_hints_draft = !app.menu_consequence_hints_draft;
+        }
+        MenuItem::ShowStats => {
+            app.menu_show_stats_draft = !app.menu_show_stats_draft;
+        }
+        MenuItem::TrustCues => {
+            app.menu_trust_cues_draft = !app.menu_trust_cues_draft;
+        }
+        MenuItem::Commentary => {
+            app.menu_commentary_draft = !app.menu_commentary_draft;
+        }
+        MenuItem::ChatLayout => {
+            app.menu_chat_layout_draft = match app.menu_chat_layout_draft {
+                ChatLayout::Inline => ChatLayout::Gutter,
+                ChatLayout::Gutter => ChatLayout::Inline,
+            };
+        }
+        MenuItem::SessionMarkers => {
+            app.menu_session_markers_draft = match (app.menu_session_markers_draft, forward) {
+                (SessionMarkers::All, true) => SessionMarkers::Collapsed,
+                (SessionMarkers::Collapsed, true) => SessionMarkers::Hidden,
+                (SessionMarkers::Hidden, true) => SessionMarkers::All,
+                (SessionMarkers::All, false) => SessionMarkers::Hidden,
+                (SessionMarkers::Collapsed, false) => SessionMarkers::All,
+                (SessionMarkers::Hidden, false) => SessionMarkers::Collapsed,
+            };
+        }
+        MenuItem::ActCards => {
+            app.menu_act_cards_draft = !app.menu_act_cards_draft;
+        }
+        MenuItem::Theme => {
+            let themes = app.unlocked_themes();
+            let current = themes
+                .iter()
+                .position(|t| *t == app.menu_theme_draft)
+                .unwrap_or(0);
+            let next = if forward {
+                (current + 1) % themes.len()
+            } else {
+                (current + themes.len() - 1) % themes.len()
+            };
+            app.menu_theme_draft = themes[next];
+        }
         _ => {}
     };
 
@@ -775,11 +2809,11 @@ fn handle_pause_menu_key(app: &mut App, code: KeyCode) {
             if app.menu_index > 0 {
                 app.menu_index -= 1;
             } else {
-                app.menu_index = items - 1;
+                app.menu_index = count - 1;
             }
         }
         KeyCode::Down | KeyCode::Char('j') => {
-            app.menu_index = (app.menu_index + 1) % items;
+            app.menu_index = (app.menu_index + 1) % count;
         }
         KeyCode::Left | KeyCode::Char('h') => {
             apply_setting(false);
@@ -787,20 +2821,20 @@ fn handle_pause_menu_key(app: &mut App, code: KeyCode) {
         KeyCode::Right | KeyCode::Char('l') => {
             apply_setting(true);
         }
-        KeyCode::Enter => match app.menu_index {
-            0 => app.resume_from_overlay(),
-            5 => {
+        KeyCode::Enter => match selected {
+            MenuItem::Resume => app.resume_from_overlay(),
+            MenuItem::ReplayIntro => app.replay_intro(),
+            MenuItem::Glossary => app.overlay = Overlay::Glossary,
+            MenuItem::Recap => app.overlay = Overlay::Recap,
+            MenuItem::Rewind => app.rewind(),
+            MenuItem::ReturnToCheckpoint => app.restore_checkpoint(),
+            MenuItem::SavedRuns => app.open_saved_runs(),
+            MenuItem::ExportTranscript => app.export_transcript(Path::new(".")),
+            MenuItem::Validate => {
                 app.validate_pause_menu_settings();
                 app.resume_from_overlay();
             }
-            6 => {
-                let _ = save_game(&app.game_state);
-                app.chat.push(ChatEntry::System(
-                    sys_msg(Msg::SavedAndQuit, app.lang()).to_string(),
-                ));
-                app.should_quit = true;
-                app.overlay = Overlay::None;
-            }
+            MenuItem::SaveQuit => app.save_and_quit(),
             _ => apply_setting(true),
         },
         KeyCode::Esc => {
@@ -810,6 +2844,94 @@ fn handle_pause_menu_key(app: &mut App, code: KeyCode) {
     }
 }
 
+fn handle_confirm_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Left
+        | KeyCode::Right
+        | KeyCode::Up
+        | KeyCode::Down
+        | KeyCode::Char('h')
+        | KeyCode::Char('j')
+        | KeyCode::Char('k')
+        | KeyCode::Char('l') => app.confirm_index = 1 - app.confirm_index,
+        KeyCode::Enter if app.confirm_index == 0 => app.confirm_choice(),
+        KeyCode::Char('y') => app.confirm_choice(),
+        KeyCode::Enter | KeyCode::Esc | KeyCode::Char('n') => app.cancel_choice_confirm(),
+        _ => {}
+    }
+}
+
+fn handle_explorer_key(app: &mut App, code: KeyCode) {
+    let count = app.game_state.decision_path.len();
+    match code {
+        KeyCode::Up | KeyCode::Char('k') if count > 0 => {
+            app.explore_index = (app.explore_index + count - 1) % count;
+        }
+        KeyCode::Down | KeyCode::Char('j') if count > 0 => {
+            app.explore_index = (app.explore_index + 1) % count;
+        }
+        KeyCode::Enter if !app.explore_open => app.explore_open = true,
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            if app.explore_open {
+                app.explore_open = false;
+            } else {
+                app.overlay = Overlay::None;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_saved_runs_key(app: &mut App, code: KeyCode) {
+    // Typing a name takes every printable key
+    if let Some(ref mut label) = app.saved_run_label {
+        match code {
+            KeyCode::Char(c) if label.chars().count() < SAVED_RUN_LABEL_MAX => label.push(c),
+            KeyCode::Backspace => {
+                label.pop();
+            }
+            KeyCode::Enter => app.save_named_run(),
+            KeyCode::Esc => app.saved_run_label = None,
+            _ => {}
+        }
+        return;
+    }
+
+    let count = app.saved_runs.runs.len() + 1;
+    app.saved_runs_notice = None;
+    match code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.saved_runs_index = (app.saved_runs_index + count - 1) % count;
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.saved_runs_index = (app.saved_runs_index + 1) % count;
+        }
+        // An unreadable file stays as it is; its error is already shown
+        KeyCode::Enter if app.saved_runs_index == 0 && app.saved_runs_unreadable.is_some() => {}
+        KeyCode::Enter if app.saved_runs_index == 0 => {
+            if app.saved_runs.is_full() {
+                app.saved_runs_notice = Some(sys_msg(Msg::SavedRunsFull, app.lang()).to_string());
+            } else {
+                app.saved_run_label = Some(String::new());
+            }
+        }
+        KeyCode::Enter => app.restore_saved_run(app.saved_runs_index - 1),
+        KeyCode::Char('d') | KeyCode::Delete if app.saved_runs_index > 0 => {
+            app.delete_saved_run(app.saved_runs_index - 1);
+        }
+        // Back to the pause menu, keeping any unsaved setting drafts
+        KeyCode::Esc | KeyCode::Char('q') => app.overlay = Overlay::PauseMenu,
+        _ => {}
+    }
+}
+
+fn handle_glossary_key(app: &mut App, code: KeyCode) {
+    // Back to the pause menu, keeping any unsaved setting drafts
+    if matches!(code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+        app.overlay = Overlay::PauseMenu;
+    }
+}
+
 fn handle_prompt_key(app: &mut App, code: KeyCode) {
     let count = app.prompt_options.len();
     if count == 0 {
@@ -829,21 +2951,10 @@ fn handle_prompt_key(app: &mut App, code: KeyCode) {
         KeyCode::Enter => {
             match app.screen {
                 Screen::LanguageSelect => {
-                    let lang = if app.prompt_index == 0 {
-                        Language::En
-                    } else {
-                        Language::Fr
-                    };
+                    let lang = Language::ALL[app.prompt_index.min(Language::ALL.len() - 1)];
                     app.game_state.language = lang;
                     // Transition to intro
-                    app.screen = Screen::Intro;
-                    let intro_text = sys_msg(Msg::IntroRadioCrackle, lang).to_string();
-                    app.intro_typewriter =
-                        Some(TypewriterState::new(intro_text, TextSpeed::Normal));
-                    // No typing indicator for intro
-                    if let Some(ref mut tw) = app.intro_typewriter {
-                        tw.show_typing_indicator = false;
-                    }
+                    app.start_intro();
                 }
                 Screen::ContinueOrNew => {
                     if app.prompt_index == 0 {
@@ -856,13 +2967,14 @@ fn handle_prompt_key(app: &mut App, code: KeyCode) {
                         app.prompt_options = vec![
                             sys_msg(Msg::LanguageOption1, Language::En).to_string(),
                             sys_msg(Msg::LanguageOption2, Language::En).to_string(),
+                            sys_msg(Msg::LanguageOption3, Language::En).to_string(),
                         ];
                         app.prompt_index = 0;
-                        app.game_state = GameState::from_story(Language::En, &app.story_data);
+                        app.game_state = app.fresh_state(Language::En);
                         crate::time::set_waiting_times_enabled(
                             app.game_state.settings.waiting_times_enabled,
                         );
-                        app.chat.clear();
+                        app.clear_chat();
                         app.wait_for_space = false;
                         app.typewriter = None;
                         app.post_message_pause = None;
@@ -871,86 +2983,83 @@ fn handle_prompt_key(app: &mut App, code: KeyCode) {
                         app.choice_index = 0;
                     }
                 }
-                Screen::Ending => {
-                    if app.prompt_index == 0 {
-                        // Play again
-                        let _ = crate::game::delete_save();
-                        app.game_state = GameState::from_story(Language::En, &app.story_data);
-                        crate::time::set_waiting_times_enabled(
-                            app.game_state.settings.waiting_times_enabled,
-                        );
-                        app.chat.clear();
-                        app.ending_reached = None;
-                        app.screen = Screen::LanguageSelect;
-                        app.prompt_options = vec![
-                            sys_msg(Msg::LanguageOption1, Language::En).to_string(),
-                            sys_msg(Msg::LanguageOption2, Language::En).to_string(),
-                        ];
-                        app.prompt_index = 0;
-                        app.wait_for_space = false;
-                        app.typewriter = None;
-                        app.post_message_pause = None;
-                        app.message_queue.clear();
-                        app.choices.clear();
-                        app.choice_index = 0;
-                    } else {
-                        // Quit
-                        let _ = crate::game::delete_save();
+                Screen::Ending => match ending_items(app).get(app.prompt_index) {
+                    Some(EndingItem::PlayAgain) => app.start_over(),
+                    Some(EndingItem::NewGamePlus) => app.start_new_game_plus(),
+                    Some(EndingItem::Quit) => {
+                        if app.persist {
+                            let _ = crate::game::delete_save_slot(app.save_slot);
+                        }
                         app.should_quit = true;
                     }
-                }
+                    Some(EndingItem::Checkpoint) => app.restore_checkpoint(),
+                    None => {}
+                },
+                Screen::StoryError => app.resolve_story_error(app.prompt_index),
+                Screen::SlotSelect => app.choose_save_slot(app.prompt_index),
                 Screen::Waiting => {
                     // Keep the player in-game while waiting.
                 }
                 _ => {}
             }
         }
-        KeyCode::Esc => {
-            // Esc opens pause menu on game-like screens
-            if app.screen == Screen::Waiting {
-                app.open_pause_menu();
-            }
+        // Esc opens pause menu on game-like screens
+        KeyCode::Esc if app.screen == Screen::Waiting => {
+            app.open_pause_menu();
         }
         _ => {}
     }
 }
 
-fn handle_intro_key(app: &mut App, code: KeyCode) {
+fn handle_intro_key(app: &mut App, _code: KeyCode) {
     if let Some(ref mut tw) = app.intro_typewriter {
         if !tw.is_done() {
             tw.skip();
             return;
         }
     }
-    // Intro is done — any key proceeds to game
-    match code {
-        _ => {
-            app.screen = Screen::Game;
-            app.advance_story = true;
-            app.intro_typewriter = None;
-
-            // Log session start
-            let now = chrono::Utc::now();
-            let label = now.format("%Y-%m-%d %H:%M").to_string();
-            app.game_state.message_log.push(LogEntry {
-                sender: Sender::System,
-                text: format!("SESSION:{}", label),
-                timestamp: now,
-            });
-            app.chat.push(ChatEntry::Separator(label));
-        }
-    }
+    // Intro is done — any key proceeds
+    app.finish_intro();
 }
 
 // ── Tick (animation update) ──────────────────────────────────
 
 /// Called on each frame to advance animations.
 pub fn tick(app: &mut App) {
+    // Count active play: in the chat with nothing open and Elara around.
+    // Gaps longer than a frame or two (a suspended terminal) are not counted.
+    let elapsed = app.play_clock.elapsed().min(Duration::from_secs(1));
+    app.play_clock = Instant::now();
+    if app.screen == Screen::Game
+        && app.overlay == Overlay::None
+        && !crate::time::is_waiting(&app.game_state)
+    {
+        app.game_state.active_play_ms += elapsed.as_millis() as u64;
+    }
+
+    // Write a throttled save once its interval has passed
+    let interval = Duration::from_millis(u64::from(app.game_state.settings.save_interval_ms));
+    if app.save_scheduler.due(Instant::now(), interval) {
+        app.save_now();
+    }
+
+    // Rows held still by the last draw become part of the scroll offset
+    let held_rows = std::mem::take(&mut app.chat_metrics.borrow_mut().held_rows);
+    if app.chat_scroll == 0 {
+        app.unseen_below = false;
+    } else if held_rows > 0 {
+        app.chat_scroll = (app.chat_scroll as usize + held_rows).min(u16::MAX as usize) as u16;
+        app.unseen_below = true;
+    }
+
     if let Some(_until) = app.game_state.waiting_until {
         if !crate::time::is_waiting(&app.game_state) {
             app.game_state.waiting_until = None;
             app.wait_message = None;
-            let _ = save_game(&app.game_state);
+            app.bell_pending = true;
+            // The wait itself is not connected time
+            app.session_clock = Instant::now();
+            app.save();
             if app.screen == Screen::Waiting {
                 app.screen = Screen::Game;
             }
@@ -958,13 +3067,53 @@ pub fn tick(app: &mut App) {
         }
     }
 
+    // Auto-pause when the player has walked away mid-conversation
+    let idle_minutes = app.game_state.settings.idle_timeout_minutes;
+    if idle_minutes > 0
+        && app.screen == Screen::Game
+        && app.overlay == Overlay::None
+        && app.last_input.elapsed() >= Duration::from_secs(u64::from(idle_minutes) * 60)
+    {
+        app.open_pause_menu();
+    }
+
+    if app.overlay == Overlay::ActCard
+        && app
+            .act_card
+            .is_none_or(|(_, shown)| shown.elapsed() >= Duration::from_millis(ACT_CARD_MS))
+    {
+        app.close_act_card();
+    }
+
     // Don't advance anything while an overlay is open
     if app.overlay != Overlay::None {
+        app.suspend_timers();
+        return;
+    }
+
+    app.maybe_nudge();
+
+    // The intro screen only animates its own text; a replayed intro keeps
+    // the conversation frozen underneath.
+    if app.screen == Screen::Intro {
+        if let Some(ref mut tw) = app.intro_typewriter {
+            tw.tick();
+        }
         return;
     }
+    // An overlay may have been closed without resuming the timers
+    app.resume_timers();
+
+    let fast_forward = app.fast_forwarding();
+    if !fast_forward {
+        app.fast_forward_until = None;
+    }
 
     // Advance typewriter
     if let Some(ref mut tw) = app.typewriter {
+        if fast_forward {
+            tw.skip();
+        }
         tw.tick();
         if tw.is_done() {
             app.on_message_complete();
@@ -973,17 +3122,17 @@ pub fn tick(app: &mut App) {
 
     // Post-message pause
     if let Some(start) = app.post_message_pause {
-        if start.elapsed() >= Duration::from_millis(300) {
+        let pause_ms = if fast_forward {
+            app.post_message_pause_ms.min(FAST_FORWARD_PAUSE_MS)
+        } else {
+            app.post_message_pause_ms
+        };
+        if start.elapsed() >= Duration::from_millis(pause_ms) {
             app.post_message_pause = None;
             app.start_next_message();
         }
     }
 
-    // Advance intro typewriter
-    if let Some(ref mut tw) = app.intro_typewriter {
-        tw.tick();
-    }
-
     // Advance story if needed
     if app.advance_story
         && app.typewriter.is_none()
@@ -999,6 +3148,12 @@ pub fn tick(app: &mut App) {
 
 /// Main render function.
 pub fn draw(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        draw_too_small(frame, app);
+        return;
+    }
+
     match app.screen {
         Screen::LanguageSelect => {
             draw_prompt_screen(frame, app, sys_msg(Msg::LanguagePrompt, Language::En))
@@ -1010,12 +3165,127 @@ pub fn draw(frame: &mut Frame, app: &App) {
         Screen::Game => draw_game(frame, app),
         Screen::Waiting => draw_waiting(frame, app),
         Screen::Ending => draw_ending(frame, app),
+        Screen::StoryError => draw_prompt_screen(
+            frame,
+            app,
+            &format!(
+                "{} '{}'",
+                sys_msg(Msg::StoryNodeMissing, app.lang()),
+                app.game_state.current_node
+            ),
+        ),
+        Screen::SlotSelect => {
+            draw_prompt_screen(frame, app, sys_msg(Msg::SlotSelectPrompt, app.lang()))
+        }
     }
 
     // Draw overlay on top
-    if app.overlay == Overlay::PauseMenu {
-        draw_pause_menu(frame, app);
+    match app.overlay {
+        Overlay::PauseMenu => draw_pause_menu(frame, app),
+        Overlay::Glossary => draw_glossary(frame, app),
+        Overlay::Recap => draw_recap(frame, app),
+        Overlay::ConfirmChoice => draw_confirm_choice(frame, app),
+        Overlay::Explorer => draw_explorer(frame, app),
+        Overlay::ActCard => draw_act_card(frame, app),
+        Overlay::SavedRuns => draw_saved_runs(frame, app),
+        Overlay::None => {}
+    }
+
+    if app.dev_panel {
+        draw_dev_panel(frame, app);
+    }
+}
+
+/// Live timer readout for `--dev`, one line per timer.
+fn dev_panel_lines(app: &App) -> Vec<String> {
+    let mut lines = vec![format!("node        {}", app.game_state.current_node)];
+    match app.typewriter {
+        Some(ref tw) => {
+            lines.push(format!(
+                "typewriter  {}/{} bytes @ {}ms",
+                tw.revealed,
+                tw.full_text.len(),
+                tw.char_tick_ms
+            ));
+            let indicator = if tw.show_typing_indicator {
+                format!(
+                    "{}ms / {}ms",
+                    tw.indicator_start.elapsed().as_millis(),
+                    TYPING_INDICATOR_MS
+                )
+            } else {
+                "off".to_string()
+            };
+            lines.push(format!("indicator   {}", indicator));
+        }
+        None => {
+            lines.push("typewriter  idle".to_string());
+            lines.push("indicator   -".to_string());
+        }
+    }
+    let pause = match app.post_message_pause {
+        Some(start) => {
+            let total = Duration::from_millis(app.post_message_pause_ms);
+            format!(
+                "{}ms left of {}ms",
+                total.saturating_sub(start.elapsed()).as_millis(),
+                app.post_message_pause_ms
+            )
+        }
+        None => "-".to_string(),
+    };
+    lines.push(format!("pause       {}", pause));
+    let waiting = match app.game_state.waiting_until {
+        Some(until) => {
+            let left = (until - chrono::Utc::now()).num_seconds().max(0);
+            format!("{} ({}s left)", until.format("%H:%M:%S"), left)
+        }
+        None => "-".to_string(),
+    };
+    lines.push(format!("waiting     {}", waiting));
+    lines
+}
+
+/// Small read-only box in the top-right corner; drawn over everything.
+fn draw_dev_panel(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let palette = palette(app.game_state.settings.theme);
+    let lines = dev_panel_lines(app);
+    let width = lines
+        .iter()
+        .map(|l| l.chars().count() as u16 + 4)
+        .max()
+        .unwrap_or(0)
+        .min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let panel = Rect::new(area.x + area.width - width, area.y, width, height);
+    frame.render_widget(Clear, panel);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.system))
+        .title(" dev ")
+        .padding(Padding::horizontal(1));
+    let text: Vec<Line> = lines
+        .into_iter()
+        .map(|l| Line::from(Span::styled(l, Style::default().fg(palette.text))))
+        .collect();
+    frame.render_widget(Paragraph::new(text).block(block), panel);
+}
+
+/// Minimal notice for terminals too small to lay out the UI.
+fn draw_too_small(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let palette = palette(app.game_state.settings.theme);
+    if area.width == 0 || area.height == 0 {
+        return;
     }
+    let notice = Paragraph::new(Line::from(Span::styled(
+        sys_msg(Msg::TerminalTooSmall, app.lang()),
+        Style::default().fg(palette.system),
+    )))
+    .wrap(Wrap { trim: true });
+    frame.render_widget(notice, area);
 }
 
 fn draw_game(frame: &mut Frame, app: &App) {
@@ -1027,6 +3297,7 @@ fn draw_game(frame: &mut Frame, app: &App) {
 
     // Build chat lines
     let mut lines: Vec<Line> = Vec::new();
+    let palette = palette(app.game_state.settings.theme);
 
     // Banner
     lines.push(Line::from("").centered());
@@ -1041,10 +3312,38 @@ fn draw_game(frame: &mut Frame, app: &App) {
             lines.push(
                 Line::from(Span::styled(
                     format!("{} (~{})", base_msg, remaining),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(palette.system),
+                ))
+                .centered(),
+            );
+            lines.push(
+                Line::from(Span::styled(
+                    crate::time::countdown_clock(until),
+                    Style::default().fg(palette.choice),
                 ))
                 .centered(),
             );
+            let mut hints = vec![sys_msg(Msg::WaitQuitHint, app.lang()).to_string()];
+            let skips = app.game_state.wait_skips_remaining;
+            if skips > 0 {
+                hints.push(format!(
+                    "{} ({}/{})",
+                    sys_msg(Msg::WaitSkipHint, app.lang()),
+                    skips,
+                    crate::game::WAIT_SKIPS_PER_RUN
+                ));
+            }
+            for hint in hints {
+                lines.push(
+                    Line::from(Span::styled(
+                        hint,
+                        Style::default()
+                            .fg(palette.system)
+                            .add_modifier(Modifier::DIM),
+                    ))
+                    .centered(),
+                );
+            }
             lines.push(Line::from("").centered());
         }
     }
@@ -1052,7 +3351,7 @@ fn draw_game(frame: &mut Frame, app: &App) {
         Line::from(Span::styled(
             "E S H A R A",
             Style::default()
-                .fg(Color::White)
+                .fg(palette.title)
                 .add_modifier(Modifier::BOLD),
         ))
         .centered(),
@@ -1061,33 +3360,48 @@ fn draw_game(frame: &mut Frame, app: &App) {
     lines.push(
         Line::from(Span::styled(
             "─".repeat(40),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
     lines.push(Line::from("").centered());
 
+    let lang = app.lang();
+    let gutter = app.game_state.settings.chat_layout == ChatLayout::Gutter;
+    // One spare column on the right keeps the typing caret from wrapping
+    let body_width = chat_area.width.saturating_sub(GUTTER_WIDTH + 1).max(1);
+    let elara_style = Style::default().fg(palette.elara);
+    let player_style = Style::default().fg(palette.player);
+
     // Chat entries
-    for entry in &app.chat {
+    let mut session_lines: Vec<(usize, String)> = Vec::new();
+    let mut bookmark_lines: Vec<usize> = Vec::new();
+    for (entry, bookmarked) in app.chat.iter().zip(app.bookmarked_chat_entries()) {
+        let first_line = lines.len();
         match entry {
+            ChatEntry::Elara(text) if gutter => {
+                lines.extend(gutter_lines("E", text, elara_style, body_width, None));
+            }
+            ChatEntry::Player(text) if gutter => {
+                lines.extend(gutter_lines(
+                    sys_msg(Msg::GutterPlayer, lang),
+                    text,
+                    player_style,
+                    body_width,
+                    None,
+                ));
+            }
             ChatEntry::Elara(text) => {
                 lines.push(Line::from(vec![
-                    Span::styled(
-                        "  Elara: ",
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(text.as_str(), Style::default().fg(Color::Cyan)),
+                    Span::styled("  Elara: ", elara_style.add_modifier(Modifier::BOLD)),
+                    Span::styled(text.as_str(), elara_style),
                 ]));
             }
             ChatEntry::Player(text) => {
                 lines.push(
                     Line::from(vec![Span::styled(
                         format!("  {} >", text),
-                        Style::default()
-                            .fg(Color::Green)
-                            .add_modifier(Modifier::BOLD),
+                        player_style.add_modifier(Modifier::BOLD),
                     )])
                     .right_aligned(),
                 );
@@ -1096,50 +3410,71 @@ fn draw_game(frame: &mut Frame, app: &App) {
                 lines.push(
                     Line::from(Span::styled(
                         text.as_str(),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(palette.system),
                     ))
                     .centered(),
                 );
             }
+            ChatEntry::Commentary(_) if !app.game_state.settings.commentary => {}
+            ChatEntry::Commentary(note) => {
+                lines.push(Line::from(Span::styled(
+                    format!("  \u{270e} {}", note),
+                    Style::default()
+                        .fg(palette.system)
+                        .add_modifier(Modifier::ITALIC | Modifier::DIM),
+                )));
+            }
             ChatEntry::Separator(label) => {
                 lines.push(Line::from("").centered());
+                session_lines.push((lines.len(), label.clone()));
                 lines.push(
                     Line::from(Span::styled(
                         format!("── {} ──", label),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(palette.system),
                     ))
                     .centered(),
                 );
                 lines.push(Line::from("").centered());
             }
         }
+        if bookmarked {
+            mark_bookmarked(&mut lines[first_line]);
+            bookmark_lines.push(first_line);
+        }
         lines.push(Line::from("")); // spacing between messages
     }
 
     // Current typewriter message
     if let Some(ref tw) = app.typewriter {
-        let lang = app.lang();
+        let caret = caret_visible(tw, app.game_state.settings.reduce_motion);
         if tw.is_indicating() {
             let elapsed = tw.indicator_start.elapsed().as_millis() as usize;
             let dots = ".".repeat((elapsed / ANIM_FRAME_MS as usize) % 3 + 1);
             lines.push(Line::from(Span::styled(
                 format!("  {}{}", sys_msg(Msg::ElaraTyping, lang), dots),
                 Style::default()
-                    .fg(Color::DarkGray)
+                    .fg(palette.system)
                     .add_modifier(Modifier::ITALIC),
             )));
         } else {
-            let visible = tw.visible_text();
-            if !visible.is_empty() {
-                lines.push(Line::from(vec![
-                    Span::styled(
-                        "  Elara: ",
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(visible, Style::default().fg(Color::Cyan)),
-                ]));
+            let visible = tw.display_text();
+            if gutter && !visible.is_empty() {
+                lines.extend(gutter_lines(
+                    "E",
+                    &visible,
+                    elara_style,
+                    body_width,
+                    caret.then_some(TYPEWRITER_CARET),
+                ));
+            } else if !visible.is_empty() {
+                let mut spans = vec![
+                    Span::styled("  Elara: ", elara_style.add_modifier(Modifier::BOLD)),
+                    Span::styled(visible, elara_style),
+                ];
+                if caret {
+                    spans.push(Span::styled(TYPEWRITER_CARET, elara_style));
+                }
+                lines.push(Line::from(spans));
             }
         }
         lines.push(Line::from(""));
@@ -1149,7 +3484,7 @@ fn draw_game(frame: &mut Frame, app: &App) {
         lines.push(Line::from(Span::styled(
             "  [press space to continue]",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(palette.system)
                 .add_modifier(Modifier::ITALIC),
         )));
         lines.push(Line::from(""));
@@ -1158,36 +3493,138 @@ fn draw_game(frame: &mut Frame, app: &App) {
     // Choices
     if !app.choices.is_empty() && app.typewriter.is_none() && app.post_message_pause.is_none() {
         lines.push(Line::from(""));
+        let ready = app.choices_ready();
+        let explored_note = format!("  {}", sys_msg(Msg::ChoiceExplored, lang));
         for (i, choice) in app.choices.iter().enumerate() {
-            let (prefix, style) = if i == app.choice_index {
+            // Only trust the choice data if it belongs to the labels shown
+            let data = if app.choice_data.len() == app.choices.len() {
+                app.choice_data.get(i).and_then(Option::as_ref)
+            } else {
+                None
+            };
+            let locked = data.is_some_and(|(_, c)| c.is_locked(&app.game_state));
+            let (prefix, style) = if locked {
+                let prefix = if i == app.choice_index {
+                    "  > "
+                } else {
+                    "    "
+                };
+                (
+                    prefix,
+                    Style::default()
+                        .fg(palette.system)
+                        .add_modifier(Modifier::DIM),
+                )
+            } else if !ready {
+                // Dimmed until the minimum read time has passed
+                let prefix = if i == app.choice_index {
+                    "  > "
+                } else {
+                    "    "
+                };
+                (prefix, Style::default().fg(palette.system))
+            } else if i == app.choice_index {
                 (
                     "  > ",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(palette.choice)
                         .add_modifier(Modifier::BOLD),
                 )
             } else {
                 (
                     "    ",
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(palette.choice)
                         .add_modifier(Modifier::DIM),
                 )
             };
-            lines.push(Line::from(Span::styled(
-                format!("{}{}", prefix, choice),
+            // The first nine are numbered for their shortcut keys
+            let number = if i < 9 {
+                format!("{}. ", i + 1)
+            } else {
+                "   ".to_string()
+            };
+            let mut spans = vec![Span::styled(
+                format!("{}{}{}", prefix, number, choice),
                 style,
-            )));
+            )];
+            if app.game_state.settings.consequence_hints {
+                if let Some(hint) =
+                    data.and_then(|(_, c)| consequence_hint(c, &app.story_data, lang))
+                {
+                    spans.push(Span::styled(
+                        format!("  [{}]", hint),
+                        Style::default().fg(palette.hint),
+                    ));
+                }
+            }
+            let explored = app.game_state.new_game_plus
+                && data.is_some_and(|(index, _)| {
+                    app.ledger.has_taken(&app.game_state.current_node, *index)
+                });
+            if explored {
+                spans.push(Span::styled(
+                    explored_note.clone(),
+                    Style::default()
+                        .fg(palette.system)
+                        .add_modifier(Modifier::DIM),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
     }
 
     let text = Text::from(lines);
-    let chat_height = chat_area.height as usize;
+    let chat_height = (chat_area.height as usize).max(1);
     let total_lines = wrapped_line_count(&text, chat_area.width);
     let max_scroll = total_lines.saturating_sub(chat_height) as u16;
-    let effective_scroll = app.chat_scroll.min(max_scroll);
+    // Hold a scrolled-up view still while new rows arrive below it
+    let held_rows = {
+        let previous = app.chat_metrics.borrow();
+        if app.chat_scroll > 0 && previous.width == chat_area.width {
+            previous.held_rows + total_lines.saturating_sub(previous.total_rows)
+        } else {
+            0
+        }
+    };
+    let effective_scroll = (app.chat_scroll as usize + held_rows).min(max_scroll as usize) as u16;
     let scroll = max_scroll.saturating_sub(effective_scroll);
 
+    // Record where each session and bookmark starts for `[` / `]` and `n`
+    let mut sessions = Vec::with_capacity(session_lines.len());
+    let mut bookmarks = Vec::with_capacity(bookmark_lines.len());
+    let mut row = 0;
+    let mut pending = session_lines.into_iter().peekable();
+    let mut pending_bookmarks = bookmark_lines.into_iter().peekable();
+    for (i, line) in text.lines.iter().enumerate() {
+        if let Some((_, label)) = pending.next_if(|(index, _)| *index == i) {
+            sessions.push((row, label));
+        }
+        if pending_bookmarks.next_if_eq(&i).is_some() {
+            bookmarks.push(row);
+        }
+        row += line_rows(line, chat_area.width);
+    }
+    let metrics = ChatMetrics {
+        total_rows: total_lines,
+        view_height: chat_height,
+        sessions,
+        bookmarks,
+        width: chat_area.width,
+        held_rows,
+    };
+    let mut session_hint = match metrics.session_at(metrics.top_row(effective_scroll)) {
+        Some(label) if app.chat_scroll > 0 => format!("  [ ] {}", label),
+        _ if !metrics.sessions.is_empty() => {
+            format!("  [ ] {}", sys_msg(Msg::SessionsHint, lang))
+        }
+        _ => String::new(),
+    };
+    if !metrics.bookmarks.is_empty() {
+        session_hint.push_str(&format!("  [n] {}", sys_msg(Msg::BookmarksHint, lang)));
+    }
+    *app.chat_metrics.borrow_mut() = metrics;
+
     let paragraph = Paragraph::new(text)
         .wrap(Wrap { trim: false })
         .scroll((scroll, 0));
@@ -1212,26 +3649,281 @@ fn draw_game(frame: &mut Frame, app: &App) {
     } else {
         String::new()
     };
+    let act_hint = match (app.current_act, app.story_data.max_act()) {
+        (Some(act), Some(max)) if wait_hint.is_empty() => format!(
+            "{} {} / {}  ",
+            sys_msg(Msg::ActProgress, app.lang()),
+            act,
+            max
+        ),
+        _ => String::new(),
+    };
+    let signal_hint = app
+        .current_act
+        .and_then(|act| app.story_data.meta.signal.get(&act))
+        .filter(|_| wait_hint.is_empty())
+        .map(|text| format!("{}  ", text.get(app.lang())))
+        .unwrap_or_default();
+    // Row offset of the top of the view: above it is hidden history, and a
+    // non-zero effective scroll means newer lines are hidden below
+    let more_hint = if app.chat_scroll > 0 && (app.unseen_below || held_rows > 0) {
+        format!("{}  ", sys_msg(Msg::NewMessagesBelow, app.lang()))
+    } else {
+        more_indicator(scroll > 0, effective_scroll > 0, app.lang())
+    };
     let hint = format!(
-        "[Esc] {}  {}{}",
+        "{}{}{}[Esc] {}  {}{}{}",
+        more_hint,
+        act_hint,
+        signal_hint,
         sys_msg(Msg::PauseMenuHint, app.lang()).trim_start_matches("[Esc] "),
         scroll_hint,
+        session_hint,
         wait_hint
     );
-    let status = Line::from(Span::styled(
-        format!(" {}", hint),
-        Style::default().fg(Color::DarkGray),
-    ));
-    frame.render_widget(Paragraph::new(status), status_area);
+    let mut spans = vec![Span::raw(" ")];
+    if app.debug_delays {
+        spans.push(Span::styled(
+            "[DEBUG] ",
+            Style::default().fg(palette.choice),
+        ));
+    }
+    if app.game_state.settings.show_stats {
+        spans.extend(stat_gauges(app));
+    }
+    spans.push(Span::styled(hint, Style::default().fg(palette.system)));
+    frame.render_widget(Paragraph::new(Line::from(spans)), status_area);
+}
+
+/// Cells in a stat gauge.
+const GAUGE_WIDTH: usize = 5;
+
+/// Filled cells for `value` within the stat's bounds, clamped to the gauge.
+fn gauge_cells(value: i32, min: i32, max: i32) -> usize {
+    if max <= min {
+        return if value >= max { GAUGE_WIDTH } else { 0 };
+    }
+    let value = value.clamp(min, max) - min;
+    let range = max - min;
+    // Rounded, so a sliver of a stat still shows one cell
+    ((value as usize * GAUGE_WIDTH * 2 + range as usize) / (range as usize * 2)).min(GAUGE_WIDTH)
+}
+
+/// "trust ▇▇▇░░  health ▇▇▇▇░  supplies ▇▇░░░" for the status bar, with
+/// health in red once it is down to one cell.
+fn stat_gauges(app: &App) -> Vec<Span<'static>> {
+    let stats = &app.game_state.stats;
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    [
+        ("trust", Msg::HintTrust, stats.trust),
+        ("health", Msg::HintHealth, stats.health),
+        ("supplies", Msg::HintSupplies, stats.supplies),
+    ]
+    .into_iter()
+    .map(|(key, msg, value)| {
+        let (min, max) = app
+            .story_data
+            .stats
+            .get(key)
+            .map_or((0, 10), |def| (def.min, def.max));
+        let filled = gauge_cells(value, min, max);
+        let low = key == "health" && filled <= 1;
+        let color = if low { palette.bad } else { palette.text };
+        Span::styled(
+            format!(
+                "{} {}{}  ",
+                sys_msg(msg, lang),
+                "\u{2587}".repeat(filled),
+                "\u{2591}".repeat(GAUGE_WIDTH - filled)
+            ),
+            Style::default().fg(color),
+        )
+    })
+    .collect()
+}
+
+/// "▲ more" / "▼ more" when chat lines are hidden above or below the view.
+fn more_indicator(above: bool, below: bool, lang: Language) -> String {
+    let arrows = match (above, below) {
+        (false, false) => return String::new(),
+        (true, false) => "\u{25b2}",
+        (false, true) => "\u{25bc}",
+        (true, true) => "\u{25b2}\u{25bc}",
+    };
+    format!("{} {}  ", arrows, sys_msg(Msg::MoreContent, lang))
+}
+
+/// Display name of a chat theme.
+fn theme_name(theme: Theme, lang: Language) -> &'static str {
+    match theme {
+        Theme::Classic => sys_msg(Msg::SettingThemeClassic, lang),
+        Theme::HighContrast => sys_msg(Msg::SettingThemeHighContrast, lang),
+        Theme::Monochrome => sys_msg(Msg::SettingThemeMonochrome, lang),
+        Theme::Signal => sys_msg(Msg::SettingThemeSignal, lang),
+    }
+}
+
+/// Colours of every screen for a theme.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Palette {
+    /// The E S H A R A banner and other headings
+    title: Color,
+    elara: Color,
+    player: Color,
+    /// System lines, hints and anything dimmed
+    system: Color,
+    /// Choices, menu selections, overlay borders and the wait countdown
+    choice: Color,
+    /// Consequence hints next to choices
+    hint: Color,
+    /// Secondary text: descriptions, unselected entries, codes
+    text: Color,
+    /// The highlighted pause menu entry
+    selected: Color,
+    /// The decision explorer frame
+    explorer: Color,
+    /// Ending titles by type; `bad` also marks low health
+    good: Color,
+    bad: Color,
+    bittersweet: Color,
+}
+
+fn palette(theme: Theme) -> Palette {
+    match theme {
+        Theme::Classic => Palette {
+            title: Color::White,
+            elara: Color::Cyan,
+            player: Color::Green,
+            system: Color::DarkGray,
+            choice: Color::Yellow,
+            hint: Color::LightBlue,
+            text: Color::Gray,
+            selected: Color::Cyan,
+            explorer: Color::Magenta,
+            good: Color::Green,
+            bad: Color::Red,
+            bittersweet: Color::Yellow,
+        },
+        Theme::HighContrast => Palette {
+            title: Color::White,
+            elara: Color::LightCyan,
+            player: Color::LightGreen,
+            system: Color::White,
+            choice: Color::LightYellow,
+            hint: Color::LightMagenta,
+            text: Color::White,
+            selected: Color::LightCyan,
+            explorer: Color::LightMagenta,
+            good: Color::LightGreen,
+            bad: Color::LightRed,
+            bittersweet: Color::LightYellow,
+        },
+        // Bold and dim still tell the speakers and choices apart
+        Theme::Monochrome => Palette {
+            title: Color::Reset,
+            elara: Color::Reset,
+            player: Color::Reset,
+            system: Color::Reset,
+            choice: Color::Reset,
+            hint: Color::Reset,
+            text: Color::Reset,
+            selected: Color::Reset,
+            explorer: Color::Reset,
+            good: Color::Reset,
+            bad: Color::Reset,
+            bittersweet: Color::Reset,
+        },
+        Theme::Signal => Palette {
+            title: Color::White,
+            elara: Color::LightMagenta,
+            player: Color::LightYellow,
+            system: Color::DarkGray,
+            choice: Color::Yellow,
+            hint: Color::LightCyan,
+            text: Color::Gray,
+            selected: Color::LightMagenta,
+            explorer: Color::Magenta,
+            good: Color::Green,
+            bad: Color::Red,
+            bittersweet: Color::Yellow,
+        },
+    }
+}
+
+/// Short description of what a choice changes, e.g. "trust +1 · sets: Found
+/// shielding". Trust-scaled changes are marked with "~". None if it changes nothing.
+fn consequence_hint(choice: &Choice, story: &StoryData, lang: Language) -> Option<String> {
+    let effects = choice.on_choose.as_ref()?;
+    let approx = if effects.scale_with_trust { "~" } else { "" };
+    let mut parts: Vec<String> = [
+        (Msg::HintTrust, effects.trust_change),
+        (Msg::HintHealth, effects.health_change),
+        (Msg::HintSupplies, effects.supplies_change),
+    ]
+    .into_iter()
+    .filter_map(|(msg, delta)| {
+        let delta = delta.filter(|d| *d != 0)?;
+        Some(format!("{} {}{:+}", sys_msg(msg, lang), approx, delta))
+    })
+    .collect();
+    if !effects.flags_set.is_empty() {
+        let flags: Vec<String> = effects
+            .flags_set
+            .iter()
+            .map(|f| story.flag_label(f, lang))
+            .collect();
+        parts.push(format!(
+            "{} {}",
+            sys_msg(Msg::HintSets, lang),
+            flags.join(", ")
+        ));
+    }
+    (!parts.is_empty()).then(|| parts.join(" \u{b7} "))
+}
+
+/// Step to the next (or previous) value of a numeric preset list.
+fn cycle_preset(presets: &[u32], current: u32, forward: bool) -> u32 {
+    let count = presets.len();
+    let index = presets.iter().position(|&p| p == current).unwrap_or(0);
+    let next = if forward {
+        (index + 1) % count
+    } else {
+        (index + count - 1) % count
+    };
+    presets[next]
+}
+
+/// Render a numeric preset list with the selected value in brackets;
+/// 0 is shown as `zero_label`.
+fn preset_value(presets: &[u32], selected: u32, zero_label: &str) -> String {
+    presets
+        .iter()
+        .map(|&value| {
+            let label = if value == 0 {
+                zero_label.to_string()
+            } else {
+                value.to_string()
+            };
+            if value == selected {
+                format!("[{}]", label)
+            } else {
+                label
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
 }
 
 fn draw_pause_menu(frame: &mut Frame, app: &App) {
     let area = frame.area();
     let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
 
     // Centered popup
+    let menu_items = pause_menu_items(app);
     let popup_width = 58u16.min(area.width.saturating_sub(4));
-    let popup_height = 13u16.min(area.height.saturating_sub(4));
+    let popup_height = (menu_items.len() as u16 + 6).min(area.height.saturating_sub(4));
     let popup_area = centered_rect(popup_width, popup_height, area);
 
     // Clear the area behind the popup
@@ -1239,7 +3931,7 @@ fn draw_pause_menu(frame: &mut Frame, app: &App) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow))
+        .border_style(Style::default().fg(palette.choice))
         .title(format!(
             " {} ",
             sys_msg(Msg::PauseMenuTitle, lang).trim_matches('-').trim()
@@ -1250,77 +3942,218 @@ fn draw_pause_menu(frame: &mut Frame, app: &App) {
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
-    let language_value = match app.menu_language_draft {
-        Language::Fr => format!(
-            "[{}] | {}",
-            sys_msg(Msg::SettingLangFr, lang),
-            sys_msg(Msg::SettingLangEn, lang)
-        ),
-        Language::En => format!(
-            "{} | [{}]",
-            sys_msg(Msg::SettingLangFr, lang),
-            sys_msg(Msg::SettingLangEn, lang)
-        ),
-    };
-    let text_speed_value = match app.menu_text_speed_draft {
-        TextSpeed::Normal => format!(
-            "[{}] | {} | {}",
-            sys_msg(Msg::SettingSpeedNormal, lang),
-            sys_msg(Msg::SettingSpeedFast, lang),
-            sys_msg(Msg::SettingSpeedInstant, lang)
-        ),
-        TextSpeed::Fast => format!(
-            "{} | [{}] | {}",
-            sys_msg(Msg::SettingSpeedNormal, lang),
-            sys_msg(Msg::SettingSpeedFast, lang),
-            sys_msg(Msg::SettingSpeedInstant, lang)
-        ),
-        TextSpeed::Instant => format!(
-            "{} | {} | [{}]",
-            sys_msg(Msg::SettingSpeedNormal, lang),
-            sys_msg(Msg::SettingSpeedFast, lang),
-            sys_msg(Msg::SettingSpeedInstant, lang)
-        ),
+    let language_value = [
+        (Language::En, Msg::SettingLangEn),
+        (Language::Fr, Msg::SettingLangFr),
+        (Language::De, Msg::SettingLangDe),
+    ]
+    .iter()
+    .map(|&(language, msg)| {
+        if language == app.menu_language_draft {
+            format!("[{}]", sys_msg(msg, lang))
+        } else {
+            sys_msg(msg, lang).to_string()
+        }
+    })
+    .collect::<Vec<_>>()
+    .join(" | ");
+    let text_speed_value = [
+        (TextSpeed::Slow, Msg::SettingSpeedSlow),
+        (TextSpeed::Normal, Msg::SettingSpeedNormal),
+        (TextSpeed::Fast, Msg::SettingSpeedFast),
+        (TextSpeed::Instant, Msg::SettingSpeedInstant),
+    ]
+    .iter()
+    .map(|&(speed, msg)| {
+        if speed == app.menu_text_speed_draft {
+            format!("[{}]", sys_msg(msg, lang))
+        } else {
+            sys_msg(msg, lang).to_string()
+        }
+    })
+    .collect::<Vec<_>>()
+    .join(" | ");
+    let toggle_value = |enabled: bool| {
+        if enabled {
+            format!(
+                "[{}] | {}",
+                sys_msg(Msg::SettingEnabled, lang),
+                sys_msg(Msg::SettingDisabled, lang)
+            )
+        } else {
+            format!(
+                "{} | [{}]",
+                sys_msg(Msg::SettingEnabled, lang),
+                sys_msg(Msg::SettingDisabled, lang)
+            )
+        }
     };
-    let waiting_value = if app.menu_waiting_times_enabled_draft {
-        format!(
-            "[{}] | {}",
-            sys_msg(Msg::SettingEnabled, lang),
-            sys_msg(Msg::SettingDisabled, lang)
-        )
-    } else {
-        format!(
-            "{} | [{}]",
-            sys_msg(Msg::SettingEnabled, lang),
-            sys_msg(Msg::SettingDisabled, lang)
-        )
+    let waiting_value = toggle_value(app.menu_waiting_times_enabled_draft);
+    let automatic_dialogs_value = toggle_value(app.menu_automatic_dialogs_enabled_draft);
+    let fast_forward_value = toggle_value(app.menu_fast_forward_draft);
+    let typing_rhythm_value = toggle_value(app.menu_typing_rhythm_draft);
+    let act_cards_value = toggle_value(app.menu_act_cards_draft);
+    let reduce_motion_value = toggle_value(app.menu_reduce_motion_draft);
+    let consequence_hints_value = toggle_value(app.menu_consequence_hints_draft);
+    let show_stats_value = toggle_value(app.menu_show_stats_draft);
+    let trust_cues_value = toggle_value(app.menu_trust_cues_draft);
+    let commentary_value = toggle_value(app.menu_commentary_draft);
+    let rewind_value = match app.game_state.rewind_available() {
+        0 => String::new(),
+        steps => steps.to_string(),
     };
-    let automatic_dialogs_value = if app.menu_automatic_dialogs_enabled_draft {
-        format!(
+    let checkpoint_value = app
+        .game_state
+        .checkpoints
+        .last()
+        .and_then(|c| app.story_data.nodes.get(&c.node)?.checkpoint.as_ref())
+        .map(|name| app.story_data.localized(name, lang).into_owned())
+        .unwrap_or_default();
+    let chat_layout_value = match app.menu_chat_layout_draft {
+        ChatLayout::Inline => format!(
             "[{}] | {}",
-            sys_msg(Msg::SettingEnabled, lang),
-            sys_msg(Msg::SettingDisabled, lang)
-        )
-    } else {
-        format!(
+            sys_msg(Msg::SettingLayoutInline, lang),
+            sys_msg(Msg::SettingLayoutGutter, lang)
+        ),
+        ChatLayout::Gutter => format!(
             "{} | [{}]",
-            sys_msg(Msg::SettingEnabled, lang),
-            sys_msg(Msg::SettingDisabled, lang)
-        )
+            sys_msg(Msg::SettingLayoutInline, lang),
+            sys_msg(Msg::SettingLayoutGutter, lang)
+        ),
     };
+    let session_markers_value = [
+        (SessionMarkers::All, Msg::SettingAll),
+        (SessionMarkers::Collapsed, Msg::SettingMarkersCollapsed),
+        (SessionMarkers::Hidden, Msg::SettingMarkersHidden),
+    ]
+    .iter()
+    .map(|&(markers, msg)| {
+        if markers == app.menu_session_markers_draft {
+            format!("[{}]", sys_msg(msg, lang))
+        } else {
+            sys_msg(msg, lang).to_string()
+        }
+    })
+    .collect::<Vec<_>>()
+    .join(" | ");
+    let theme_value = app
+        .unlocked_themes()
+        .into_iter()
+        .map(|theme| {
+            let name = theme_name(theme, lang);
+            if theme == app.menu_theme_draft {
+                format!("[{}]", name)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ");
+    let idle_timeout_value = preset_value(
+        &IDLE_TIMEOUT_PRESETS,
+        app.menu_idle_timeout_draft,
+        sys_msg(Msg::SettingOff, lang),
+    ) + " min";
+    let idle_nudge_value = preset_value(
+        &IDLE_NUDGE_PRESETS,
+        app.menu_idle_nudge_draft,
+        sys_msg(Msg::SettingOff, lang),
+    ) + " s";
+    let min_read_value = preset_value(
+        &MIN_READ_PRESETS,
+        app.menu_min_read_draft,
+        sys_msg(Msg::SettingOff, lang),
+    ) + " ms";
+    let language_pace_label = format!(
+        "{} ({})",
+        sys_msg(Msg::MenuLanguagePace, lang),
+        app.menu_language_draft.code().to_uppercase()
+    );
+    let language_pace_value = preset_value(
+        &PACE_PRESETS,
+        app.menu_language_pace_draft.get(app.menu_language_draft),
+        "",
+    ) + " %";
+    let log_retention_value = preset_value(
+        &LOG_RETENTION_PRESETS,
+        app.menu_log_retention_draft,
+        sys_msg(Msg::SettingAll, lang),
+    );
+    let save_interval_value = preset_value(
+        &SAVE_INTERVAL_PRESETS,
+        app.menu_save_interval_draft,
+        sys_msg(Msg::SettingEveryMessage, lang),
+    ) + " ms";
 
-    let items = vec![
-        (sys_msg(Msg::MenuResume, lang), String::new()),
-        (sys_msg(Msg::MenuLanguage, lang), language_value),
-        (sys_msg(Msg::MenuTextSpeed, lang), text_speed_value),
-        (sys_msg(Msg::MenuWaitingTimes, lang), waiting_value),
-        (
-            sys_msg(Msg::MenuAutomaticDialogs, lang),
-            automatic_dialogs_value,
-        ),
-        (sys_msg(Msg::MenuValidate, lang), String::new()),
-        (sys_msg(Msg::MenuSaveQuit, lang), String::new()),
-    ];
+    let items: Vec<(&str, String)> = menu_items
+        .iter()
+        .map(|item| match item {
+            MenuItem::Resume => (sys_msg(Msg::MenuResume, lang), String::new()),
+            MenuItem::Language => (sys_msg(Msg::MenuLanguage, lang), language_value.clone()),
+            MenuItem::TextSpeed => (sys_msg(Msg::MenuTextSpeed, lang), text_speed_value.clone()),
+            MenuItem::LanguagePace => (language_pace_label.as_str(), language_pace_value.clone()),
+            MenuItem::WaitingTimes => (sys_msg(Msg::MenuWaitingTimes, lang), waiting_value.clone()),
+            MenuItem::AutomaticDialogs => (
+                sys_msg(Msg::MenuAutomaticDialogs, lang),
+                automatic_dialogs_value.clone(),
+            ),
+            MenuItem::FastForward => (
+                sys_msg(Msg::MenuFastForward, lang),
+                fast_forward_value.clone(),
+            ),
+            MenuItem::IdleNudge => (sys_msg(Msg::MenuIdleNudge, lang), idle_nudge_value.clone()),
+            MenuItem::MinReadTime => (sys_msg(Msg::MenuMinReadTime, lang), min_read_value.clone()),
+            MenuItem::LogRetention => (
+                sys_msg(Msg::MenuLogRetention, lang),
+                log_retention_value.clone(),
+            ),
+            MenuItem::SaveInterval => (
+                sys_msg(Msg::MenuSaveInterval, lang),
+                save_interval_value.clone(),
+            ),
+            MenuItem::ChatLayout => (
+                sys_msg(Msg::MenuChatLayout, lang),
+                chat_layout_value.clone(),
+            ),
+            MenuItem::SessionMarkers => (
+                sys_msg(Msg::MenuSessionMarkers, lang),
+                session_markers_value.clone(),
+            ),
+            MenuItem::ActCards => (sys_msg(Msg::MenuActCards, lang), act_cards_value.clone()),
+            MenuItem::Theme => (sys_msg(Msg::MenuTheme, lang), theme_value.clone()),
+            MenuItem::TypingRhythm => (
+                sys_msg(Msg::MenuTypingRhythm, lang),
+                typing_rhythm_value.clone(),
+            ),
+            MenuItem::ReduceMotion => (
+                sys_msg(Msg::MenuReduceMotion, lang),
+                reduce_motion_value.clone(),
+            ),
+            MenuItem::ConsequenceHints => (
+                sys_msg(Msg::MenuConsequenceHints, lang),
+                consequence_hints_value.clone(),
+            ),
+            MenuItem::ShowStats => (sys_msg(Msg::MenuShowStats, lang), show_stats_value.clone()),
+            MenuItem::TrustCues => (sys_msg(Msg::MenuTrustCues, lang), trust_cues_value.clone()),
+            MenuItem::Commentary => (sys_msg(Msg::MenuCommentary, lang), commentary_value.clone()),
+            MenuItem::IdleTimeout => (
+                sys_msg(Msg::MenuIdleTimeout, lang),
+                idle_timeout_value.clone(),
+            ),
+            MenuItem::Glossary => (sys_msg(Msg::MenuGlossary, lang), String::new()),
+            MenuItem::Recap => (sys_msg(Msg::MenuRecap, lang), String::new()),
+            MenuItem::Rewind => (sys_msg(Msg::MenuRewind, lang), rewind_value.clone()),
+            MenuItem::ReturnToCheckpoint => (
+                sys_msg(Msg::MenuReturnToCheckpoint, lang),
+                checkpoint_value.clone(),
+            ),
+            MenuItem::SavedRuns => (sys_msg(Msg::MenuSavedRuns, lang), String::new()),
+            MenuItem::ExportTranscript => (sys_msg(Msg::MenuExportTranscript, lang), String::new()),
+            MenuItem::ReplayIntro => (sys_msg(Msg::MenuReplayIntro, lang), String::new()),
+            MenuItem::Validate => (sys_msg(Msg::MenuValidate, lang), String::new()),
+            MenuItem::SaveQuit => (sys_msg(Msg::MenuSaveQuit, lang), String::new()),
+        })
+        .collect();
 
     let mut lines = Vec::new();
     for (i, (label, value)) in items.iter().enumerate() {
@@ -1328,14 +4161,18 @@ fn draw_pause_menu(frame: &mut Frame, app: &App) {
         let marker = if selected { "> " } else { "  " };
         let left_style = if selected {
             Style::default()
-                .fg(Color::Yellow)
+                .fg(palette.choice)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(palette.system)
         };
 
         let value_style = Style::default()
-            .fg(if selected { Color::Cyan } else { Color::Gray })
+            .fg(if selected {
+                palette.selected
+            } else {
+                palette.text
+            })
             .add_modifier(if selected {
                 Modifier::BOLD
             } else {
@@ -1349,7 +4186,7 @@ fn draw_pause_menu(frame: &mut Frame, app: &App) {
             )));
         } else {
             let available = inner.width.saturating_sub(2) as usize;
-            let used = label.len() + value.len();
+            let used = label.chars().count() + value.chars().count();
             let spacing = if available > used {
                 available - used
             } else {
@@ -1368,99 +4205,629 @@ fn draw_pause_menu(frame: &mut Frame, app: &App) {
     frame.render_widget(Paragraph::new(text), inner);
 }
 
-fn draw_prompt_screen(frame: &mut Frame, app: &App, title: &str) {
+/// Confirmation popup for a point-of-no-return choice.
+fn draw_confirm_choice(frame: &mut Frame, app: &App) {
     let area = frame.area();
-
-    let [_top, center, _bottom] = Layout::vertical([
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    let prompt = app.confirm_prompt().unwrap_or_default();
+    let popup_width = 50u16.min(area.width.saturating_sub(4));
+    let text_width = popup_width.saturating_sub(4).max(1) as usize;
+    let rows = wrap_words(&prompt, text_width);
+    let popup_height = (rows.len() as u16 + 5).min(area.height);
+    let popup_area = centered_rect(popup_width, popup_height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.choice))
+        .padding(Padding::new(1, 1, 1, 0));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let mut lines: Vec<Line> = rows
+        .into_iter()
+        .map(|row| Line::from(Span::styled(row, Style::default().fg(palette.title))).centered())
+        .collect();
+    lines.push(Line::from(""));
+    let button = |msg: Msg, selected: bool| {
+        if selected {
+            Span::styled(
+                format!("> {} ", sys_msg(msg, lang)),
+                Style::default()
+                    .fg(palette.choice)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(
+                format!("  {} ", sys_msg(msg, lang)),
+                Style::default().fg(palette.system),
+            )
+        }
+    };
+    lines.push(
+        Line::from(vec![
+            button(Msg::ConfirmChoiceYes, app.confirm_index == 0),
+            Span::raw("   "),
+            button(Msg::ConfirmChoiceBack, app.confirm_index == 1),
+        ])
+        .centered(),
+    );
+    frame.render_widget(Paragraph::new(Text::from(lines)), inner);
+}
+
+/// Act title card, e.g. "ACT II" over "The Crossing", filling the screen.
+fn draw_act_card(frame: &mut Frame, app: &App) {
+    let palette = palette(app.game_state.settings.theme);
+    let Some((heading, title)) = app.act_card_text() else {
+        return;
+    };
+    let lang = app.lang();
+    let area = frame.area();
+    frame.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(Span::styled(heading, Style::default().fg(palette.system))).centered(),
+        Line::from(""),
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(palette.title)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .centered(),
+        Line::from(""),
+        Line::from(""),
+        Line::from(Span::styled(
+            sys_msg(Msg::ActCardSkip, lang),
+            Style::default().fg(palette.system),
+        ))
+        .centered(),
+    ];
+    let height = lines.len() as u16;
+    let card_area = Rect {
+        y: area.y + area.height.saturating_sub(height) / 2,
+        height: height.min(area.height),
+        ..area
+    };
+    frame.render_widget(Paragraph::new(Text::from(lines)), card_area);
+}
+
+/// Roman numeral for an act number ("IV"); 0 has none and stays "0".
+fn roman_numeral(mut n: u32) -> String {
+    if n == 0 {
+        return "0".to_string();
+    }
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Branch explorer: the run's decisions, or the roads not taken at one of
+/// them with the messages each would have led to. Nothing here is applied.
+fn draw_explorer(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = centered_rect(popup_width, popup_height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.explorer))
+        .title(format!(" {} ", sys_msg(Msg::ExploreTitle, lang)))
+        .title_alignment(ratatui::layout::Alignment::Center)
+        .padding(Padding::new(1, 1, 1, 0));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            sys_msg(Msg::ExploreNotCanon, lang),
+            Style::default()
+                .fg(palette.explorer)
+                .add_modifier(Modifier::ITALIC),
+        )),
+        Line::from(""),
+    ];
+    let label = |record: &DecisionRecord| -> Option<(&StoryNode, &Choice)> {
+        let node = app.story_data.nodes.get(&record.node)?;
+        let choice = node.choices.as_ref()?.get(record.choice)?;
+        Some((node, choice))
+    };
+
+    let path = &app.game_state.decision_path;
+    let mut scroll = 0u16;
+    if app.explore_open {
+        if let Some((node, taken)) = path.get(app.explore_index).and_then(label) {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", sys_msg(Msg::ExploreYouChose, lang)),
+                    Style::default().fg(palette.system),
+                ),
+                Span::styled(
+                    app.story_data.localized(&taken.label, lang).into_owned(),
+                    Style::default().fg(palette.text),
+                ),
+            ]));
+            lines.push(Line::from(""));
+            let others: Vec<&Choice> = node
+                .ordered_choices()
+                .into_iter()
+                .filter(|&(i, _)| i != path[app.explore_index].choice)
+                .map(|(_, c)| c)
+                .collect();
+            if others.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    sys_msg(Msg::ExploreNoAlternatives, lang),
+                    Style::default().fg(palette.system),
+                )));
+            }
+            for choice in others {
+                lines.push(Line::from(Span::styled(
+                    format!("> {}", app.story_data.localized(&choice.label, lang)),
+                    Style::default()
+                        .fg(palette.choice)
+                        .add_modifier(Modifier::BOLD),
+                )));
+                let next = app.story_data.nodes.get(&choice.next_node);
+                for msg in next.iter().flat_map(|n| &n.messages) {
+                    // Conditional lines depend on a state this run never had
+                    if msg.condition.is_none() {
+                        lines.push(Line::from(Span::styled(
+                            format!("  {}", app.story_data.localized(&msg.text, lang)),
+                            Style::default().fg(palette.text),
+                        )));
+                    }
+                }
+                lines.push(Line::from(""));
+            }
+        }
+    } else {
+        for (i, record) in path.iter().enumerate() {
+            let Some((_, choice)) = label(record) else {
+                continue;
+            };
+            let (prefix, style) = if i == app.explore_index {
+                (
+                    "> ",
+                    Style::default()
+                        .fg(palette.choice)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default().fg(palette.text))
+            };
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{}{}. {}",
+                    prefix,
+                    i + 1,
+                    app.story_data.localized(&choice.label, lang)
+                ),
+                style,
+            )));
+        }
+        // Keep the selection in view on long runs
+        let visible = inner.height.saturating_sub(2) as usize;
+        scroll = (app.explore_index + 1).saturating_sub(visible) as u16;
+    }
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0)),
+        inner,
+    );
+}
+
+/// Named runs: a row to save the current one, then one row per saved run.
+fn draw_saved_runs(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    let popup_width = 58u16.min(area.width.saturating_sub(4));
+    let popup_height = (MAX_SAVED_RUNS as u16 + 9).min(area.height);
+    let popup_area = centered_rect(popup_width, popup_height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.choice))
+        .title(format!(
+            " {} {}/{} ",
+            sys_msg(Msg::MenuSavedRuns, lang),
+            app.saved_runs.runs.len(),
+            MAX_SAVED_RUNS
+        ))
+        .title_alignment(ratatui::layout::Alignment::Center)
+        .padding(Padding::new(1, 1, 1, 0));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let row = |i: usize, text: String| {
+        if i == app.saved_runs_index {
+            Line::from(Span::styled(
+                format!("> {}", text),
+                Style::default()
+                    .fg(palette.choice)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(Span::styled(
+                format!("  {}", text),
+                Style::default().fg(palette.text),
+            ))
+        }
+    };
+    let new_row = match app.saved_run_label {
+        Some(ref label) => format!("{} {}_", sys_msg(Msg::SavedRunsNew, lang), label),
+        None => sys_msg(Msg::SavedRunsNew, lang).to_string(),
+    };
+    let mut lines = vec![row(0, new_row)];
+    for (i, run) in app.saved_runs.runs.iter().enumerate() {
+        let saved_at = run.saved_at.with_timezone(&chrono::Local);
+        lines.push(row(
+            i + 1,
+            format!(
+                "{}  ({} {}, {})",
+                run.label,
+                sys_msg(Msg::DaySeparator, lang),
+                run.state.day,
+                saved_at.format("%Y-%m-%d %H:%M")
+            ),
+        ));
+    }
+    lines.push(Line::from(""));
+    if let Some(ref error) = app.saved_runs_unreadable {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(palette.bad),
+        )));
+    }
+    if let Some(ref notice) = app.saved_runs_notice {
+        lines.push(Line::from(Span::styled(
+            notice.clone(),
+            Style::default().fg(palette.title),
+        )));
+    }
+    let hint = if app.saved_run_label.is_some() {
+        Msg::SavedRunsNameHint
+    } else {
+        Msg::SavedRunsHint
+    };
+    lines.push(Line::from(Span::styled(
+        sys_msg(hint, lang),
+        Style::default().fg(palette.system),
+    )));
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false }),
+        inner,
+    );
+}
+
+/// Glossary popup: unlocked terms with their definitions, locked ones as "???".
+fn draw_glossary(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    let popup_width = 58u16.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = centered_rect(popup_width, popup_height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.choice))
+        .title(format!(" {} ", sys_msg(Msg::MenuGlossary, lang)))
+        .title_alignment(ratatui::layout::Alignment::Center)
+        .padding(Padding::new(1, 1, 1, 0));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let mut lines = Vec::new();
+    for (id, entry) in &app.story_data.glossary {
+        if app.game_state.glossary_unlocked.contains(id) {
+            lines.push(Line::from(Span::styled(
+                entry.term.get(lang).to_string(),
+                Style::default()
+                    .fg(palette.choice)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(Span::styled(
+                entry.definition.get(lang).to_string(),
+                Style::default().fg(palette.text),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                sys_msg(Msg::GlossaryLocked, lang),
+                Style::default().fg(palette.system),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            sys_msg(Msg::GlossaryEmpty, lang),
+            Style::default().fg(palette.system),
+        )));
+    }
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false }),
+        inner,
+    );
+}
+
+/// Lines of the story recap: day and act, key flags set so far and the
+/// player's last reply.
+fn recap_lines(app: &App, lang: Language) -> Vec<Line<'static>> {
+    let palette = palette(app.game_state.settings.theme);
+    let mut position = format!(
+        "{} {}",
+        sys_msg(Msg::DaySeparator, lang),
+        app.game_state.day
+    );
+    if let Some(act) = app.current_act {
+        position.push_str(&format!(
+            " \u{00b7} {} {}",
+            sys_msg(Msg::ActProgress, lang),
+            act
+        ));
+    }
+    let mut lines = vec![
+        Line::from(Span::styled(
+            position,
+            Style::default()
+                .fg(palette.choice)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    let key_flags: Vec<&String> = app
+        .game_state
+        .flags
+        .iter()
+        .filter(|(flag, set)| **set && app.story_data.flag_has_label(flag))
+        .map(|(flag, _)| flag)
+        .collect();
+    let last_reply = app
+        .game_state
+        .message_log
+        .iter()
+        .rev()
+        .find(|entry| entry.sender == Sender::Player);
+    if key_flags.is_empty() && last_reply.is_none() {
+        lines.push(Line::from(Span::styled(
+            sys_msg(Msg::RecapNothingYet, lang),
+            Style::default().fg(palette.system),
+        )));
+        return lines;
+    }
+
+    if !key_flags.is_empty() {
+        lines.push(Line::from(Span::styled(
+            sys_msg(Msg::KeyChoices, lang),
+            Style::default().fg(palette.system),
+        )));
+        for flag in key_flags {
+            lines.push(Line::from(Span::styled(
+                format!("\u{00b7} {}", app.story_data.flag_label(flag, lang)),
+                Style::default().fg(palette.text),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    if let Some(entry) = last_reply {
+        lines.push(Line::from(Span::styled(
+            sys_msg(Msg::RecapLastChoice, lang),
+            Style::default().fg(palette.system),
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("> {}", entry.text),
+            Style::default().fg(palette.text),
+        )));
+    }
+    lines
+}
+
+fn draw_recap(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
+    let popup_width = 58u16.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = centered_rect(popup_width, popup_height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(palette.choice))
+        .title(format!(" {} ", sys_msg(Msg::MenuRecap, lang)))
+        .title_alignment(ratatui::layout::Alignment::Center)
+        .padding(Padding::new(1, 1, 1, 0));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    frame.render_widget(
+        Paragraph::new(Text::from(recap_lines(app, lang))).wrap(Wrap { trim: false }),
+        inner,
+    );
+}
+
+fn draw_prompt_screen(frame: &mut Frame, app: &App, title: &str) {
+    let area = frame.area();
+    let palette = palette(app.game_state.settings.theme);
+
+    // Starting a new game after some runs: show what is left to find
+    let endings = if app.screen == Screen::LanguageSelect && !app.ledger.endings_seen.is_empty() {
+        ending_list_lines(app, app.lang())
+    } else {
+        Vec::new()
+    };
+
+    let [_top, center, _bottom] = Layout::vertical([
         Constraint::Fill(1),
-        Constraint::Length(app.prompt_options.len() as u16 + 6),
+        Constraint::Length((app.prompt_options.len() + endings.len()) as u16 + 6),
         Constraint::Fill(1),
     ])
     .areas(area);
 
-    let mut lines = Vec::new();
-    lines.push(Line::from(""));
-    lines.push(
+    let mut lines = vec![
+        Line::from(""),
         Line::from(Span::styled(
             "E S H A R A",
             Style::default()
-                .fg(Color::White)
+                .fg(palette.title)
                 .add_modifier(Modifier::BOLD),
         ))
         .centered(),
-    );
-    lines.push(Line::from(""));
-    lines.push(Line::from(Span::styled(title, Style::default().fg(Color::DarkGray))).centered());
-    lines.push(Line::from(""));
+        Line::from(""),
+        Line::from(Span::styled(title, Style::default().fg(palette.system))).centered(),
+        Line::from(""),
+    ];
 
     for (i, opt) in app.prompt_options.iter().enumerate() {
         let (prefix, style) = if i == app.prompt_index {
             (
                 "> ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::BOLD),
             )
         } else {
             (
                 "  ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::DIM),
             )
         };
         lines.push(Line::from(Span::styled(format!("{}{}", prefix, opt), style)).centered());
     }
+    lines.extend(endings);
 
     let text = Text::from(lines);
     frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: false }), center);
 }
 
+/// Every ending, with undiscovered titles kept hidden, under a count of
+/// the ones found so far. Empty when the story has no endings.
+fn ending_list_lines(app: &App, lang: Language) -> Vec<Line<'static>> {
+    let palette = palette(app.game_state.settings.theme);
+    let mut ending_keys: Vec<&String> = app.story_data.endings.keys().collect();
+    ending_keys.sort();
+    if ending_keys.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "{} {}/{}",
+                sys_msg(Msg::EndingsDiscovered, lang),
+                app.ledger.discovered(ending_keys.iter().copied()),
+                ending_keys.len()
+            ),
+            Style::default().fg(palette.system),
+        ))
+        .centered(),
+    ];
+    for key in ending_keys {
+        let is_current = app.ending_reached.as_deref() == Some(key.as_str());
+        let mut spans = Vec::new();
+        if app.ledger.has_seen(key) {
+            let style = if is_current {
+                Style::default().fg(palette.title)
+            } else {
+                Style::default().fg(palette.text)
+            };
+            spans.push(Span::styled(app.story_data.ending_text(key, lang).0, style));
+        } else {
+            spans.push(Span::styled(
+                sys_msg(Msg::EndingUnknown, lang),
+                Style::default().fg(palette.system),
+            ));
+        }
+        if is_current && app.new_ending {
+            spans.push(Span::styled(
+                format!("  {}", sys_msg(Msg::EndingNew, lang)),
+                Style::default()
+                    .fg(palette.choice)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        lines.push(Line::from(spans).centered());
+    }
+    lines
+}
+
 fn draw_intro(frame: &mut Frame, app: &App) {
     let area = frame.area();
+    let palette = palette(app.game_state.settings.theme);
 
-    let mut lines = Vec::new();
-    lines.push(Line::from(""));
-    lines.push(
+    let mut lines = vec![
+        Line::from(""),
         Line::from(Span::styled(
             "E S H A R A",
             Style::default()
-                .fg(Color::White)
+                .fg(palette.title)
                 .add_modifier(Modifier::BOLD),
         ))
         .centered(),
-    );
-    lines.push(Line::from(""));
-    lines.push(
+        Line::from(""),
         Line::from(Span::styled(
             "─".repeat(40),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
-    );
-    lines.push(Line::from(""));
+        Line::from(""),
+    ];
 
     if let Some(ref tw) = app.intro_typewriter {
         let visible = tw.visible_text();
         for line in visible.lines() {
             lines.push(
-                Line::from(Span::styled(line, Style::default().fg(Color::DarkGray))).centered(),
+                Line::from(Span::styled(line, Style::default().fg(palette.system))).centered(),
             );
         }
 
         if tw.is_done() {
             lines.push(Line::from(""));
             lines.push(Line::from(""));
-            let hint = if app.lang() == Language::Fr {
-                "Appuyez sur une touche..."
-            } else {
-                "Press any key..."
+            let hint = match app.lang() {
+                Language::En => "Press any key...",
+                Language::Fr => "Appuyez sur une touche...",
+                Language::De => "Beliebige Taste dr\u{00fc}cken...",
             };
             lines.push(
                 Line::from(Span::styled(
                     hint,
                     Style::default()
-                        .fg(Color::DarkGray)
+                        .fg(palette.system)
                         .add_modifier(Modifier::DIM),
                 ))
                 .centered(),
@@ -1481,6 +4848,7 @@ fn draw_intro(frame: &mut Frame, app: &App) {
 
 fn draw_waiting(frame: &mut Frame, app: &App) {
     let area = frame.area();
+    let palette = palette(app.game_state.settings.theme);
 
     let mut lines = Vec::new();
     lines.push(Line::from(""));
@@ -1488,7 +4856,7 @@ fn draw_waiting(frame: &mut Frame, app: &App) {
         Line::from(Span::styled(
             "E S H A R A",
             Style::default()
-                .fg(Color::White)
+                .fg(palette.title)
                 .add_modifier(Modifier::BOLD),
         ))
         .centered(),
@@ -1498,7 +4866,7 @@ fn draw_waiting(frame: &mut Frame, app: &App) {
     if let Some(ref msg) = app.wait_message {
         for line in msg.lines() {
             lines.push(
-                Line::from(Span::styled(line, Style::default().fg(Color::DarkGray))).centered(),
+                Line::from(Span::styled(line, Style::default().fg(palette.system))).centered(),
             );
         }
     }
@@ -1510,14 +4878,14 @@ fn draw_waiting(frame: &mut Frame, app: &App) {
             (
                 "> ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::BOLD),
             )
         } else {
             (
                 "  ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::DIM),
             )
         };
@@ -1538,6 +4906,7 @@ fn draw_waiting(frame: &mut Frame, app: &App) {
 fn draw_ending(frame: &mut Frame, app: &App) {
     let area = frame.area();
     let lang = app.lang();
+    let palette = palette(app.game_state.settings.theme);
 
     let mut lines = Vec::new();
     lines.push(Line::from(""));
@@ -1545,7 +4914,7 @@ fn draw_ending(frame: &mut Frame, app: &App) {
         Line::from(Span::styled(
             "E S H A R A",
             Style::default()
-                .fg(Color::White)
+                .fg(palette.title)
                 .add_modifier(Modifier::BOLD),
         ))
         .centered(),
@@ -1554,7 +4923,7 @@ fn draw_ending(frame: &mut Frame, app: &App) {
     lines.push(
         Line::from(Span::styled(
             "─".repeat(40),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
@@ -1563,7 +4932,7 @@ fn draw_ending(frame: &mut Frame, app: &App) {
     lines.push(
         Line::from(Span::styled(
             format!("--- {} ---", sys_msg(Msg::EndingReached, lang)),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
@@ -1573,20 +4942,32 @@ fn draw_ending(frame: &mut Frame, app: &App) {
         if let Some(info) = app.story_data.ending_info(ending_key) {
             // Color based on ending type
             let title_color = match info.ending_type.as_str() {
-                "good" => Color::Green,
-                "bad" => Color::Red,
-                "bittersweet" => Color::Yellow,
-                _ => Color::White,
+                "good" => palette.good,
+                "bad" => palette.bad,
+                "bittersweet" => palette.bittersweet,
+                _ => palette.title,
             };
+            let (title, description) = app.story_data.ending_text(ending_key, lang);
             lines.push(
                 Line::from(Span::styled(
-                    format!("\"{}\"", info.title.get(lang)),
+                    format!("\"{}\"", title),
                     Style::default()
                         .fg(title_color)
                         .add_modifier(Modifier::BOLD),
                 ))
                 .centered(),
             );
+            if let Some(description) = description {
+                lines.push(
+                    Line::from(Span::styled(
+                        description,
+                        Style::default()
+                            .fg(palette.text)
+                            .add_modifier(Modifier::ITALIC),
+                    ))
+                    .centered(),
+                );
+            }
         }
     }
 
@@ -1598,15 +4979,99 @@ fn draw_ending(frame: &mut Frame, app: &App) {
                 sys_msg(Msg::DaysSurvived, lang),
                 app.game_state.day
             ),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
-    lines.push(Line::from(""));
-    lines.push(
+    for (label, seconds) in [
+        (Msg::TimeConnected, app.game_state.total_playtime_secs),
+        (Msg::ActivePlayTime, app.game_state.active_play_ms / 1000),
+        (Msg::WaitsEndured, app.game_state.waited_secs),
+    ] {
+        lines.push(
+            Line::from(Span::styled(
+                format!("{} {}", sys_msg(label, lang), format_duration(seconds)),
+                Style::default().fg(palette.system),
+            ))
+            .centered(),
+        );
+    }
+    if let Some(code) = crate::route::encode(&app.story_data, &app.game_state.decision_path) {
+        lines.push(
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", sys_msg(Msg::RouteCode, lang)),
+                    Style::default().fg(palette.system),
+                ),
+                Span::styled(code, Style::default().fg(palette.text)),
+            ])
+            .centered(),
+        );
+    }
+
+    // Where the people Elara came across ended up
+    let epilogues = app.story_data.epilogues_for(&app.game_state);
+    if !epilogues.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(Span::styled(
+                sys_msg(Msg::Epilogues, lang),
+                Style::default().fg(palette.system),
+            ))
+            .centered(),
+        );
+        for (name, text) in epilogues {
+            lines.push(
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", name.get(lang)),
+                        Style::default().fg(palette.title),
+                    ),
+                    Span::styled(
+                        app.story_data.localized(text, lang).into_owned(),
+                        Style::default().fg(palette.text),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+    }
+
+    // Key plot flags picked up along the way
+    let key_flags: Vec<&String> = app
+        .game_state
+        .flags
+        .iter()
+        .filter(|(flag, set)| **set && app.story_data.flag_has_label(flag))
+        .map(|(flag, _)| flag)
+        .collect();
+    if !key_flags.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(Span::styled(
+                sys_msg(Msg::KeyChoices, lang),
+                Style::default().fg(palette.system),
+            ))
+            .centered(),
+        );
+        for flag in key_flags {
+            lines.push(
+                Line::from(Span::styled(
+                    format!("· {}", app.story_data.flag_label(flag, lang)),
+                    Style::default().fg(palette.text),
+                ))
+                .centered(),
+            );
+        }
+    }
+
+    lines.extend(ending_list_lines(app, lang));
+
+    lines.push(Line::from(""));
+    lines.push(
         Line::from(Span::styled(
             "─".repeat(40),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
@@ -1615,7 +5080,7 @@ fn draw_ending(frame: &mut Frame, app: &App) {
     lines.push(
         Line::from(Span::styled(
             sys_msg(Msg::PlayAgain, lang),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(palette.system),
         ))
         .centered(),
     );
@@ -1626,54 +5091,168 @@ fn draw_ending(frame: &mut Frame, app: &App) {
             (
                 "> ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::BOLD),
             )
         } else {
             (
                 "  ",
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(palette.choice)
                     .add_modifier(Modifier::DIM),
             )
         };
         lines.push(Line::from(Span::styled(format!("{}{}", prefix, opt), style)).centered());
     }
+    if !app.game_state.decision_path.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(Span::styled(
+                sys_msg(Msg::ExploreHint, lang),
+                Style::default().fg(palette.system),
+            ))
+            .centered(),
+        );
+    }
 
+    // Size by wrapped rows so a long description doesn't push the options out
+    let text = Text::from(lines);
     let [_top, center, _bottom] = Layout::vertical([
         Constraint::Fill(1),
-        Constraint::Length(lines.len() as u16),
+        Constraint::Length(wrapped_line_count(&text, area.width) as u16),
         Constraint::Fill(1),
     ])
     .areas(area);
 
-    let text = Text::from(lines);
     frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: false }), center);
 }
 
-/// Estimate the number of visual lines a `Text` will occupy when wrapped to `width`.
-fn wrapped_line_count(text: &Text, width: u16) -> usize {
-    if width == 0 {
-        return text.lines.len();
+/// Whether the typing caret is drawn this frame. It blinks on the animation
+/// frame clock while text is being revealed and stays solid under reduce motion.
+fn caret_visible(tw: &TypewriterState, reduce_motion: bool) -> bool {
+    if tw.is_done() {
+        return false;
     }
-    let w = width as usize;
-    text.lines
-        .iter()
-        .map(|line| {
-            let line_width: usize = line.spans.iter().map(|s| s.content.len()).sum();
-            if line_width == 0 {
-                1 // empty lines still take one row
+    if reduce_motion {
+        return true;
+    }
+    (tw.indicator_start.elapsed().as_millis() / u128::from(ANIM_FRAME_MS)).is_multiple_of(2)
+}
+
+/// Put the bookmark marker in the first column of a message's first line,
+/// which every layout leaves blank.
+fn mark_bookmarked(line: &mut Line) {
+    if let Some(span) = line.spans.first_mut() {
+        let rest: String = span.content.chars().skip(1).collect();
+        span.content = format!("{}{}", BOOKMARK_MARKER, rest).into();
+    }
+}
+
+/// Lay out a message for the gutter layout: the speaker label sits in a fixed
+/// left column and the body is pre-wrapped to `body_width`, so continuation
+/// lines stay aligned and each line takes exactly one row.
+fn gutter_lines(
+    label: &str,
+    body: &str,
+    style: Style,
+    body_width: u16,
+    caret: Option<&'static str>,
+) -> Vec<Line<'static>> {
+    let label = format!(" {:<width$}", label, width = GUTTER_WIDTH as usize - 1);
+    let blank = " ".repeat(GUTTER_WIDTH as usize);
+    let rows = wrap_words(body, body_width as usize);
+    let last = rows.len() - 1;
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let gutter = if i == 0 {
+                Span::styled(label.clone(), style.add_modifier(Modifier::BOLD))
             } else {
-                (line_width + w - 1) / w // ceil division
+                Span::raw(blank.clone())
+            };
+            let mut spans = vec![gutter, Span::styled(row, style)];
+            if i == last {
+                if let Some(caret) = caret {
+                    spans.push(Span::styled(caret, style));
+                }
             }
+            Line::from(spans)
         })
-        .sum()
+        .collect()
+}
+
+/// Greedy word wrap to `width` columns; words longer than a line are split.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if current_len > 0 {
+                rows.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            rows.push(word.drain(..width).collect());
+        }
+        if word.is_empty() {
+            continue;
+        }
+        let needed = if current_len == 0 {
+            word.len()
+        } else {
+            current_len + 1 + word.len()
+        };
+        if needed > width {
+            rows.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.extend(word.iter());
+        current_len += word.len();
+    }
+    if current_len > 0 || rows.is_empty() {
+        rows.push(current);
+    }
+    rows
+}
+
+/// Estimate the number of visual lines a `Text` will occupy when wrapped to `width`.
+fn wrapped_line_count(text: &Text, width: u16) -> usize {
+    text.lines.iter().map(|line| line_rows(line, width)).sum()
+}
+
+/// Rows a single line takes once wrapped to `width`.
+fn line_rows(line: &Line, width: u16) -> usize {
+    if width == 0 {
+        return 1;
+    }
+    let line_width: usize = line.spans.iter().map(|s| s.content.chars().count()).sum();
+    if line_width == 0 {
+        1 // empty lines still take one row
+    } else {
+        line_width.div_ceil(width as usize)
+    }
 }
 
 fn format_elapsed_time(seconds: u64, lang: Language) -> String {
     let hours = seconds / 3600;
     let minutes = (seconds % 3600) / 60;
 
+    if lang == Language::De {
+        return match (hours, minutes) {
+            (0, 0) => "ein paar Sekunden vergangen".to_string(),
+            (0, m) => format!("{} min vergangen", m),
+            (h, 0) => format!("{} h vergangen", h),
+            (h, m) => format!("{} h {} min vergangen", h, m),
+        };
+    }
+
     if hours > 0 {
         if lang == Language::Fr {
             if minutes > 0 {
@@ -1716,18 +5295,73 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     Rect::new(x, y, width.min(area.width), height.min(area.height))
 }
 
+// ── Terminal check ───────────────────────────────────────────
+
+/// Why the full-screen UI cannot start in the current terminal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TerminalProblem {
+    /// stdin or stdout is not a TTY (redirected, piped, some CI shells).
+    NotInteractive,
+    /// The window is below the minimum drawable size.
+    TooSmall,
+}
+
+impl TerminalProblem {
+    /// Localized explanation plus a pointer to the classic frontend.
+    pub fn message(self, lang: Language) -> String {
+        let problem = match self {
+            TerminalProblem::NotInteractive => Msg::TerminalNotInteractive,
+            TerminalProblem::TooSmall => Msg::TerminalTooSmall,
+        };
+        format!(
+            "{}\n{}",
+            sys_msg(problem, lang),
+            sys_msg(Msg::TerminalTryClassic, lang)
+        )
+    }
+}
+
+/// Check that the terminal can host the UI, before raw mode or the
+/// alternate screen are touched.
+pub fn check_terminal() -> Result<(), TerminalProblem> {
+    use std::io::IsTerminal;
+
+    let interactive = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+    let size = ratatui::crossterm::terminal::size().ok();
+    terminal_problem(interactive, size).map_or(Ok(()), Err)
+}
+
+fn terminal_problem(interactive: bool, size: Option<(u16, u16)>) -> Option<TerminalProblem> {
+    match size {
+        _ if !interactive => Some(TerminalProblem::NotInteractive),
+        // crossterm cannot query the size without a real terminal
+        None => Some(TerminalProblem::NotInteractive),
+        Some((width, height)) if width < MIN_TERMINAL_WIDTH || height < MIN_TERMINAL_HEIGHT => {
+            Some(TerminalProblem::TooSmall)
+        }
+        Some(_) => None,
+    }
+}
+
 // ── Main event loop ──────────────────────────────────────────
 
+/// Ctrl+C as seen in raw mode, where it arrives as a key instead of a signal.
+fn is_ctrl_c(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c')
+}
+
 /// Run the ratatui event loop. This is the main entry point for the UI.
 pub fn run(mut app: App, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
     let tick_rate = Duration::from_millis(30);
 
-    loop {
+    // Ctrl+C is checked around every draw, so the caller gets the terminal
+    // back to restore without another frame going out
+    while !crate::is_interrupted() {
         // Draw
         terminal.draw(|frame| draw(frame, &app))?;
 
         // Check quit
-        if app.should_quit {
+        if app.should_quit || crate::is_interrupted() {
             break;
         }
 
@@ -1735,25 +5369,3247 @@ pub fn run(mut app: App, terminal: &mut DefaultTerminal) -> std::io::Result<()>
         if event::poll(tick_rate)? {
             match event::read()? {
                 Event::Key(key) => {
+                    app.last_input = Instant::now();
                     // Only handle key press events (not release/repeat)
                     if key.kind == KeyEventKind::Press {
-                        handle_key(&mut app, key.code);
+                        if is_ctrl_c(&key) {
+                            crate::set_interrupted();
+                        } else {
+                            handle_key(&mut app, key.code);
+                        }
                     }
                 }
-                Event::Mouse(mouse) => handle_mouse(&mut app, mouse),
+                Event::Mouse(mouse) => {
+                    app.last_input = Instant::now();
+                    handle_mouse(&mut app, mouse);
+                }
+                Event::Resize(_, _) => {
+                    // Repaint from scratch and re-measure the chat at the new size
+                    terminal.clear()?;
+                    terminal.draw(|frame| draw(frame, &app))?;
+                    clamp_chat_scroll(&mut app);
+                }
                 _ => {}
             }
         }
 
         // Tick animations
         tick(&mut app);
-
-        // Check Ctrl+C flag
-        if crate::is_interrupted() {
-            let _ = save_game(&app.game_state);
-            break;
+        if std::mem::take(&mut app.bell_pending) {
+            use std::io::Write;
+            let mut out = std::io::stdout();
+            let _ = out.write_all(b"\x07").and_then(|()| out.flush());
         }
     }
 
+    // Interrupted: save once, whichever check caught it (quitting has saved already)
+    if crate::is_interrupted() && !app.should_quit {
+        app.save_now();
+    }
+    // A throttled save still waiting for its turn goes out before leaving
+    app.flush_pending_save();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::story::embedded_story;
+
+    fn test_app() -> App {
+        app_with_story(embedded_story())
+    }
+
+    fn app_with_story(story: StoryData) -> App {
+        let state = GameState::from_story(Language::En, &story);
+        let mut app = App::new(state, story);
+        app.persist = false;
+        app
+    }
+
+    fn story_from_json(json: &str) -> StoryData {
+        serde_json::from_str(json).expect("test story should parse")
+    }
+
+    /// Draw the app once on a test terminal and return the buffer, styles included.
+    fn render_buffer(app: &App, width: u16, height: u16) -> ratatui::buffer::Buffer {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| draw(frame, app)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Render `app` on a `width` x `height` test terminal and return its rows.
+    fn render_rows(app: &App, width: u16, height: u16) -> Vec<String> {
+        let buffer = render_buffer(app, width, height);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Drive the shared node logic until choices are shown or an ending is reached.
+    fn drive(app: &mut App) {
+        for _ in 0..100 {
+            if app.screen == Screen::Ending || app.should_quit {
+                return;
+            }
+            if app.advance_story {
+                app.process_current_node();
+            }
+            app.flush_messages();
+            if !app.advance_story {
+                return;
+            }
+        }
+        panic!("story did not settle within 100 steps");
+    }
+
+    fn resolve_ending(state: GameState) -> String {
+        let mut app = test_app();
+        app.game_state = state;
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+        app.ending_reached.expect("an ending should be reached")
+    }
+
+    /// Play the shipped story from the start, answering each choice node with
+    /// the listed index and letting every real-time wait elapse immediately.
+    /// Panics if the story asks for a choice the route does not expect.
+    fn play_route(route: &[(&str, usize)]) -> App {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        let mut steps = route.iter();
+        for _ in 0..500 {
+            drive(&mut app);
+            if app.screen == Screen::Ending {
+                assert!(steps.next().is_none(), "route ended early");
+                return app;
+            }
+            if app.game_state.waiting_until.is_some() {
+                app.game_state.waiting_until =
+                    Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+                tick(&mut app);
+                continue;
+            }
+            let &(node, index) = steps.next().expect("route ran out of choices");
+            assert_eq!(app.game_state.current_node, node, "route diverged");
+            app.choice_index = index;
+            app.select_choice();
+            if app.overlay == Overlay::ConfirmChoice {
+                app.confirm_choice();
+            }
+        }
+        panic!("route did not reach an ending");
+    }
+
+    #[test]
+    fn test_heroic_route_reaches_let_go() {
+        // The canonical "do everything right" run: encourage Elara, scavenge
+        // the warehouse, take the records, open up about Lina, and let the
+        // failsafe shut the emitter down. Still Here needs more health than
+        // the shipped story leaves her with, so Let Go is the best reachable.
+        let app = play_route(&[
+            ("a1_oriented", 0),
+            ("a1_prep_choice", 0),
+            ("a2_first_pulse", 0),
+            ("a2_overpass_approach", 0),
+            ("a2_camp", 2),
+            ("a2_storm_talking", 1),
+            ("a2_night_entity", 0),
+            ("a3_doubt", 0),
+            ("a3_fork", 1),
+            ("a4f_child_room", 1),
+            ("a4d_gates", 0),
+            ("a5_choice_failsafe_active", 0),
+        ]);
+
+        assert_eq!(app.ending_reached.as_deref(), Some("let_go"));
+        for flag in [
+            "encouraged_departure",
+            "has_facility_records",
+            "chose_failsafe",
+            "lina_opened_up",
+            "failsafe_succeeded",
+            "emitter_shutdown",
+        ] {
+            assert!(app.game_state.has_flag(flag), "missing flag {}", flag);
+        }
+    }
+
+    #[test]
+    fn test_stayed_safe_route_reaches_static() {
+        // Always the cautious option, then walk away from the emitter.
+        let app = play_route(&[
+            ("a1_oriented", 1),
+            ("a1_prep_choice", 1),
+            ("a2_first_pulse", 0),
+            ("a2_overpass_approach", 1),
+            ("a2_camp", 1),
+            ("a2_camp_2", 0),
+            ("a2_storm_talking", 1),
+            ("a2_night_entity", 0),
+            ("a3_doubt", 1),
+            ("a3_fork", 0),
+            ("a4d_closing_in", 1),
+            ("a4d_bp_high_trust", 0),
+            ("a4d_gates", 1),
+            ("a5_choice_manual", 2),
+        ]);
+
+        assert_eq!(app.ending_reached.as_deref(), Some("static"));
+        assert!(app.game_state.has_flag("radio_sacrificed"));
+        assert!(!app.game_state.has_flag("emitter_shutdown"));
+    }
+
+    #[test]
+    fn test_ending_resolve_routing() {
+        use crate::game::GameStateBuilder;
+
+        let at_resolve = || GameStateBuilder::new().at_node("a5_ending_resolve");
+        let cases = [
+            (
+                at_resolve().health(8).trust(9).flag("emitter_shutdown"),
+                "still_here",
+            ),
+            (
+                at_resolve().health(5).trust(6).flag("emitter_shutdown"),
+                "let_go",
+            ),
+            (
+                at_resolve().health(3).trust(3).flag("emitter_shutdown"),
+                "echo",
+            ),
+            (
+                at_resolve().health(2).trust(6).flag("emitter_shutdown"),
+                "static",
+            ),
+            (at_resolve().health(10).trust(2), "echo"),
+            (at_resolve().health(10).trust(9), "static"),
+        ];
+        for (builder, expected) in cases {
+            let state = builder.build();
+            let description = format!(
+                "health={} trust={} flags={:?}",
+                state.stats.health, state.stats.trust, state.flags
+            );
+            assert_eq!(resolve_ending(state), expected, "{}", description);
+        }
+    }
+
+    #[test]
+    fn test_fatal_choice_routes_to_gone_dark() {
+        use crate::game::GameStateBuilder;
+
+        let mut app = test_app();
+        app.game_state = GameStateBuilder::new()
+            .at_node("a5_the_core")
+            .health(1)
+            .trust(9)
+            .flag("emitter_shutdown")
+            .build();
+        app.screen = Screen::Game;
+        let fatal = Choice {
+            label: crate::i18n::LocalizedString::new("Go", "Vas-y"),
+            next_node: "a5_ending_resolve".to_string(),
+            on_choose: Some(crate::story::Effects {
+                health_change: Some(-3),
+                ..Default::default()
+            }),
+            display_order: None,
+            confirm: None,
+            condition: None,
+            show_locked: false,
+            locked_hint: None,
+        };
+        app.apply_choice(&fatal);
+        drive(&mut app);
+
+        assert_eq!(app.ending_reached.as_deref(), Some("gone_dark"));
+    }
+
+    #[test]
+    fn test_lethal_on_enter_routes_to_death_node_once() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "T", "version": "1", "start_node": "start" },
+                "stats": { "health": { "initial": 2, "min": 0, "max": 5 } },
+                "endings": { "gone_dark": { "title": { "en": "Gone Dark", "fr": "" }, "type": "bad" } },
+                "death_check": { "override_next_node": "fading" },
+                "nodes": {
+                    "start": { "id": "start", "messages": [{ "en": "Hi.", "fr": "" }], "next_node": "fall" },
+                    "fall": { "id": "fall", "on_enter": { "health_change": -5 },
+                        "messages": [{ "en": "I slipped.", "fr": "" }], "next_node": "start" },
+                    "fading": { "id": "fading", "on_enter": { "health_change": -1 },
+                        "messages": [{ "en": "It's getting dark.", "fr": "" }], "next_node": "gone" },
+                    "gone": { "id": "gone", "messages": [{ "en": "...", "fr": "" }], "ending": "gone_dark" }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+        app.screen = Screen::Game;
+        drive(&mut app);
+
+        assert_eq!(app.ending_reached.as_deref(), Some("gone_dark"));
+        let said = |text: &str| {
+            app.chat
+                .iter()
+                .filter(|e| matches!(e, ChatEntry::Elara(t) if t == text))
+                .count()
+        };
+        // The fall never plays; the death node plays exactly once
+        assert_eq!(said("I slipped."), 0);
+        assert_eq!(said("It's getting dark."), 1);
+    }
+
+    #[test]
+    fn test_death_sequence_spanning_nodes_plays_once() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "T", "version": "1", "start_node": "fall" },
+                "stats": { "health": { "initial": 2, "min": 0, "max": 5 } },
+                "endings": { "gone_dark": { "title": { "en": "Gone Dark", "fr": "" }, "type": "bad" } },
+                "death_check": { "override_next_node": "fading" },
+                "nodes": {
+                    "fall": { "id": "fall", "on_enter": { "health_change": -5 },
+                        "messages": [{ "en": "I slipped.", "fr": "" }], "next_node": "fall" },
+                    "fading": { "id": "fading", "messages": [{ "en": "It's getting dark.", "fr": "" }],
+                        "next_node": "cold" },
+                    "cold": { "id": "cold", "on_enter": { "health_change": -1 },
+                        "messages": [{ "en": "So cold.", "fr": "" }], "next_node": "gone" },
+                    "gone": { "id": "gone", "messages": [{ "en": "...", "fr": "" }], "ending": "gone_dark" }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+        app.screen = Screen::Game;
+        drive(&mut app);
+
+        assert_eq!(app.ending_reached.as_deref(), Some("gone_dark"));
+        assert!(app.game_state.death_triggered);
+        let said = |text: &str| {
+            app.chat
+                .iter()
+                .filter(|e| matches!(e, ChatEntry::Elara(t) if t == text))
+                .count()
+        };
+        assert_eq!(said("It's getting dark."), 1);
+        assert_eq!(said("So cold."), 1);
+    }
+
+    #[test]
+    fn test_replay_intro_restores_game_state() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.game_state.current_node = "a2_camp".to_string();
+        app.chat.push(ChatEntry::Elara("Still here.".to_string()));
+        app.choices = vec!["First".to_string(), "Second".to_string()];
+        app.choice_index = 1;
+
+        app.open_pause_menu();
+        app.replay_intro();
+        assert_eq!(app.screen, Screen::Intro);
+        assert_eq!(app.overlay, Overlay::None);
+
+        // First key skips the typewriter, second returns to the game.
+        handle_key(&mut app, KeyCode::Enter);
+        handle_key(&mut app, KeyCode::Enter);
+
+        assert_eq!(app.screen, Screen::Game);
+        assert!(app.intro_typewriter.is_none());
+        assert_eq!(app.game_state.current_node, "a2_camp");
+        assert!(app.game_state.message_log.is_empty());
+        assert_eq!(app.chat.len(), 1);
+        assert_eq!(app.choices.len(), 2);
+        assert_eq!(app.choice_index, 1);
+        assert!(!app.advance_story);
+    }
+
+    #[test]
+    fn test_language_pace_evens_out_reveal_time() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        let node = &app.story_data.nodes["a4f_settlement"];
+        let en_text = node.messages[0].get(Language::En).to_string();
+        let fr_text = node.messages[0].get(Language::Fr).to_string();
+        assert!(fr_text.chars().count() > en_text.chars().count() * 11 / 10);
+
+        let reveal = |text: &str, lang: Language| {
+            let mut tw = TypewriterState::new(text.to_string(), TextSpeed::Normal);
+            tw.pace_percent = app.game_state.settings.language_pace.get(lang);
+            tw.reveal_duration().as_secs_f64()
+        };
+        let (en, fr) = (
+            reveal(&en_text, Language::En),
+            reveal(&fr_text, Language::Fr),
+        );
+        assert!((fr / en - 1.0).abs() < 0.08, "en {en:.2}s vs fr {fr:.2}s");
+
+        // A faster pace shortens the gap between characters as the tick sees it
+        let mut tw = TypewriterState::new(fr_text, TextSpeed::Normal);
+        tw.show_typing_indicator = false;
+        tw.pace_percent = 200;
+        tw.last_tick = Instant::now() - tw.char_interval();
+        tw.tick();
+        assert_eq!(tw.revealed, 1);
+
+        // The pause menu adjusts the pace of the language being picked
+        app.open_pause_menu();
+        app.menu_language_draft = Language::Fr;
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::LanguagePace)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Right);
+        assert_eq!(app.menu_language_pace_draft.fr, 120);
+        assert_eq!(app.menu_language_pace_draft.en, 100);
+    }
+
+    #[test]
+    fn test_untranslated_lines_keep_the_english_pace() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "rift" },
+                "nodes": {
+                    "rift": {
+                        "id": "rift",
+                        "messages": [
+                            { "en": "It opens.", "fr": "", "de": "Es \u00f6ffnet sich." },
+                            { "en": "Run.", "fr": "" }
+                        ],
+                        "next_node": "rift"
+                    }
+                }
+            }"#,
+        ));
+        app.game_state.language = Language::De;
+        app.game_state.settings.language_pace.de = 120;
+        app.screen = Screen::Game;
+        app.process_current_node();
+        assert_eq!(app.typewriter.as_ref().unwrap().pace_percent, 120);
+
+        // Shown in English, so paced like English
+        app.start_next_message();
+        let tw = app.typewriter.as_ref().unwrap();
+        assert_eq!(tw.full_text, "Run.");
+        assert_eq!(tw.pace_percent, 100);
+    }
+
+    #[test]
+    fn test_wrap_words() {
+        assert_eq!(wrap_words("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap_words("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_words("", 10), vec![""]);
+        assert_eq!(wrap_words("déjà vu", 4), vec!["déjà", "vu"]);
+    }
+
+    #[test]
+    fn test_gutter_lines_align_continuations() {
+        let style = Style::default();
+        let lines = gutter_lines("E", "Déjà vu, encore une fois", style, 10, Some("|"));
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![" E    Déjà vu,", "      encore une", "      fois|"]
+        );
+
+        // Pre-wrapped rows each take exactly one row of the chat area
+        let text = Text::from(lines);
+        assert_eq!(wrapped_line_count(&text, GUTTER_WIDTH + 11), 3);
+    }
+
+    #[test]
+    fn test_caret_only_while_typing() {
+        let mut tw = TypewriterState::new("Hello".to_string(), TextSpeed::Normal);
+        tw.show_typing_indicator = false;
+        tw.indicator_start = Instant::now();
+        assert!(caret_visible(&tw, false));
+        assert!(caret_visible(&tw, true));
+
+        // Blinks off on the next animation frame, unless motion is reduced
+        tw.indicator_start = Instant::now() - Duration::from_millis(ANIM_FRAME_MS);
+        assert!(!caret_visible(&tw, false));
+        assert!(caret_visible(&tw, true));
+
+        tw.skip();
+        assert!(!caret_visible(&tw, false));
+        assert!(!caret_visible(&tw, true));
+    }
+
+    #[test]
+    fn test_slot_select_lists_runs_and_starts_a_free_slot() {
+        let mut app = test_app();
+        let start = app.story_data.meta.start_node.clone();
+        let modified = chrono::Utc::now();
+        app.show_slot_select(vec![
+            SaveSlotInfo {
+                slot: 0,
+                current_node: start.clone(),
+                day: 2,
+                language: Language::En,
+                modified,
+            },
+            SaveSlotInfo {
+                slot: 2,
+                current_node: "gone".to_string(),
+                day: 5,
+                language: Language::Fr,
+                modified,
+            },
+        ]);
+        assert_eq!(app.screen, Screen::SlotSelect);
+        assert!(app.prompt_options[0]
+            .starts_with(&format!("Slot 0 \u{2014} Act 1 ({}), Day 2, EN, ", start)));
+        assert!(app.prompt_options[1].starts_with("Slot 2 \u{2014} (gone), Day 5, FR, "));
+        assert_eq!(app.prompt_options[2], "New game in slot 1");
+        assert!(render_rows(&app, 80, 24)
+            .iter()
+            .any(|row| row.contains("Which save do you want to play?")));
+
+        // Wraps up to the fresh slot, which starts a new game there
+        handle_key(&mut app, KeyCode::Up);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.save_slot, 1);
+        assert!(app.quicksave_path.ends_with("quicksave_1.json"));
+        assert_eq!(app.screen, Screen::LanguageSelect);
+
+        // A loaded run asks to continue, in the --lang override, with its backlog
+        app.launch_language = Some(Language::Fr);
+        let mut state = crate::game::GameStateBuilder::new().at_node(&start).build();
+        state.message_log.push(LogEntry {
+            sender: Sender::Elara,
+            text: "Still there?".to_string(),
+            timestamp: modified,
+        });
+        app.resume_state(Some(state));
+        assert_eq!(app.screen, Screen::ContinueOrNew);
+        assert_eq!(app.lang(), Language::Fr);
+        assert_eq!(app.prompt_options.len(), 2);
+        assert!(app
+            .chat
+            .iter()
+            .any(|entry| matches!(entry, ChatEntry::Elara(text) if text == "Still there?")));
+    }
+
+    #[test]
+    fn test_language_select_offers_german() {
+        let mut app = test_app();
+        app.resume_state(None);
+        assert_eq!(app.screen, Screen::LanguageSelect);
+        assert_eq!(app.prompt_options.len(), 3);
+        assert!(render_rows(&app, 80, 24)
+            .iter()
+            .any(|row| row.contains("3. Deutsch")));
+
+        handle_key(&mut app, KeyCode::Down);
+        handle_key(&mut app, KeyCode::Down);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.lang(), Language::De);
+
+        // The pause menu cycles through all three and back
+        app.screen = Screen::Game;
+        app.open_pause_menu();
+        app.menu_index = 1;
+        handle_key(&mut app, KeyCode::Right);
+        assert_eq!(app.menu_language_draft, Language::En);
+        handle_key(&mut app, KeyCode::Left);
+        handle_key(&mut app, KeyCode::Left);
+        assert_eq!(app.menu_language_draft, Language::Fr);
+    }
+
+    #[test]
+    fn test_dangling_next_node_routes_to_error_screen() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "nodes": {
+                    "camp": { "id": "camp", "checkpoint": { "en": "Camp", "fr": "Camp" },
+                              "messages": [], "next_node": "nowhere" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::StoryError);
+        assert!(!app.should_quit);
+        assert_eq!(
+            app.prompt_options,
+            vec!["Return to the last checkpoint", "Start a new game"]
+        );
+        let rows = render_rows(&app, 80, 16);
+        assert!(rows
+            .iter()
+            .any(|r| r.contains("The story can't go on from here. Missing node: 'nowhere'")));
+
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.screen, Screen::Game);
+        assert_eq!(app.game_state.current_node, "camp");
+
+        // Without a checkpoint the only way out is a new game
+        app.game_state.checkpoints.clear();
+        app.move_to_node("nowhere".to_string());
+        app.process_current_node();
+        assert_eq!(app.screen, Screen::StoryError);
+        assert_eq!(app.prompt_options, vec!["Start a new game"]);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.screen, Screen::LanguageSelect);
+        assert_eq!(app.game_state.current_node, "camp");
+    }
+
+    #[test]
+    fn test_saved_runs_from_the_pause_menu() {
+        let tmp = std::env::temp_dir().join("eshara_test_saved_runs_menu");
+        let _ = std::fs::remove_dir_all(&tmp);
+
+        let mut app = test_app();
+        app.saved_runs_path = tmp.join("runs.json");
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+
+        let open = |app: &mut App| {
+            app.open_pause_menu();
+            app.menu_index = pause_menu_items(app)
+                .iter()
+                .position(|item| *item == MenuItem::SavedRuns)
+                .unwrap();
+            handle_key(app, KeyCode::Enter);
+            assert_eq!(app.overlay, Overlay::SavedRuns);
+        };
+        open(&mut app);
+        handle_key(&mut app, KeyCode::Enter);
+        for c in "before the rift".chars() {
+            handle_key(&mut app, KeyCode::Char(c));
+        }
+        handle_key(&mut app, KeyCode::Backspace);
+        handle_key(&mut app, KeyCode::Char('t'));
+        let rows = render_rows(&app, 80, 24);
+        assert!(rows.iter().any(|r| r.contains("before the rift_")));
+        handle_key(&mut app, KeyCode::Enter);
+        assert!(app.saved_run_label.is_none());
+        assert_eq!(app.saved_runs_index, 1);
+        assert!(app.saved_runs_path.exists());
+        handle_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.overlay, Overlay::PauseMenu);
+        app.resume_from_overlay();
+
+        // Move on, then come back to the saved run
+        app.choice_index = 0;
+        app.select_choice();
+        drive(&mut app);
+        assert_ne!(app.game_state.current_node, "a1_oriented");
+        open(&mut app);
+        let rows = render_rows(&app, 80, 24);
+        assert!(rows.iter().any(|r| r.contains("before the rift  (Day 1,")));
+        handle_key(&mut app, KeyCode::Down);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t.ends_with("before the rift")
+        ));
+
+        // Delete it; the list on disk is empty again
+        open(&mut app);
+        handle_key(&mut app, KeyCode::Down);
+        handle_key(&mut app, KeyCode::Char('d'));
+        assert!(app.saved_runs.runs.is_empty());
+        assert_eq!(app.saved_runs_index, 0);
+        assert!(SavedRuns::load_from(&app.saved_runs_path)
+            .unwrap()
+            .runs
+            .is_empty());
+        assert_eq!(
+            app.saved_runs_notice.as_deref(),
+            Some("Deleted: before the rift")
+        );
+
+        // A list that can't be written says so in the player's language
+        app.game_state.language = Language::Fr;
+        app.saved_runs_path = tmp.clone();
+        handle_key(&mut app, KeyCode::Enter);
+        handle_key(&mut app, KeyCode::Char('x'));
+        handle_key(&mut app, KeyCode::Enter);
+        assert!(app
+            .saved_runs_notice
+            .as_deref()
+            .unwrap()
+            .starts_with("Impossible de mettre \u{00e0} jour les parties enregistr\u{00e9}es :"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_unreadable_saved_runs_are_never_overwritten() {
+        let tmp = std::env::temp_dir().join("eshara_test_saved_runs_unreadable");
+        let _ = std::fs::remove_dir_all(&tmp);
+        std::fs::create_dir_all(&tmp).unwrap();
+        let mut app = test_app();
+        app.saved_runs_path = tmp.join("runs.json");
+        std::fs::write(&app.saved_runs_path, b"{\"runs\": [").unwrap();
+
+        app.overlay = Overlay::PauseMenu;
+        app.open_saved_runs();
+        let rows = render_rows(&app, 100, 24);
+        assert!(rows
+            .iter()
+            .any(|r| r.contains("Couldn't read the saved runs")));
+
+        // Saving is refused, so the player's file is left alone
+        handle_key(&mut app, KeyCode::Enter);
+        assert!(app.saved_run_label.is_none());
+        app.saved_run_label = Some("exploring".to_string());
+        app.save_named_run();
+        assert!(app.saved_runs.runs.is_empty());
+        assert_eq!(
+            std::fs::read(&app.saved_runs_path).unwrap(),
+            b"{\"runs\": [".to_vec()
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_save_interval_defers_writes_until_tick_quit_ending_or_wait() {
+        let tmp = std::env::temp_dir().join("eshara_test_save_interval");
+        let _ = std::fs::remove_dir_all(&tmp);
+
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "rest" },
+                "endings": { "home": { "title": { "en": "Home", "fr": "" } } },
+                "nodes": {
+                    "rest": { "id": "rest", "messages": [], "next_node": "end",
+                              "delay": { "seconds": 600, "message": { "en": "Resting.", "fr": "" } } },
+                    "end": { "id": "end", "messages": [], "ending": "home" }
+                }
+            }"#,
+        ));
+        app.persist = true;
+        app.save_path = tmp.join("save.json");
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.game_state.settings.save_interval_ms = 50;
+        // Whether the save file was written since the last look
+        let written = |app: &App| std::fs::remove_file(&app.save_path).is_ok();
+
+        // The first save goes out, the next ones wait for the interval
+        app.save();
+        assert!(written(&app));
+        app.game_state.day = 2;
+        app.save();
+        app.save();
+        tick(&mut app);
+        assert!(!written(&app));
+        assert!(app.save_scheduler.is_pending());
+
+        // tick writes it once the interval has passed
+        std::thread::sleep(Duration::from_millis(60));
+        tick(&mut app);
+        assert!(!app.save_scheduler.is_pending());
+        assert_eq!(crate::game::load_game_from(&app.save_path).unwrap().day, 2);
+        assert!(written(&app));
+
+        // A wait node writes at once
+        app.save();
+        assert!(!written(&app));
+        crate::time::set_waiting_times_enabled(true);
+        app.process_current_node();
+        assert!(app.game_state.waiting_until.is_some());
+        assert!(written(&app));
+
+        // So does an ending
+        app.game_state.waiting_until = None;
+        app.save();
+        assert!(!written(&app));
+        app.process_current_node();
+        assert_eq!(app.screen, Screen::Ending);
+        assert!(written(&app));
+
+        // And quitting
+        app.screen = Screen::Game;
+        app.save();
+        assert!(!written(&app));
+        app.save_and_quit();
+        assert!(written(&app));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_transcript_export_reports_where_or_why_not() {
+        let tmp = std::env::temp_dir().join("eshara_test_transcript_export");
+        let _ = std::fs::remove_dir_all(&tmp);
+        std::fs::create_dir_all(&tmp).unwrap();
+
+        let mut app = test_app();
+        app.export_transcript(&tmp);
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t.starts_with("Transcript saved to")
+        ));
+
+        app.game_state.language = Language::Fr;
+        app.export_transcript(&tmp.join("missing"));
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t.starts_with("Impossible d'enregistrer la transcription :")
+        ));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_quick_save_and_confirmed_quick_load() {
+        let tmp = std::env::temp_dir().join("eshara_test_quicksave");
+        let _ = std::fs::remove_dir_all(&tmp);
+
+        let mut app = test_app();
+        app.quicksave_path = tmp.join("quicksave.json");
+        app.screen = Screen::Game;
+
+        handle_key(&mut app, KeyCode::F(9));
+        assert!(
+            matches!(app.chat.last(), Some(ChatEntry::System(t)) if t.starts_with("No quick-save"))
+        );
+
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        handle_key(&mut app, KeyCode::F(5));
+        assert!(app.quicksave_path.exists());
+        let log_len = app.game_state.message_log.len();
+
+        app.choice_index = 0;
+        app.select_choice();
+        drive(&mut app);
+        assert_ne!(app.game_state.current_node, "a1_oriented");
+
+        // First press only warns; any other key cancels
+        handle_key(&mut app, KeyCode::F(9));
+        assert!(app.quickload_pending);
+        handle_key(&mut app, KeyCode::Char('x'));
+        assert!(!app.quickload_pending);
+        handle_key(&mut app, KeyCode::F(9));
+        handle_key(&mut app, KeyCode::F(9));
+
+        assert!(!app.quickload_pending);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert_eq!(app.game_state.message_log.len(), log_len);
+        // Chat is rebuilt from the restored log plus the confirmation line
+        assert_eq!(app.chat.len(), log_len + 1);
+        drive(&mut app);
+        assert_eq!(app.choices.len(), 2);
+
+        // A damaged quick-save says so instead of claiming there is none
+        std::fs::write(&app.quicksave_path, b"not a save").unwrap();
+        handle_key(&mut app, KeyCode::F(9));
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t.starts_with("Couldn't read the quick-save:")
+        ));
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+
+        // And a failed write is reported in the player's language
+        app.game_state.language = Language::Fr;
+        app.quicksave_path = tmp.clone();
+        handle_key(&mut app, KeyCode::F(5));
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t.starts_with("Sauvegarde rapide impossible :")
+        ));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_checkpoint_restores_from_ending_and_pause_menu() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "T", "version": "1", "start_node": "start" },
+                "endings": { "dark": { "title": { "en": "Dark", "fr": "" }, "type": "bad" } },
+                "characters": { "mara": { "en": "Mara", "fr": "Mara" } },
+                "nodes": {
+                    "start": { "id": "start", "messages": [{ "en": "Hi.", "fr": "" }], "next_node": "camp" },
+                    "camp": { "id": "camp", "messages": [{ "en": "We made camp.", "fr": "" }],
+                        "checkpoint": { "en": "{char:mara}'s camp", "fr": "Camp de {char:mara}" },
+                        "on_enter": { "flags_set": ["at_camp"] },
+                        "choices": [
+                            { "label": { "en": "Go out", "fr": "" }, "next_node": "storm",
+                              "on_choose": { "trust_change": -2, "flags_set": ["went_out"] } }
+                        ] },
+                    "storm": { "id": "storm", "messages": [{ "en": "...", "fr": "" }], "ending": "dark" }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.game_state.checkpoints.len(), 1);
+        assert!(app
+            .chat
+            .iter()
+            .any(|e| matches!(e, ChatEntry::System(t) if t == "Checkpoint: Mara's camp")));
+        let trust = app.game_state.stats.trust;
+        app.select_choice();
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+        assert_eq!(app.prompt_options.len(), 4);
+
+        // "Replay from the last checkpoint" on the ending screen
+        app.prompt_index = ending_items(&app)
+            .iter()
+            .position(|item| *item == EndingItem::Checkpoint)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.screen, Screen::Game);
+        assert_eq!(app.ending_reached, None);
+        assert_eq!(app.game_state.current_node, "camp");
+        assert_eq!(app.game_state.ending, None);
+        assert!(!app.game_state.has_flag("went_out"));
+        assert_eq!(app.game_state.stats.trust, trust);
+        assert!(app.game_state.decision_path.is_empty());
+        assert!(app
+            .chat
+            .iter()
+            .any(|e| matches!(e, ChatEntry::System(t) if t == "Back at checkpoint: Mara's camp")));
+        drive(&mut app);
+        assert!(app.game_state.has_flag("at_camp"));
+        assert_eq!(app.choices, vec!["Go out".to_string()]);
+
+        // And from the pause menu, mid-run
+        app.select_choice();
+        app.open_pause_menu();
+        let rows = render_rows(&app, 80, 40);
+        assert!(rows.iter().any(|r| r.contains("Mara's camp")));
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::ReturnToCheckpoint)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.game_state.current_node, "camp");
+        assert!(!app.game_state.has_flag("went_out"));
+    }
+
+    #[test]
+    fn test_commentary_only_on_replays_after_an_ending() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "T", "version": "1", "start_node": "start" },
+                "endings": { "end": { "title": { "en": "End", "fr": "Fin" }, "type": "good" } },
+                "nodes": {
+                    "start": { "id": "start", "messages": [{ "en": "Hello?", "fr": "" }],
+                        "commentary": { "en": "Written last.", "fr": "\u00c9crit en dernier." },
+                        "next_node": "end" },
+                    "end": { "id": "end", "messages": [{ "en": "Bye.", "fr": "" }], "ending": "end" }
+                }
+            }"#,
+        );
+        let commentary = |app: &App| {
+            app.chat
+                .iter()
+                .filter(|entry| matches!(entry, ChatEntry::Commentary(_)))
+                .count()
+        };
+        let in_menu = |app: &App| pause_menu_items(app).contains(&MenuItem::Commentary);
+
+        // First playthrough: no note, no menu entry, even with the setting on
+        let mut app = app_with_story(story.clone());
+        app.game_state.settings.commentary = true;
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(commentary(&app), 0);
+        assert!(!in_menu(&app));
+        assert!(app.ledger.has_seen("end"));
+
+        // Replay after the ending: the note is there, drawn once switched on
+        let mut app = app_with_story(story);
+        app.ledger.record("end", 1);
+        app.start_new_game_plus();
+        assert!(in_menu(&app));
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(commentary(&app), 1);
+        // Look back at the conversation rather than the ending summary
+        app.screen = Screen::Game;
+        let shown = |app: &App| {
+            render_rows(app, 60, 20)
+                .join("\n")
+                .contains("Written last.")
+        };
+        assert!(!shown(&app));
+
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::Commentary)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Right);
+        app.validate_pause_menu_settings();
+        app.overlay = Overlay::None;
+        assert!(app.game_state.settings.commentary);
+        assert!(shown(&app));
+    }
+
+    #[test]
+    fn test_text_speed_presets_from_the_pause_menu() {
+        let mut app = test_app();
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::TextSpeed)
+            .unwrap();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            handle_key(&mut app, KeyCode::Right);
+            seen.push(app.menu_text_speed_draft);
+        }
+        assert_eq!(
+            seen,
+            [
+                TextSpeed::Fast,
+                TextSpeed::Instant,
+                TextSpeed::Slow,
+                TextSpeed::Normal
+            ]
+        );
+        handle_key(&mut app, KeyCode::Left);
+        assert!(render_rows(&app, 100, 40)
+            .iter()
+            .any(|row| row.contains("[slow] | normal | fast | instant")));
+        app.validate_pause_menu_settings();
+        app.overlay = Overlay::None;
+        assert_eq!(app.game_state.settings.text_speed, TextSpeed::Slow);
+
+        let tw = TypewriterState::new("Slowly.".to_string(), TextSpeed::Slow);
+        assert_eq!(tw.char_tick_ms, TYPEWRITER_TICK_SLOW_MS);
+        let tw = TypewriterState::new("At once.".to_string(), TextSpeed::Instant);
+        assert!(tw.is_done());
+    }
+
+    #[test]
+    fn test_new_game_plus_keeps_unlocks_and_annotates_choices() {
+        let mut app = test_app();
+        for key in app.story_data.endings.keys().cloned().collect::<Vec<_>>() {
+            app.ledger.record(&key, 1);
+        }
+        app.ledger.record_choice("a1_oriented", 1);
+        assert_eq!(app.unlocked_themes().last(), Some(&Theme::Signal));
+
+        app.game_state = crate::game::GameStateBuilder::new()
+            .language(Language::Fr)
+            .at_node("ending_static")
+            .build();
+        app.game_state.settings.theme = Theme::Signal;
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.prompt_options.len(), 3);
+
+        app.prompt_index = 1;
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.screen, Screen::Intro);
+        assert!(app.game_state.new_game_plus);
+        assert_eq!(app.game_state.language, Language::Fr);
+        assert_eq!(app.game_state.settings.theme, Theme::Signal);
+        assert!(app.game_state.flags.is_empty());
+        assert_eq!(app.game_state.current_node, app.story_data.meta.start_node);
+
+        app.finish_intro();
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        let screen = render_rows(&app, 120, 40).join("\n");
+        assert_eq!(screen.matches("(déjà exploré)").count(), 1);
+    }
+
+    #[test]
+    fn test_ending_unlocks_carry_into_the_next_game() {
+        let veteran_line = |app: &App| {
+            app.chat
+                .iter()
+                .any(|e| matches!(e, ChatEntry::Elara(t) if t.contains("Have we...")))
+        };
+
+        // A first playthrough never hears the line
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert!(!veteran_line(&app));
+
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("ending_still_here")
+            .build();
+        app.advance_story = true;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+        assert!(app.ledger.unlocked_flags.contains("veteran"));
+
+        app.start_new_game_plus();
+        assert!(app.game_state.has_flag("veteran"));
+        app.finish_intro();
+        drive(&mut app);
+        assert!(veteran_line(&app));
+    }
+
+    #[test]
+    fn test_theme_menu_only_offers_unlocked_themes() {
+        let mut app = test_app();
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::Theme)
+            .unwrap();
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            handle_key(&mut app, KeyCode::Right);
+            seen.push(app.menu_theme_draft);
+        }
+        assert_eq!(
+            seen,
+            [Theme::HighContrast, Theme::Monochrome, Theme::Classic]
+        );
+
+        for key in app.story_data.endings.keys().cloned().collect::<Vec<_>>() {
+            app.ledger.record(&key, 1);
+        }
+        handle_key(&mut app, KeyCode::Left);
+        assert_eq!(app.menu_theme_draft, Theme::Signal);
+    }
+
+    #[test]
+    fn test_themes_recolour_the_chat() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.chat = vec![
+            ChatEntry::Elara("Hello?".to_string()),
+            ChatEntry::System("Day 2".to_string()),
+        ];
+        app.choices = vec!["I'm here.".to_string()];
+        app.choice_data.clear();
+        let color_of = |app: &App, needle: &str| {
+            let buffer = render_buffer(app, 60, 20);
+            let width = buffer.area.width as usize;
+            let cells = buffer.content();
+            let row = (0..cells.len() / width)
+                .find(|y| {
+                    cells[y * width..(y + 1) * width]
+                        .iter()
+                        .map(|c| c.symbol())
+                        .collect::<String>()
+                        .contains(needle)
+                })
+                .unwrap_or_else(|| panic!("{} not drawn", needle));
+            let first = needle.chars().next().unwrap().to_string();
+            cells[row * width..(row + 1) * width]
+                .iter()
+                .find(|c| c.symbol() == first)
+                .unwrap()
+                .fg
+        };
+
+        for theme in [Theme::Classic, Theme::HighContrast, Theme::Monochrome] {
+            app.game_state.settings.theme = theme;
+            let colors = palette(theme);
+            assert_eq!(color_of(&app, "Hello?"), colors.elara);
+            assert_eq!(color_of(&app, "Day 2"), colors.system);
+            assert_eq!(color_of(&app, "I'm here."), colors.choice);
+        }
+        assert_eq!(palette(Theme::Monochrome).choice, Color::Reset);
+
+        // Monochrome reaches the other screens and overlays too
+        app.game_state.settings.theme = Theme::Monochrome;
+        app.ending_reached = Some("static".to_string());
+        app.prompt_options = vec!["1. Yes".to_string(), "2. No".to_string()];
+        for screen in [Screen::Intro, Screen::Waiting, Screen::Ending] {
+            for overlay in [
+                Overlay::None,
+                Overlay::PauseMenu,
+                Overlay::Glossary,
+                Overlay::Recap,
+                Overlay::SavedRuns,
+            ] {
+                app.screen = screen.clone();
+                app.overlay = overlay.clone();
+                assert!(
+                    render_buffer(&app, 100, 40)
+                        .content()
+                        .iter()
+                        .all(|c| c.fg == Color::Reset),
+                    "{:?} / {:?} still has hard-coded colours",
+                    screen,
+                    overlay
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_recap_summarizes_where_the_story_stands() {
+        let mut app = test_app();
+        let open_recap = |app: &mut App| {
+            app.open_pause_menu();
+            app.menu_index = pause_menu_items(app)
+                .iter()
+                .position(|item| *item == MenuItem::Recap)
+                .unwrap();
+            handle_key(app, KeyCode::Enter);
+            assert_eq!(app.overlay, Overlay::Recap);
+            render_rows(app, 80, 30)
+        };
+
+        // A brand-new game has nothing to sum up yet
+        let rows = open_recap(&mut app);
+        assert!(rows.iter().any(|r| r.contains("Day 1")));
+        assert!(rows.iter().any(|r| r.contains("Nothing has happened yet.")));
+        handle_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.overlay, Overlay::PauseMenu);
+        app.overlay = Overlay::None;
+
+        app.screen = Screen::Game;
+        drive(&mut app);
+        let reply = app.choices[0].clone();
+        app.choice_index = 0;
+        app.select_choice();
+        drive(&mut app);
+        app.game_state
+            .flags
+            .insert("has_shielding".to_string(), true);
+        app.game_state.language = Language::Fr;
+        let rows = open_recap(&mut app);
+        assert!(rows.iter().any(|r| r.contains("Jour 1 \u{00b7} Acte 1")));
+        assert!(rows.iter().any(|r| r.contains("Blindage trouv\u{00e9}")));
+        assert!(rows
+            .iter()
+            .any(|r| r.contains("Ta derni\u{00e8}re r\u{00e9}ponse")));
+        assert!(rows
+            .iter()
+            .any(|r| r.contains(&format!("> {}", &reply[..20]))));
+    }
+
+    #[test]
+    fn test_glossary_unlocks_on_flag() {
+        let mut app = test_app();
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("a1_oriented")
+            .build();
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert!(app.game_state.glossary_unlocked.contains("eshara"));
+        assert!(!app.game_state.glossary_unlocked.contains("vasik"));
+        assert!(app
+            .chat
+            .iter()
+            .any(|e| matches!(e, ChatEntry::System(t) if t.ends_with("The Eshara"))));
+
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::Glossary)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.overlay, Overlay::Glossary);
+
+        let screen = render_rows(&app, 70, 40).join("\n");
+        assert!(screen.contains("The Eshara"));
+        assert!(screen.contains("???"));
+        assert!(!screen.contains("Vasik"));
+
+        handle_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.overlay, Overlay::PauseMenu);
+    }
+
+    #[test]
+    fn test_ending_list_hides_undiscovered_titles() {
+        let mut app = test_app();
+        app.ledger.record("static", 1);
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("ending_let_go")
+            .build();
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.ending_reached.as_deref(), Some("let_go"));
+        assert!(app.new_ending);
+        assert!(app.ledger.has_seen("let_go"));
+
+        let screen = render_rows(&app, 60, 40).join("\n");
+        assert!(screen.contains("Static"));
+        assert!(screen.contains("NEW"));
+        assert!(screen.contains("Endings discovered: 2/5"));
+        assert_eq!(screen.matches("???").count(), 3);
+
+        // Playing again lists them under the language prompt
+        app.start_over();
+        let rows = render_rows(&app, 60, 30);
+        assert!(rows
+            .iter()
+            .any(|row| row.contains("Endings discovered: 2/5")));
+        assert!(rows.iter().any(|row| row.contains("Static")));
+        assert_eq!(rows.iter().filter(|row| row.contains("???")).count(), 3);
+
+        // Reaching it again is no longer new
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("ending_let_go")
+            .build();
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+        assert!(!app.new_ending);
+    }
+
+    #[test]
+    fn test_ending_shows_epilogues_for_met_characters() {
+        let mut app = test_app();
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("ending_let_go")
+            .flag("lina_asked")
+            .build();
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+
+        let screen = render_rows(&app, 100, 50).join("\n");
+        assert!(screen.contains("Where are they now"));
+        assert!(screen.contains("Lina:"));
+        assert!(!screen.contains("Vasik:"));
+    }
+
+    #[test]
+    fn test_explorer_shows_roads_not_taken_read_only() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "T", "version": "1", "start_node": "start" },
+                "endings": { "end": { "title": { "en": "End", "fr": "Fin" }, "type": "good" } },
+                "nodes": {
+                    "start": { "id": "start", "messages": [{ "en": "Which way?", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "Left", "fr": "" }, "next_node": "left" },
+                            { "label": { "en": "Right", "fr": "" }, "next_node": "right" }
+                        ] },
+                    "left": { "id": "left", "messages": [{ "en": "Cold in here.", "fr": "" }], "ending": "end" },
+                    "right": { "id": "right", "messages": [{ "en": "Warm out here.", "fr": "" }], "ending": "end" }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+        app.screen = Screen::Game;
+        app.advance_story = true;
+
+        // Only reachable from an ending
+        handle_key(&mut app, KeyCode::Char('e'));
+        assert_eq!(app.overlay, Overlay::None);
+
+        drive(&mut app);
+        app.choice_index = 0;
+        app.select_choice();
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+        assert_eq!(
+            app.game_state.decision_path,
+            vec![DecisionRecord {
+                node: "start".to_string(),
+                choice: 0
+            }]
+        );
+
+        handle_key(&mut app, KeyCode::Char('e'));
+        assert_eq!(app.overlay, Overlay::Explorer);
+        handle_key(&mut app, KeyCode::Enter);
+        assert!(app.explore_open);
+
+        let screen = render_rows(&app, 80, 24).join("\n");
+        assert!(screen.contains("None of this happened"));
+        assert!(screen.contains("Warm out here."));
+        assert!(!screen.contains("Cold in here."));
+
+        handle_key(&mut app, KeyCode::Esc);
+        handle_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.screen, Screen::Ending);
+        assert_eq!(app.game_state.current_node, "left");
+    }
+
+    #[test]
+    fn test_dev_panel_toggle_and_readout() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+
+        // Without --dev the key does nothing
+        handle_key(&mut app, KeyCode::F(12));
+        assert!(!app.dev_panel);
+
+        app.dev_mode = true;
+        handle_key(&mut app, KeyCode::F(12));
+        assert!(app.dev_panel);
+
+        let mut tw = TypewriterState::new("0123456789".to_string(), TextSpeed::Normal);
+        tw.revealed = 3;
+        app.typewriter = Some(tw);
+        app.game_state.waiting_until = Some(chrono::Utc::now() + chrono::Duration::seconds(90));
+        let lines = dev_panel_lines(&app);
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("typewriter  3/10 bytes")));
+        assert!(lines.iter().any(|l| l.starts_with("pause       -")));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("waiting") && l.contains("s left")));
+
+        handle_key(&mut app, KeyCode::F(12));
+        assert!(!app.dev_panel);
+    }
+
+    #[test]
+    fn test_terminal_problem_detection() {
+        assert_eq!(
+            terminal_problem(false, Some((80, 24))),
+            Some(TerminalProblem::NotInteractive)
+        );
+        assert_eq!(
+            terminal_problem(true, None),
+            Some(TerminalProblem::NotInteractive)
+        );
+        assert_eq!(
+            terminal_problem(true, Some((10, 24))),
+            Some(TerminalProblem::TooSmall)
+        );
+        assert_eq!(terminal_problem(true, Some((80, 24))), None);
+
+        let message = TerminalProblem::NotInteractive.message(Language::Fr);
+        assert!(message.contains("terminal interactif"));
+        assert!(message.contains("--ui classic"));
+    }
+
+    #[test]
+    fn test_signal_descriptor_follows_act() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        let status = |app: &App| render_rows(app, 80, 8)[7].clone();
+        assert!(status(&app).contains("faint"));
+
+        app.current_act = Some(5);
+        app.game_state.language = Language::Fr;
+        assert!(status(&app).contains("brouillé"));
+    }
+
+    #[test]
+    fn test_act_progress_carries_forward() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "one" },
+                "nodes": {
+                    "one": { "id": "one", "act": 1, "messages": [], "next_node": "between" },
+                    "between": { "id": "between", "messages": [], "next_node": "three" },
+                    "three": { "id": "three", "act": 3, "messages": [], "ending": "done" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        assert_eq!(app.current_act, Some(1));
+
+        app.process_current_node();
+        app.process_current_node();
+        assert_eq!(app.game_state.current_node, "three");
+        assert_eq!(app.current_act, Some(1));
+
+        let status = render_rows(&app, 60, 8)[7].clone();
+        assert!(status.contains("Act 1 / 3"), "status was {:?}", status);
+        assert!(!status.contains("Signal"));
+
+        app.process_current_node();
+        assert_eq!(app.current_act, Some(3));
+    }
+
+    #[test]
+    fn test_act_card_only_on_entering_a_new_act() {
+        let story = || {
+            story_from_json(
+                r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "one",
+                          "acts": { "2": { "en": "The Crossing", "fr": "La travers\u00e9e" },
+                                    "3": { "en": "The End", "fr": "La fin" } } },
+                "nodes": {
+                    "one": { "id": "one", "act": 1, "messages": [], "next_node": "two" },
+                    "two": { "id": "two", "act": 1, "messages": [], "next_node": "three" },
+                    "three": { "id": "three", "act": 2, "messages": [], "next_node": "four" },
+                    "four": { "id": "four", "act": 3, "messages": [], "ending": "done" }
+                }
+            }"#,
+            )
+        };
+        let mut app = app_with_story(story());
+        app.screen = Screen::Game;
+        let mut cards = Vec::new();
+        for _ in 0..4 {
+            let node = app.game_state.current_node.clone();
+            app.process_current_node();
+            if app.overlay == Overlay::ActCard {
+                cards.push((node, app.act_card.unwrap().0));
+                app.close_act_card();
+            }
+        }
+        assert_eq!(cards, vec![("three".to_string(), 2)]);
+
+        let rows_for = |app: &mut App| {
+            app.move_to_node("three".to_string());
+            app.current_act = Some(1);
+            app.process_current_node();
+            render_rows(app, 60, 12)
+        };
+        let rows = rows_for(&mut app);
+        assert!(rows.iter().any(|row| row.trim() == "ACT II"));
+        assert!(rows.iter().any(|row| row.trim() == "The Crossing"));
+
+        // Any key takes it down, and so does time
+        handle_key(&mut app, KeyCode::Esc);
+        assert_eq!(app.overlay, Overlay::None);
+        rows_for(&mut app);
+        app.act_card = Some((2, Instant::now() - Duration::from_millis(ACT_CARD_MS)));
+        tick(&mut app);
+        assert_eq!(app.overlay, Overlay::None);
+
+        // Resuming inside the act, or with cards switched off, shows nothing
+        app.process_current_node();
+        assert_eq!(app.overlay, Overlay::None);
+        app.game_state.settings.act_cards = false;
+        rows_for(&mut app);
+        assert_eq!(app.overlay, Overlay::None);
+    }
+
+    #[test]
+    fn test_message_timing_overrides() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "rift" },
+                "nodes": {
+                    "rift": {
+                        "id": "rift",
+                        "messages": [
+                            { "en": "It opens.", "fr": "", "reveal_ms": 200, "pause_after_ms": 2000 },
+                            { "en": "Run.", "fr": "" }
+                        ],
+                        "next_node": "rift"
+                    }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.process_current_node();
+        let tw = app.typewriter.as_ref().unwrap();
+        assert_eq!(tw.char_tick_ms, 200);
+        assert_eq!(tw.pause_after_ms, 2000);
+
+        app.on_message_complete();
+        assert_eq!(app.post_message_pause_ms, 2000);
+        app.start_next_message();
+        let tw = app.typewriter.as_ref().unwrap();
+        assert_eq!(tw.char_tick_ms, TYPEWRITER_TICK_NORMAL_MS);
+        assert_eq!(tw.pause_after_ms, POST_MESSAGE_PAUSE_MS);
+
+        // Reduce motion lands slowed beats instantly
+        app.game_state.settings.reduce_motion = true;
+        app.game_state.node_message_index = 0;
+        app.process_current_node();
+        assert!(app.typewriter.as_ref().unwrap().is_done());
+    }
+
+    /// Pretend the pause menu was opened `secs` seconds ago with the
+    /// animation timers as they are now, then close it.
+    fn pause_for(app: &mut App, secs: u64) {
+        let paused = Duration::from_secs(secs);
+        app.open_pause_menu();
+        tick(app);
+        app.paused_since = app.paused_since.map(|since| since - paused);
+        if let Some(ref mut tw) = app.typewriter {
+            tw.last_tick -= paused;
+            tw.indicator_start -= paused;
+        }
+        if let Some(ref mut start) = app.post_message_pause {
+            *start -= paused;
+        }
+        app.resume_from_overlay();
+        tick(app);
+    }
+
+    #[test]
+    fn test_pausing_never_reveals_hidden_text() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.process_current_node();
+
+        // Typing indicator: half of it had run when the menu opened
+        let tw = app.typewriter.as_mut().unwrap();
+        tw.indicator_start = Instant::now() - Duration::from_millis(TYPING_INDICATOR_MS / 2);
+        pause_for(&mut app, 10);
+        let tw = app.typewriter.as_ref().unwrap();
+        assert!(tw.is_indicating());
+        assert_eq!(tw.revealed, 0);
+
+        // Mid-reveal: the next character is not due yet
+        let tw = app.typewriter.as_mut().unwrap();
+        tw.show_typing_indicator = false;
+        tw.revealed = 4;
+        tw.last_tick = Instant::now();
+        pause_for(&mut app, 10);
+        assert_eq!(app.typewriter.as_ref().unwrap().revealed, 4);
+
+        // Post-message pause: the next message doesn't start early
+        app.typewriter.as_mut().unwrap().skip();
+        tick(&mut app);
+        assert!(app.post_message_pause.is_some());
+        let index = app.game_state.node_message_index;
+        pause_for(&mut app, 10);
+        assert!(app.post_message_pause.is_some());
+        assert!(app.typewriter.is_none());
+        assert_eq!(app.game_state.node_message_index, index);
+
+        // A replayed intro freezes the conversation underneath too
+        app.post_message_pause = None;
+        app.start_next_message();
+        let tw = app.typewriter.as_mut().unwrap();
+        tw.show_typing_indicator = false;
+        tw.last_tick = Instant::now() - Duration::from_secs(10);
+        app.open_pause_menu();
+        app.paused_since = app
+            .paused_since
+            .map(|since| since - Duration::from_secs(10));
+        app.replay_intro();
+        tick(&mut app);
+        app.finish_intro();
+        tick(&mut app);
+        assert_eq!(app.typewriter.as_ref().unwrap().revealed, 0);
+        assert!(app.paused_since.is_none());
+    }
+
+    #[test]
+    fn test_reduce_motion_from_menu_or_launch_skips_typing() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.process_current_node();
+        let tw = app.typewriter.as_ref().unwrap();
+        assert!(!tw.is_done() && tw.show_typing_indicator);
+
+        // Switched on in the pause menu, the very next line lands whole
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::ReduceMotion)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Right);
+        app.validate_pause_menu_settings();
+        app.resume_from_overlay();
+        app.game_state.node_message_index = 0;
+        app.process_current_node();
+        let tw = app.typewriter.as_ref().unwrap();
+        assert!(tw.is_done() && !tw.show_typing_indicator);
+
+        // --no-animation turns it on for a loaded run and remembers it
+        let mut app = test_app();
+        app.launch_reduce_motion = true;
+        app.resume_state(Some(crate::game::GameStateBuilder::new().build()));
+        assert!(app.game_state.settings.reduce_motion);
+        let mut app = test_app();
+        app.launch_reduce_motion = true;
+        app.resume_state(None);
+        assert!(app.game_state.settings.reduce_motion);
+    }
+
+    #[test]
+    fn test_held_space_fast_forwards_to_the_next_choice() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "road" },
+                "nodes": {
+                    "road": { "id": "road", "messages": [
+                        { "en": "The road goes on.", "fr": "" },
+                        { "en": "And on.", "fr": "", "pause_after_ms": 5000 }
+                    ], "next_node": "bridge" },
+                    "bridge": { "id": "bridge", "messages": [{ "en": "A bridge.", "fr": "" }],
+                                "next_node": "fork" },
+                    "fork": { "id": "fork", "messages": [{ "en": "Left or right?", "fr": "" }],
+                              "choices": [
+                                  { "label": { "en": "Left", "fr": "" }, "next_node": "road" },
+                                  { "label": { "en": "Right", "fr": "" }, "next_node": "road" }
+                              ] }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.process_current_node();
+
+        handle_key(&mut app, KeyCode::Char(' '));
+        assert!(app.fast_forwarding());
+        // Still held: every repeat pushes the window on
+        app.fast_forward_until = Some(Instant::now() + Duration::from_secs(10));
+        for _ in 0..20 {
+            if !app.choices.is_empty() {
+                break;
+            }
+            tick(&mut app);
+            std::thread::sleep(Duration::from_millis(FAST_FORWARD_PAUSE_MS));
+        }
+        assert_eq!(app.choices.len(), 2);
+        let logged: Vec<&str> = app
+            .game_state
+            .message_log
+            .iter()
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(
+            logged,
+            vec![
+                "The road goes on.",
+                "And on.",
+                "A bridge.",
+                "Left or right?"
+            ]
+        );
+
+        // Choices end it, and Space can't start it again while they're up
+        tick(&mut app);
+        assert!(app.fast_forward_until.is_none());
+        handle_key(&mut app, KeyCode::Char(' '));
+        assert!(!app.fast_forwarding());
+        assert_eq!(app.choices.len(), 2);
+
+        // Switched off, Space only skips the line being typed
+        app.game_state.settings.fast_forward = false;
+        app.choice_index = 0;
+        app.select_choice();
+        app.process_current_node();
+        handle_key(&mut app, KeyCode::Char(' '));
+        assert!(app.fast_forward_until.is_none());
+        assert!(app.typewriter.as_ref().unwrap().is_done());
+    }
+
+    #[test]
+    fn test_rewind_last_choice_from_the_pause_menu() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "fork" },
+                "nodes": {
+                    "fork": { "id": "fork", "messages": [{ "en": "Left or right?", "fr": "" }],
+                              "choices": [
+                                  { "label": { "en": "Left", "fr": "" }, "next_node": "left" },
+                                  { "label": { "en": "Right", "fr": "" }, "next_node": "camp" }
+                              ] },
+                    "left": { "id": "left", "messages": [{ "en": "Cold here.", "fr": "" }],
+                              "choices": [
+                                  { "label": { "en": "Back", "fr": "" }, "next_node": "fork" }
+                              ] },
+                    "camp": { "id": "camp", "messages": [{ "en": "Resting.", "fr": "" }],
+                              "delay": { "seconds": 60, "message": { "en": "Asleep.", "fr": "" } },
+                              "next_node": "fork" }
+                }
+            }"#,
+        ));
+        let texts = |app: &App| -> Vec<String> {
+            app.chat
+                .iter()
+                .filter_map(|entry| match entry {
+                    ChatEntry::Elara(t) | ChatEntry::Player(t) | ChatEntry::System(t) => {
+                        Some(t.clone())
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+        let rewind = |app: &mut App| {
+            app.open_pause_menu();
+            app.menu_index = pause_menu_items(app)
+                .iter()
+                .position(|item| *item == MenuItem::Rewind)
+                .unwrap();
+            handle_key(app, KeyCode::Enter);
+            assert_eq!(app.overlay, Overlay::None);
+            drive(app);
+        };
+        app.screen = Screen::Game;
+        drive(&mut app);
+        app.choice_index = 0;
+        app.select_choice();
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "left");
+
+        rewind(&mut app);
+        assert_eq!(app.game_state.current_node, "fork");
+        assert!(app.game_state.decision_path.is_empty());
+        assert_eq!(app.choices, vec!["Left", "Right"]);
+        assert_eq!(
+            texts(&app),
+            vec!["Left or right?", "Rewound to your last choice."]
+        );
+
+        rewind(&mut app);
+        assert_eq!(texts(&app).last().unwrap(), "There is no choice to rewind.");
+
+        // Real time has passed once Elara goes quiet, so the choice stands
+        app.choice_index = 1;
+        app.select_choice();
+        drive(&mut app);
+        assert!(app.game_state.waiting_until.is_some());
+        rewind(&mut app);
+        assert!(app.game_state.waiting_until.is_some());
+        assert_eq!(app.game_state.decision_path.len(), 1);
+        assert_eq!(
+            texts(&app).last().unwrap(),
+            "Time has passed since that choice; it can't be rewound."
+        );
+    }
+
+    #[test]
+    fn test_typing_rhythm() {
+        let text = "Wait, listen. Something moves.".to_string();
+        let mut tw = TypewriterState::new(text.clone(), TextSpeed::Normal);
+        let base = tw.char_interval();
+        let uniform = tw.reveal_duration();
+        tw.humanize();
+
+        let after = |needle: &str| text.find(needle).unwrap() + needle.len();
+        assert_eq!(tw.interval_at(after("Wait,")), base * 220 / 100);
+        assert_eq!(tw.interval_at(after("listen.")), base * 4);
+        assert_eq!(tw.interval_at(after("Wait, ")), base);
+        let within_word: Vec<Duration> = (1..4).map(|pos| tw.interval_at(pos)).collect();
+        assert!(within_word
+            .iter()
+            .all(|&d| d < base && d >= base * 70 / 100));
+        assert!(within_word.windows(2).any(|w| w[0] != w[1]));
+
+        // Seeded by the text: the same line always plays out the same way
+        let mut again = TypewriterState::new(text.clone(), TextSpeed::Normal);
+        again.humanize();
+        assert_eq!(again.reveal_duration(), tw.reveal_duration());
+        assert_ne!(tw.reveal_duration(), uniform);
+
+        // The tick waits out the longer pause after a full stop
+        tw.show_typing_indicator = false;
+        tw.revealed = after("listen.");
+        tw.last_tick = Instant::now() - base * 2;
+        tw.tick();
+        assert_eq!(tw.revealed, after("listen."));
+
+        // On by default, off with its setting or with reduce motion
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.process_current_node();
+        assert!(app.typewriter.as_ref().unwrap().rhythm_seed.is_some());
+        for (rhythm, reduce_motion) in [(false, false), (true, true)] {
+            app.game_state.settings.typing_rhythm = rhythm;
+            app.game_state.settings.reduce_motion = reduce_motion;
+            app.game_state.node_message_index = 0;
+            app.process_current_node();
+            assert!(app.typewriter.as_ref().unwrap().rhythm_seed.is_none());
+        }
+    }
+
+    #[test]
+    fn test_stat_gauges_clamp_and_toggle() {
+        assert_eq!(gauge_cells(0, 0, 10), 0);
+        assert_eq!(gauge_cells(1, 0, 10), 1);
+        assert_eq!(gauge_cells(5, 0, 10), 3);
+        assert_eq!(gauge_cells(10, 0, 10), GAUGE_WIDTH);
+        assert_eq!(gauge_cells(-4, 0, 10), 0);
+        assert_eq!(gauge_cells(14, 0, 10), GAUGE_WIDTH);
+        assert_eq!(gauge_cells(3, 3, 3), GAUGE_WIDTH);
+
+        let mut app = test_app();
+        app.game_state = crate::game::GameStateBuilder::new()
+            .trust(10)
+            .health(1)
+            .build();
+        app.game_state.stats.supplies = 0;
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        let status = |app: &App| render_rows(app, 100, 12)[11].clone();
+        assert!(!status(&app).contains('\u{2587}'));
+
+        // Off by default; switched on from the pause menu
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::ShowStats)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Right);
+        app.validate_pause_menu_settings();
+        app.resume_from_overlay();
+        let line = status(&app);
+        assert!(line.contains("trust \u{2587}\u{2587}\u{2587}\u{2587}\u{2587} "));
+        assert!(line.contains("health \u{2587}\u{2591}\u{2591}\u{2591}\u{2591} "));
+        assert!(line.contains("supplies \u{2591}\u{2591}\u{2591}\u{2591}\u{2591} "));
+
+        // Low health is drawn in red, and a stat change shows on the next frame
+        let health_color = |app: &App| {
+            let buffer = render_buffer(app, 100, 12);
+            let row: String = (0..100).map(|x| buffer[(x, 11)].symbol()).collect();
+            let x = row.find("health").unwrap();
+            buffer[(row[..x].chars().count() as u16, 11)].fg
+        };
+        assert_eq!(health_color(&app), Color::Red);
+        app.game_state.stats.health = 8;
+        assert_eq!(health_color(&app), Color::Gray);
+    }
+
+    #[test]
+    fn test_trust_cues_follow_real_trust_changes() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "nodes": {
+                    "camp": { "id": "camp", "messages": [{ "en": "Well?", "fr": "" }],
+                              "choices": [
+                        { "label": { "en": "Thank her", "fr": "" }, "next_node": "camp",
+                          "on_choose": { "trust_change": 1 } },
+                        { "label": { "en": "Snap", "fr": "" }, "next_node": "camp",
+                          "on_choose": { "trust_change": -1 } },
+                        { "label": { "en": "Shrug", "fr": "" }, "next_node": "camp",
+                          "on_choose": { "health_change": -1 } }
+                    ] }
+                }
+            }"#,
+        ));
+        let cues = |app: &App| -> Vec<String> {
+            app.chat
+                .iter()
+                .filter_map(|entry| match entry {
+                    ChatEntry::System(t) if t.starts_with("(Elara") => Some(t.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        let choose = |app: &mut App, index: usize| {
+            drive(app);
+            app.choice_index = index;
+            app.select_choice();
+            drive(app);
+        };
+        app.screen = Screen::Game;
+        choose(&mut app, 0);
+        choose(&mut app, 1);
+        choose(&mut app, 2);
+        assert_eq!(
+            cues(&app),
+            vec![
+                "(Elara seems to trust you a little more.)",
+                "(Elara seems a little more wary of you.)"
+            ]
+        );
+
+        // Nothing when trust is already at its cap, or with cues switched off
+        app.game_state.stats.trust = 10;
+        choose(&mut app, 0);
+        app.game_state.settings.trust_cues = false;
+        choose(&mut app, 1);
+        assert_eq!(cues(&app).len(), 2);
+    }
+
+    #[test]
+    fn test_consequence_hints() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "flags": { "helped_stranger": { "description": "Shared food",
+                           "label": { "en": "Helped the stranger", "fr": "Aidé l'inconnu" } } },
+                "nodes": {
+                    "camp": {
+                        "id": "camp",
+                        "messages": [{ "en": "Someone's here.", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "Share", "fr": "Partage" }, "next_node": "camp",
+                              "on_choose": { "trust_change": 1, "supplies_change": -1,
+                                             "flags_set": ["helped_stranger"] } },
+                            { "label": { "en": "Hide", "fr": "Cache-toi" }, "next_node": "camp",
+                              "on_choose": { "trust_change": 2, "scale_with_trust": true } },
+                            { "label": { "en": "Wait", "fr": "Attends" }, "next_node": "camp" }
+                        ]
+                    }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert_eq!(app.choice_data.len(), 3);
+
+        let render = |app: &App| render_rows(app, 80, 20).join("\n");
+
+        // Off by default
+        assert!(!render(&app).contains("trust"));
+
+        app.game_state.settings.consequence_hints = true;
+        let screen = render(&app);
+        assert!(screen.contains("[trust +1 · supplies -1 · sets: Helped the stranger]"));
+        assert!(screen.contains("[trust ~+2]"));
+
+        app.game_state.language = Language::Fr;
+        let screen = render(&app);
+        assert!(screen.contains("[confiance +1 · provisions -1 · active : Aidé l'inconnu]"));
+    }
+
+    #[test]
+    fn test_confirmed_choice_can_be_cancelled() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "rift" },
+                "nodes": {
+                    "rift": {
+                        "id": "rift",
+                        "messages": [{ "en": "It's open.", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "Look inside", "fr": "" }, "next_node": "inside",
+                              "on_choose": { "health_change": -3, "flags_set": ["looked"] },
+                              "confirm": { "en": "There's no unseeing it. Look?", "fr": "" } },
+                            { "label": { "en": "Back away", "fr": "" }, "next_node": "rift" }
+                        ]
+                    },
+                    "inside": { "id": "inside", "messages": [{ "en": "Oh.", "fr": "" }], "next_node": "rift" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        let health = app.game_state.stats.health;
+        let logged = app.game_state.message_log.len();
+
+        app.choice_index = 0;
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.overlay, Overlay::ConfirmChoice);
+        assert_eq!(
+            app.confirm_prompt().as_deref(),
+            Some("There's no unseeing it. Look?")
+        );
+
+        // Enter defaults to going back: nothing applied, nothing logged
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.overlay, Overlay::None);
+        assert_eq!(app.game_state.current_node, "rift");
+        assert_eq!(app.game_state.stats.health, health);
+        assert!(!app.game_state.has_flag("looked"));
+        assert_eq!(app.game_state.message_log.len(), logged);
+        assert_eq!(app.choices.len(), 2);
+
+        handle_key(&mut app, KeyCode::Enter);
+        handle_key(&mut app, KeyCode::Left);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.game_state.current_node, "inside");
+        assert_eq!(app.game_state.stats.health, health - 3);
+        assert!(app.game_state.has_flag("looked"));
+    }
+
+    #[test]
+    fn test_play_clock_counts_only_active_chat() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+
+        app.overlay = Overlay::PauseMenu;
+        app.play_clock = Instant::now() - Duration::from_millis(400);
+        tick(&mut app);
+        assert_eq!(app.game_state.active_play_ms, 0);
+
+        app.overlay = Overlay::None;
+        app.play_clock = Instant::now() - Duration::from_millis(400);
+        tick(&mut app);
+        assert!(app.game_state.active_play_ms >= 400);
+
+        // A long gap (suspended terminal) counts as at most a second
+        app.game_state.active_play_ms = 0;
+        app.play_clock = Instant::now() - Duration::from_secs(3600);
+        tick(&mut app);
+        assert!(app.game_state.active_play_ms <= 1100);
+
+        app.game_state.active_play_ms = 250_000;
+        app.game_state.waited_secs = 3725;
+        app.screen = Screen::Ending;
+        let screen = render_rows(&app, 60, 30).join("\n");
+        assert!(screen.contains("Active play time: 4m 10s"));
+        assert!(screen.contains("Real-time waits endured: 1h 02m 05s"));
+    }
+
+    #[test]
+    fn test_connected_time_is_flushed_on_save_without_waits() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        crate::time::set_waiting_times_enabled(true);
+
+        app.session_clock = Instant::now() - Duration::from_millis(90_400);
+        app.save();
+        assert_eq!(app.game_state.total_playtime_secs, 90);
+
+        // An hour inside a wait doesn't count, before or after it ends
+        app.game_state.waiting_until = Some(chrono::Utc::now() + chrono::Duration::hours(1));
+        app.session_clock = Instant::now() - Duration::from_secs(3600);
+        app.save();
+        app.game_state.waiting_until = Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+        app.session_clock = Instant::now() - Duration::from_secs(3600);
+        tick(&mut app);
+        app.save();
+        assert_eq!(app.game_state.total_playtime_secs, 90);
+
+        // Time connected before a wait node is kept when the wait starts
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "rest" },
+                "nodes": {
+                    "rest": { "id": "rest", "messages": [], "next_node": "wake",
+                              "delay": { "seconds": 600, "message": { "en": "Resting.", "fr": "" } } },
+                    "wake": { "id": "wake", "messages": [{ "en": "Morning.", "fr": "" }], "next_node": "rest" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.session_clock = Instant::now() - Duration::from_millis(45_200);
+        app.process_current_node();
+        assert!(app.game_state.waiting_until.is_some());
+        assert_eq!(app.game_state.total_playtime_secs, 45);
+
+        app.game_state.total_playtime_secs = 5040;
+        app.screen = Screen::Ending;
+        assert!(render_rows(&app, 60, 30)
+            .iter()
+            .any(|r| r.contains("Time connected: 1h 24m 00s")));
+    }
+
+    #[test]
+    fn test_dead_end_routes_to_fallback_node() {
+        let json = r#"{
+            "meta": { "title": "Test", "version": "1", "start_node": "gate" FALLBACK },
+            "nodes": {
+                "gate": {
+                    "id": "gate",
+                    "messages": [{ "en": "Let me check the door.", "fr": "" }],
+                    "branch": [{ "condition": { "flags_required": ["has_key"] }, "next_node": "inside" }]
+                },
+                "inside": { "id": "inside", "messages": [{ "en": "In.", "fr": "" }], "next_node": "gate" },
+                "static": { "id": "static", "messages": [{ "en": "...signal's back.", "fr": "" }], "next_node": "gate" }
+            }
+        }"#;
+
+        let mut app = app_with_story(story_from_json(
+            &json.replace("FALLBACK", r#", "fallback_node": "static""#),
+        ));
+        app.screen = Screen::Game;
+        app.process_current_node();
+        app.flush_messages();
+        assert_eq!(app.game_state.current_node, "static");
+        assert!(app.advance_story);
+        assert!(!app.should_quit);
+
+        // Without a fallback the player is told instead of being dropped
+        let mut app = app_with_story(story_from_json(&json.replace("FALLBACK", "")));
+        app.screen = Screen::Game;
+        app.process_current_node();
+        app.flush_messages();
+        assert_eq!(app.game_state.current_node, "gate");
+        assert!(!app.advance_story);
+        assert!(!app.should_quit);
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(t)) if t == sys_msg(Msg::StoryDeadEnd, Language::En)
+        ));
+    }
+
+    #[test]
+    fn test_supplies_depleted_interrupt_fires_once_and_returns() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp",
+                          "supplies_depleted_node": "hungry" },
+                "nodes": {
+                    "camp": { "id": "camp", "messages": [{ "en": "Cook it all?", "fr": "" }],
+                        "choices": [{ "label": { "en": "Feast", "fr": "" }, "next_node": "road",
+                                      "on_choose": { "supplies_change": -5 } }] },
+                    "road": { "id": "road", "messages": [{ "en": "Walking.", "fr": "" }],
+                        "choices": [{ "label": { "en": "Go on", "fr": "" }, "next_node": "ridge" }] },
+                    "ridge": { "id": "ridge", "messages": [{ "en": "A ridge.", "fr": "" }],
+                        "choices": [{ "label": { "en": "Climb", "fr": "" }, "next_node": "end" }] },
+                    "hungry": { "id": "hungry", "messages": [{ "en": "Nothing left to eat.", "fr": "" }],
+                        "choices": [{ "label": { "en": "Chew bark", "fr": "" }, "next_node": "@return",
+                                      "on_choose": { "health_change": -1 } }] },
+                    "end": { "id": "end", "messages": [{ "en": "Made it.", "fr": "" }], "ending": "x" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+
+        app.select_choice();
+        assert_eq!(app.game_state.stats.supplies, 0);
+        assert_eq!(app.game_state.current_node, "hungry");
+        assert!(app.game_state.supplies_interrupt_fired);
+        assert_eq!(app.game_state.interrupt_return.as_deref(), Some("road"));
+
+        drive(&mut app);
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "road");
+        assert_eq!(app.game_state.interrupt_return, None);
+
+        // Still starving, but the interrupt only happens once
+        drive(&mut app);
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "ridge");
+        drive(&mut app);
+        app.select_choice();
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+    }
+
+    #[test]
+    fn test_display_order_keeps_choice_effects() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "fork" },
+                "nodes": {
+                    "fork": {
+                        "id": "fork",
+                        "messages": [{ "en": "Which way?", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "North", "fr": "" }, "next_node": "north",
+                              "on_choose": { "flags_set": ["went_north"] } },
+                            { "label": { "en": "South", "fr": "" }, "next_node": "south",
+                              "on_choose": { "trust_change": 2 }, "display_order": 1 },
+                            { "label": { "en": "Stay", "fr": "" }, "next_node": "fork",
+                              "display_order": 2 }
+                        ]
+                    },
+                    "north": { "id": "north", "messages": [{ "en": "Cold.", "fr": "" }], "next_node": "fork" },
+                    "south": { "id": "south", "messages": [{ "en": "Warm.", "fr": "" }], "next_node": "fork" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert_eq!(app.choices, vec!["South", "Stay", "North"]);
+
+        let trust = app.game_state.stats.trust;
+        app.choice_index = 0;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "south");
+        assert_eq!(app.game_state.stats.trust, trust + 2);
+        assert!(!app.game_state.has_flag("went_north"));
+
+        drive(&mut app);
+        app.choice_index = 2;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "north");
+        assert!(app.game_state.has_flag("went_north"));
+        assert!(app.ledger.has_taken("fork", 0));
+        assert!(app.ledger.has_taken("fork", 1));
+        assert!(!app.ledger.has_taken("fork", 2));
+    }
+
+    #[test]
+    fn test_number_keys_pick_choices_directly() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        drive(&mut app);
+        let node = app.game_state.current_node.clone();
+        let second = app.choices[1].clone();
+
+        // Out of range, or too soon after the choices appeared: nothing happens
+        handle_key(&mut app, KeyCode::Char('9'));
+        app.game_state.settings.min_read_ms = 60_000;
+        app.choices_shown_at = Some(Instant::now());
+        handle_key(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.game_state.current_node, node);
+        handle_key(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.choice_index, 1);
+
+        app.game_state.settings.min_read_ms = 0;
+        handle_key(&mut app, KeyCode::Char('2'));
+        assert_ne!(app.game_state.current_node, node);
+        assert!(matches!(app.chat.last(), Some(ChatEntry::Player(t)) if *t == second));
+    }
+
+    #[test]
+    fn test_bookmarks_stay_on_logged_lines_after_a_reread() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "nodes": {
+                    "camp": {
+                        "id": "camp",
+                        "messages": [{ "en": "The fire is out.", "fr": "" }, { "en": "It's cold.", "fr": "" }],
+                        "allow_reread": true,
+                        "choices": [{ "label": { "en": "Light it", "fr": "" }, "next_node": "camp" }]
+                    }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+
+        // Re-read the node: its lines show again but aren't logged again
+        app.choice_index = 1;
+        app.select_choice();
+        drive(&mut app);
+        assert_eq!(app.chat.len(), 5);
+        assert_eq!(app.game_state.message_log.len(), 2);
+
+        handle_key(&mut app, KeyCode::Char('b'));
+        let marked: Vec<usize> = app
+            .bookmarked_chat_entries()
+            .iter()
+            .enumerate()
+            .filter(|(_, &marked)| marked)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(marked, vec![1]);
+
+        let rows = render_rows(&app, 60, 24);
+        let cold: Vec<&String> = rows.iter().filter(|r| r.contains("It's cold.")).collect();
+        assert_eq!(cold.len(), 2);
+        assert!(cold[0].contains(BOOKMARK_MARKER));
+        assert!(!cold[1].contains(BOOKMARK_MARKER));
+    }
+
+    #[test]
+    fn test_locked_choices_are_hidden_or_shown_with_a_hint() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "fork" },
+                "nodes": {
+                    "fork": {
+                        "id": "fork",
+                        "messages": [{ "en": "She's burning up.", "fr": "" }],
+                        "choices": [
+                            { "label": { "en": "Give her medicine", "fr": "" }, "next_node": "healed",
+                              "condition": { "flags_required": ["has_medicine"] },
+                              "show_locked": true,
+                              "locked_hint": { "en": "needs medicine", "fr": "" } },
+                            { "label": { "en": "Ask about the vault", "fr": "" }, "next_node": "healed",
+                              "condition": { "min_trust": 8 } },
+                            { "label": { "en": "Wait", "fr": "" }, "next_node": "waited" }
+                        ]
+                    },
+                    "healed": { "id": "healed", "messages": [{ "en": "Better.", "fr": "" }], "next_node": "fork" },
+                    "waited": { "id": "waited", "messages": [{ "en": "Worse.", "fr": "" }], "next_node": "fork" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        app.advance_story = true;
+        drive(&mut app);
+        assert_eq!(
+            app.choices,
+            vec!["Give her medicine \u{2014} [needs medicine]", "Wait"]
+        );
+
+        // The cursor starts on, and keeps to, the open entries
+        assert_eq!(app.choice_index, 1);
+        handle_key(&mut app, KeyCode::Up);
+        assert_eq!(app.choice_index, 1);
+        handle_key(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.choice_index, 1);
+        assert_eq!(app.locked_choice_hint(0).as_deref(), Some("needs medicine"));
+        assert_eq!(app.locked_choice_hint(1), None);
+
+        // Enter on the locked entry does nothing
+        app.choice_index = 0;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "fork");
+        assert_eq!(app.choices.len(), 2);
+
+        app.choice_index = 1;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "waited");
+
+        app.game_state.set_flag("has_medicine");
+        app.game_state.stats.trust = 9;
+        drive(&mut app);
+        assert_eq!(
+            app.choices,
+            vec!["Give her medicine", "Ask about the vault", "Wait"]
+        );
+        app.choice_index = 0;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "healed");
+    }
+
+    #[test]
+    fn test_resume_mid_message() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "nodes": {
+                    "camp": {
+                        "id": "camp",
+                        "on_enter": { "trust_change": 1 },
+                        "messages": [
+                            { "en": "Fire's going.", "fr": "" },
+                            { "en": "Finally some warmth.", "fr": "" }
+                        ],
+                        "choices": [{ "label": { "en": "Rest", "fr": "" }, "next_node": "camp" }]
+                    }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story.clone());
+        app.screen = Screen::Game;
+        app.process_current_node();
+        let trust = app.game_state.stats.trust;
+        let tw = app.typewriter.as_mut().unwrap();
+        tw.show_typing_indicator = false;
+        tw.revealed = 4;
+
+        // Save & Quit while the first message is still revealing
+        app.save();
+        let saved: GameState =
+            serde_json::from_str(&serde_json::to_string(&app.game_state).unwrap()).unwrap();
+        assert_eq!(saved.typing.as_ref().unwrap().revealed, 4);
+
+        let mut resumed = App::new(saved, story);
+        resumed.persist = false;
+        resumed.screen = Screen::Game;
+        resumed.load_backlog();
+        resumed.process_current_node();
+        let tw = resumed.typewriter.as_ref().unwrap();
+        assert!(!tw.is_indicating());
+        assert_eq!(tw.visible_text(), "Fire");
+        assert_eq!(resumed.game_state.stats.trust, trust);
+
+        resumed.flush_messages();
+        let log: Vec<&str> = resumed
+            .game_state
+            .message_log
+            .iter()
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(log, vec!["Fire's going.", "Finally some warmth."]);
+        assert!(resumed.game_state.typing.is_none());
+    }
+
+    #[test]
+    fn test_garbled_message_static() {
+        let text = "The oscillator blew. Had to rebuild it from scratch.";
+        let mut app = app_with_story(story_from_json(&format!(
+            r#"{{
+                "meta": {{ "title": "Test", "version": "1", "start_node": "hiss" }},
+                "nodes": {{
+                    "hiss": {{
+                        "id": "hiss",
+                        "messages": [{{ "en": "{text}", "fr": "", "garbled": true }}],
+                        "next_node": "hiss"
+                    }}
+                }}
+            }}"#
+        )));
+        app.screen = Screen::Game;
+        app.process_current_node();
+        let tw = app.typewriter.as_mut().unwrap();
+        tw.show_typing_indicator = false;
+        tw.revealed = 30;
+
+        // Same seed and frame, same static; spaces and length survive
+        let shown = tw.display_text().into_owned();
+        assert_eq!(
+            shown,
+            static_overlay(&text[..30], tw.garble_seed.unwrap(), 30)
+        );
+        assert_ne!(shown, tw.visible_text());
+        assert_eq!(shown.chars().count(), 30);
+        for (a, b) in shown.chars().zip(text.chars()) {
+            assert_eq!(a == ' ', b == ' ');
+        }
+
+        // Resolves to the clean line, which is what gets logged
+        tw.skip();
+        assert_eq!(tw.display_text(), text);
+        app.on_message_complete();
+        assert_eq!(app.game_state.message_log.last().unwrap().text, text);
+
+        // Reduce motion shows the clean text right away
+        app.game_state.settings.reduce_motion = true;
+        app.game_state.node_message_index = 0;
+        app.process_current_node();
+        assert!(app.typewriter.as_ref().unwrap().garble_seed.is_none());
+    }
+
+    #[test]
+    fn test_idle_nudge_fires_once_per_choice() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.choices = vec!["Wait".to_string()];
+        app.game_state.settings.idle_nudge_seconds = 60;
+        let long_ago = Instant::now() - Duration::from_secs(120);
+        app.last_input = long_ago;
+        app.nudge_pending_since = Some(long_ago);
+
+        // Not while an overlay is open or motion is reduced
+        app.overlay = Overlay::PauseMenu;
+        tick(&mut app);
+        app.overlay = Overlay::None;
+        app.game_state.settings.reduce_motion = true;
+        tick(&mut app);
+        assert!(app.chat.is_empty());
+
+        app.game_state.settings.reduce_motion = false;
+        tick(&mut app);
+        tick(&mut app);
+        assert_eq!(app.chat.len(), 1);
+        assert!(matches!(app.chat[0], ChatEntry::Elara(_)));
+        assert_eq!(app.choices.len(), 1);
+
+        // Recent input holds the nudge back
+        app.nudge_pending_since = Some(long_ago);
+        app.last_input = Instant::now();
+        tick(&mut app);
+        assert_eq!(app.chat.len(), 1);
+    }
+
+    #[test]
+    fn test_session_jumps() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        for session in ["day one", "day two"] {
+            app.chat.push(ChatEntry::Separator(session.to_string()));
+            for i in 0..10 {
+                app.chat
+                    .push(ChatEntry::Elara(format!("{} line {}", session, i)));
+            }
+        }
+        render_rows(&app, 40, 12);
+
+        let top_label = |app: &App| {
+            let metrics = app.chat_metrics.borrow();
+            metrics
+                .session_at(metrics.top_row(app.chat_scroll))
+                .map(str::to_string)
+        };
+
+        handle_key(&mut app, KeyCode::Char('['));
+        assert_eq!(top_label(&app).as_deref(), Some("day two"));
+        render_rows(&app, 40, 12);
+        handle_key(&mut app, KeyCode::Char('['));
+        assert_eq!(top_label(&app).as_deref(), Some("day one"));
+        let at_first = app.chat_scroll;
+        handle_key(&mut app, KeyCode::Char('['));
+        assert_eq!(app.chat_scroll, at_first);
+
+        handle_key(&mut app, KeyCode::Char(']'));
+        assert_eq!(top_label(&app).as_deref(), Some("day two"));
+        handle_key(&mut app, KeyCode::Char(']'));
+        assert_eq!(app.chat_scroll, 0);
+
+        // At the bottom the hint names the sessions in the player's language
+        app.game_state.language = Language::De;
+        assert!(render_rows(&app, 120, 12)
+            .iter()
+            .any(|r| r.contains("[ ] Sitzungen")));
+    }
+
+    #[test]
+    fn test_bookmark_latest_message_and_cycle() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        handle_key(&mut app, KeyCode::Char('n'));
+        assert!(matches!(
+            app.chat.last(),
+            Some(ChatEntry::System(text)) if text == sys_msg(Msg::BookmarksNone, Language::En)
+        ));
+        app.chat.clear();
+
+        for i in 0..20 {
+            let text = format!("line {}", i);
+            app.chat.push(ChatEntry::Elara(text.clone()));
+            app.game_state.message_log.push(LogEntry {
+                sender: Sender::Elara,
+                text,
+                timestamp: chrono::Utc::now(),
+            });
+            if i == 2 || i == 10 {
+                handle_key(&mut app, KeyCode::Char('b'));
+            }
+        }
+        assert_eq!(
+            app.game_state.bookmarks.iter().copied().collect::<Vec<_>>(),
+            vec![2, 10]
+        );
+        let marked: Vec<usize> = app
+            .bookmarked_chat_entries()
+            .iter()
+            .enumerate()
+            .filter(|(_, &marked)| marked)
+            .map(|(i, _)| i)
+            .collect();
+        // The first bookmark's confirmation line sits between the two
+        assert_eq!(marked, vec![2, 11]);
+
+        render_rows(&app, 40, 12);
+        let top_row = |app: &App| {
+            let metrics = app.chat_metrics.borrow();
+            metrics.top_row(app.chat_scroll)
+        };
+        let bookmarks = app.chat_metrics.borrow().bookmarks.clone();
+        assert_eq!(bookmarks.len(), 2);
+
+        handle_key(&mut app, KeyCode::Char('n'));
+        assert_eq!(top_row(&app), bookmarks[1]);
+        let rows = render_rows(&app, 40, 12);
+        assert!(rows
+            .iter()
+            .any(|row| row.contains("\u{2605} Elara: line 10")));
+
+        handle_key(&mut app, KeyCode::Char('n'));
+        assert_eq!(top_row(&app), bookmarks[0]);
+        render_rows(&app, 40, 12);
+        // Wraps around to the latest bookmark
+        handle_key(&mut app, KeyCode::Char('n'));
+        assert_eq!(top_row(&app), bookmarks[1]);
+
+        // Pressing b again on the same latest message removes its bookmark
+        app.chat_scroll = 0;
+        handle_key(&mut app, KeyCode::Char('b'));
+        handle_key(&mut app, KeyCode::Char('b'));
+        assert_eq!(app.game_state.bookmarks.len(), 2);
+    }
+
+    #[test]
+    fn test_more_indicator_follows_scroll_position() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        let status = |app: &App| render_rows(app, 60, 12)[11].clone();
+
+        app.chat.push(ChatEntry::Elara("Short.".to_string()));
+        let line = status(&app);
+        assert!(!line.contains('\u{25b2}') && !line.contains('\u{25bc}'));
+
+        for i in 0..30 {
+            app.chat.push(ChatEntry::Elara(format!("line {}", i)));
+        }
+        assert!(status(&app).contains("\u{25b2} more"));
+
+        app.chat_scroll = 3;
+        assert!(status(&app).contains("\u{25b2}\u{25bc} more"));
+
+        app.chat_scroll = u16::MAX;
+        clamp_chat_scroll(&mut app);
+        assert!(status(&app).contains("\u{25bc} more"));
+    }
+
+    #[test]
+    fn test_scrolled_up_view_holds_while_messages_arrive() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        for i in 0..30 {
+            app.chat.push(ChatEntry::Elara(format!("line {}", i)));
+        }
+        render_rows(&app, 60, 12);
+        handle_key(&mut app, KeyCode::PageUp);
+        assert!(app.chat_scroll > 0);
+        let before = render_rows(&app, 60, 12);
+
+        // New lines arrive: same rows on screen, and a cue in the status bar
+        app.chat.push(ChatEntry::Elara("new one".to_string()));
+        app.chat.push(ChatEntry::Elara("new two".to_string()));
+        let rows = render_rows(&app, 60, 12);
+        assert_eq!(rows[..8], before[..8]);
+        assert!(rows[11].contains("\u{2193} new messages"));
+
+        // The next tick keeps the view where it is, cue and all
+        let scroll = app.chat_scroll;
+        tick(&mut app);
+        assert!(app.chat_scroll > scroll);
+        assert!(app.unseen_below);
+        let rows = render_rows(&app, 60, 12);
+        assert_eq!(rows[..8], before[..8]);
+        assert!(rows[11].contains("\u{2193} new messages"));
+
+        // Jumping to the latest clears it
+        handle_key(&mut app, KeyCode::End);
+        tick(&mut app);
+        assert!(!app.unseen_below);
+        let rows = render_rows(&app, 60, 12);
+        assert!(rows.iter().any(|row| row.contains("new two")));
+        assert!(!rows[11].contains("new messages"));
+    }
+
+    #[test]
+    fn test_back_to_back_session_separators_collapse() {
+        let mut app = test_app();
+        let log = |sender, text: &str| LogEntry {
+            sender,
+            text: text.to_string(),
+            timestamp: chrono::Utc::now(),
+        };
+        app.game_state.message_log = vec![
+            log(Sender::System, "SESSION:Mon 09:00"),
+            log(Sender::Elara, "Hello?"),
+            log(Sender::System, "SESSION:Mon 12:00"),
+            log(Sender::System, "SESSION:Mon 18:00"),
+            log(Sender::System, "SESSION:Tue 08:00"),
+            log(Sender::Player, "I'm here."),
+        ];
+        let separators = |app: &App| {
+            app.chat
+                .iter()
+                .filter_map(|entry| match entry {
+                    ChatEntry::Separator(label) => Some(label.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        app.load_backlog();
+        assert_eq!(separators(&app).len(), 4);
+
+        app.chat.clear();
+        app.game_state.settings.session_markers = SessionMarkers::Collapsed;
+        app.load_backlog();
+        assert_eq!(
+            separators(&app),
+            vec!["Mon 09:00".to_string(), "Tue 08:00 (resumed)".to_string()]
+        );
+        assert_eq!(app.chat.len(), 4);
+
+        app.chat.clear();
+        app.game_state.settings.session_markers = SessionMarkers::Hidden;
+        app.load_backlog();
+        assert!(separators(&app).is_empty());
+        assert_eq!(app.chat.len(), 2);
+        // The log itself keeps every separator for the transcript
+        assert_eq!(app.game_state.message_log.len(), 6);
+
+        // Changing the setting from the pause menu lays the chat out again
+        app.open_pause_menu();
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::SessionMarkers)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Right);
+        app.menu_index = pause_menu_items(&app)
+            .iter()
+            .position(|item| *item == MenuItem::Validate)
+            .unwrap();
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.game_state.settings.session_markers, SessionMarkers::All);
+        assert_eq!(separators(&app).len(), 4);
+        assert_eq!(app.chat.len(), 6);
+    }
+
+    #[test]
+    fn test_ctrl_c_key_is_an_interrupt_not_a_letter() {
+        assert!(is_ctrl_c(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(is_ctrl_c(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert!(!is_ctrl_c(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_ctrl_c(&KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn test_idle_timeout_opens_pause_menu() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.last_input = Instant::now() - Duration::from_secs(6 * 60);
+
+        // Disabled by default
+        tick(&mut app);
+        assert_eq!(app.overlay, Overlay::None);
+
+        app.game_state.settings.idle_timeout_minutes = 10;
+        tick(&mut app);
+        assert_eq!(app.overlay, Overlay::None);
+
+        app.game_state.settings.idle_timeout_minutes = 5;
+        tick(&mut app);
+        assert_eq!(app.overlay, Overlay::PauseMenu);
+        assert_eq!(app.menu_idle_timeout_draft, 5);
+    }
+
+    #[test]
+    fn test_resize_keeps_bottom_visible_and_clamps_scroll() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        for i in 0..40 {
+            app.chat.push(ChatEntry::Elara(format!(
+                "line {} of a conversation that wraps on narrow terminals",
+                i
+            )));
+        }
+        app.chat
+            .push(ChatEntry::Elara("the very last line".to_string()));
+
+        for (width, height) in [(80, 20), (24, 8), (120, 40)] {
+            let screen = render_rows(&app, width, height).join("\n");
+            clamp_chat_scroll(&mut app);
+            assert!(
+                screen.contains("very last"),
+                "bottom hidden at {}x{}",
+                width,
+                height
+            );
+        }
+
+        // A scroll offset from a narrow layout is clamped once the chat fits better
+        render_rows(&app, 24, 8);
+        app.chat_scroll = u16::MAX;
+        clamp_chat_scroll(&mut app);
+        let narrow_max = app.chat_metrics.borrow().max_scroll();
+        assert_eq!(app.chat_scroll as usize, narrow_max);
+
+        render_rows(&app, 120, 40);
+        clamp_chat_scroll(&mut app);
+        assert!((app.chat_scroll as usize) < narrow_max);
+        assert!(app.chat_scroll as usize <= app.chat_metrics.borrow().max_scroll());
+    }
+
+    #[test]
+    fn test_enter_ignored_during_min_read_time() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.game_state.settings.min_read_ms = 2000;
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+
+        // Navigation still works, but Enter does not commit yet
+        handle_key(&mut app, KeyCode::Down);
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert_eq!(app.choice_index, 1);
+
+        app.choices_shown_at = Some(Instant::now() - Duration::from_millis(2000));
+        handle_key(&mut app, KeyCode::Enter);
+        assert_eq!(app.game_state.current_node, "a1_stay_safe");
+    }
+
+    #[test]
+    fn test_select_choice_recovers_from_stale_choices() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("a1_oriented")
+            .build();
+        // Displayed list no longer matches the node (e.g. state changed underneath)
+        app.choices = vec!["Stale".to_string()];
+        app.choice_index = 0;
+        let chat_len = app.chat.len();
+
+        app.select_choice();
+
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert_eq!(app.chat.len(), chat_len);
+        assert_eq!(app.choices.len(), 2);
+        assert_eq!(app.choice_index, 0);
+
+        // Out-of-range selection is re-presented rather than panicking
+        app.choice_index = 5;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert_eq!(app.choices.len(), 2);
+
+        // Same length but different labels is stale too
+        let fresh = app.choices.clone();
+        app.choices = vec!["Stale".to_string(), "Stale too".to_string()];
+        app.choice_index = 1;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "a1_oriented");
+        assert_eq!(app.choices, fresh);
+
+        app.choice_index = 1;
+        app.select_choice();
+        assert_eq!(app.game_state.current_node, "a1_stay_safe");
+    }
+
+    #[test]
+    fn test_reread_replays_messages_without_advancing() {
+        let mut app = app_with_story(story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "think" },
+                "nodes": {
+                    "think": {
+                        "id": "think",
+                        "messages": [{ "en": "Let me think.", "fr": "" }],
+                        "allow_reread": true,
+                        "choices": [
+                            { "label": { "en": "Go", "fr": "" }, "next_node": "end",
+                              "on_choose": { "trust_change": 2 } }
+                        ]
+                    },
+                    "end": { "id": "end", "messages": [], "ending": "done" }
+                }
+            }"#,
+        ));
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.choices, vec!["Go", "Re-read that"]);
+        let log_len = app.game_state.message_log.len();
+        let trust = app.game_state.stats.trust;
+
+        app.choice_index = 1;
+        app.select_choice();
+        app.flush_messages();
+
+        assert_eq!(app.game_state.current_node, "think");
+        assert_eq!(app.game_state.node_message_index, 1);
+        assert_eq!(app.game_state.message_log.len(), log_len);
+        assert_eq!(app.game_state.stats.trust, trust);
+        assert!(!app.rereading);
+        assert_eq!(app.choices, vec!["Go", "Re-read that"]);
+        let replays = app
+            .chat
+            .iter()
+            .filter(|e| matches!(e, ChatEntry::Elara(t) if t == "Let me think."))
+            .count();
+        assert_eq!(replays, 2);
+    }
+
+    #[test]
+    fn test_message_less_node_advances_without_typewriter() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "router" },
+                "nodes": {
+                    "router": { "id": "router", "messages": [], "next_node": "hello" },
+                    "hello": {
+                        "id": "hello",
+                        "messages": [{ "en": "Hello?", "fr": "Allô ?" }],
+                        "ending": "end"
+                    }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+
+        app.process_current_node();
+
+        assert!(app.typewriter.is_none());
+        assert!(app.chat.is_empty());
+        assert!(app.choices.is_empty());
+        assert_eq!(app.game_state.current_node, "hello");
+        assert!(app.advance_story);
+
+        app.process_current_node();
+        let tw = app.typewriter.as_ref().expect("message should be typing");
+        assert_eq!(tw.full_text, "Hello?");
+    }
+
+    #[test]
+    fn test_ending_description_leaves_every_option_visible() {
+        let mut app = test_app();
+        app.game_state.language = Language::Fr;
+        app.game_state.current_node = "ending_static".to_string();
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.ending_reached.as_deref(), Some("static"));
+
+        let rows = render_rows(&app, 80, 50);
+        for option in &app.prompt_options {
+            assert!(
+                rows.iter().any(|r| r.trim().ends_with(option.as_str())),
+                "missing option {:?}",
+                option
+            );
+        }
+    }
+
+    #[test]
+    fn test_draw_tiny_terminal_does_not_panic() {
+        let mut app = test_app();
+        app.chat
+            .push(ChatEntry::Elara("A long line of static.".to_string()));
+        app.choices = vec!["Answer".to_string()];
+        app.prompt_options = vec!["1. Yes".to_string(), "2. No".to_string()];
+        app.ending_reached = Some("still_here".to_string());
+        app.intro_typewriter = Some(TypewriterState::new(
+            "krrzzz".to_string(),
+            TextSpeed::Normal,
+        ));
+
+        let screens = [
+            Screen::LanguageSelect,
+            Screen::ContinueOrNew,
+            Screen::Intro,
+            Screen::Game,
+            Screen::Waiting,
+            Screen::Ending,
+        ];
+        for (width, height) in [(1, 1), (5, 2), (MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT)] {
+            for screen in &screens {
+                for layout in [ChatLayout::Inline, ChatLayout::Gutter] {
+                    app.game_state.settings.chat_layout = layout;
+                    app.screen = screen.clone();
+                    app.overlay = Overlay::None;
+                    render_rows(&app, width, height);
+                    app.overlay = Overlay::PauseMenu;
+                    render_rows(&app, width, height);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_game_screen() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.chat
+            .push(ChatEntry::Elara("Hello? Anyone?".to_string()));
+        app.chat
+            .push(ChatEntry::Player("I can hear you.".to_string()));
+        app.choices = vec!["Stay put".to_string(), "Head north".to_string()];
+        app.choice_index = 1;
+
+        let rows = render_rows(&app, 60, 20);
+        assert!(rows.iter().any(|r| r.contains("E S H A R A")));
+        assert!(rows.iter().any(|r| r.contains("Elara: Hello? Anyone?")));
+        assert!(rows.iter().any(|r| r.contains("I can hear you.")));
+        assert!(rows.iter().any(|r| r.trim_start() == "> 2. Head north"));
+        assert!(rows.iter().any(|r| r.trim_start() == "1. Stay put"));
+        assert!(rows[19].contains("[Esc]"));
+    }
+
+    #[test]
+    fn test_render_pause_menu() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        app.open_pause_menu();
+        app.menu_index = 2;
+
+        let rows = render_rows(&app, 70, 40);
+        assert!(rows.iter().any(|r| r.contains(" MENU ")));
+        assert!(rows.iter().any(|r| r.contains("  Resume")));
+        assert!(rows.iter().any(|r| r.contains("> Text speed")));
+        assert!(rows.iter().any(|r| r.contains("Save & Quit")));
+    }
+
+    #[test]
+    fn test_render_intro() {
+        let mut app = test_app();
+        app.screen = Screen::Intro;
+        let mut tw = TypewriterState::new(
+            "The static clears.\nSomeone is there.".to_string(),
+            TextSpeed::Normal,
+        );
+        tw.skip();
+        app.intro_typewriter = Some(tw);
+
+        let rows = render_rows(&app, 60, 20);
+        assert!(rows.iter().any(|r| r.contains("E S H A R A")));
+        assert!(rows.iter().any(|r| r.trim() == "The static clears."));
+        assert!(rows.iter().any(|r| r.trim() == "Someone is there."));
+        assert!(rows.iter().any(|r| r.contains("Press any key...")));
+    }
+
+    #[test]
+    fn test_wait_counts_down_and_rings_when_over() {
+        let mut app = test_app();
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        crate::time::set_waiting_times_enabled(true);
+        app.game_state.waiting_until =
+            Some(chrono::Utc::now() + chrono::Duration::milliseconds(249_500));
+        app.wait_message = Some("Elara is resting.".to_string());
+        let rows = render_rows(&app, 80, 24);
+        assert!(rows.iter().any(|r| r.trim() == "04:09"));
+        assert!(rows
+            .iter()
+            .any(|r| r.trim() == "[q] Quit and come back later"));
+
+        // q leaves with the wait still pending
+        handle_key(&mut app, KeyCode::Char('q'));
+        assert!(app.should_quit);
+        assert!(app.game_state.waiting_until.is_some());
+
+        // Once it has passed, the next tick goes on with a bell
+        app.should_quit = false;
+        app.game_state.waiting_until = Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+        tick(&mut app);
+        assert!(app.bell_pending);
+        assert!(app.game_state.waiting_until.is_none());
+        assert!(!render_rows(&app, 80, 24)
+            .iter()
+            .any(|r| r.contains("[q] Quit")));
+
+        // A wait that ran out while the game was closed is simply over
+        let mut state = crate::game::GameStateBuilder::new().build();
+        state.waiting_until = Some(chrono::Utc::now() - chrono::Duration::hours(3));
+        let mut app = test_app();
+        app.resume_state(Some(state));
+        assert_eq!(app.screen, Screen::ContinueOrNew);
+        assert!(app.game_state.waiting_until.is_none());
+    }
+
+    #[test]
+    fn test_wait_skips_are_limited_per_run() {
+        let story = story_from_json(
+            r#"{
+                "meta": { "title": "Test", "version": "1", "start_node": "camp" },
+                "nodes": {
+                    "camp": { "id": "camp", "messages": [{ "en": "Resting.", "fr": "" }],
+                              "delay": { "seconds": 600, "message": { "en": "Asleep.", "fr": "" } },
+                              "next_node": "dawn" },
+                    "dawn": { "id": "dawn", "messages": [{ "en": "Morning.", "fr": "" }],
+                              "delay": { "seconds": 600, "message": { "en": "Asleep.", "fr": "" } },
+                              "next_node": "camp" }
+                }
+            }"#,
+        );
+        let mut app = app_with_story(story);
+        app.screen = Screen::Game;
+        crate::time::set_waiting_times_enabled(true);
+        drive(&mut app);
+        assert!(app.game_state.waiting_until.is_some());
+        assert_eq!(app.game_state.waited_secs, 600);
+        assert!(render_rows(&app, 80, 24)
+            .iter()
+            .any(|r| r.trim() == "[s] Skip the wait (3/3)"));
+
+        handle_key(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.game_state.wait_skips_remaining, 2);
+        assert!(app.game_state.waited_secs < 5);
+        drive(&mut app);
+        assert_eq!(app.game_state.current_node, "camp");
+        assert!(app.game_state.waiting_until.is_some());
+
+        // Once they are used up the hint goes and `s` does nothing
+        app.game_state.wait_skips_remaining = 0;
+        assert!(!render_rows(&app, 80, 24)
+            .iter()
+            .any(|r| r.contains("[s] Skip")));
+        handle_key(&mut app, KeyCode::Char('s'));
+        assert!(app.game_state.waiting_until.is_some());
+
+        // Saves from before the counter start with the full allowance
+        let mut json = serde_json::to_value(&app.game_state).unwrap();
+        json.as_object_mut().unwrap().remove("wait_skips_remaining");
+        let state: GameState = serde_json::from_value(json).unwrap();
+        assert_eq!(state.wait_skips_remaining, crate::game::WAIT_SKIPS_PER_RUN);
+    }
+
+    #[test]
+    fn test_debug_mode_caps_waits_at_the_story_override() {
+        let story = || {
+            story_from_json(
+                r#"{
+                    "meta": { "title": "Test", "version": "1", "start_node": "camp",
+                              "debug_delay_override_seconds": 5 },
+                    "nodes": {
+                        "camp": { "id": "camp", "messages": [{ "en": "Resting.", "fr": "" }],
+                                  "delay": { "seconds": 600, "message": { "en": "Asleep.", "fr": "" } },
+                                  "next_node": "dawn" },
+                        "dawn": { "id": "dawn", "messages": [{ "en": "Morning.", "fr": "" }],
+                                  "ending": "done" }
+                    }
+                }"#,
+            )
+        };
+        let scheduled = |debug: bool| {
+            let mut app = app_with_story(story());
+            app.debug_delays = debug;
+            app.screen = Screen::Game;
+            crate::time::set_waiting_times_enabled(true);
+            drive(&mut app);
+            let until = app.game_state.waiting_until.expect("wait scheduled");
+            let secs = (until - chrono::Utc::now()).num_seconds();
+            let marked = render_rows(&app, 80, 24)
+                .iter()
+                .any(|r| r.contains("[DEBUG]"));
+            (secs, marked)
+        };
+        let (secs, marked) = scheduled(true);
+        assert!(secs <= 5, "{}", secs);
+        assert!(marked);
+        let (secs, marked) = scheduled(false);
+        assert!(secs > 500, "{}", secs);
+        assert!(!marked);
+    }
+
+    #[test]
+    fn test_render_waiting_screen() {
+        let mut app = test_app();
+        app.screen = Screen::Waiting;
+        app.wait_message = Some("Elara is resting.".to_string());
+        app.prompt_options = vec!["1. Wait".to_string(), "2. Quit".to_string()];
+        app.prompt_index = 1;
+
+        let rows = render_rows(&app, 60, 20);
+        assert!(rows.iter().any(|r| r.contains("E S H A R A")));
+        assert!(rows.iter().any(|r| r.trim() == "Elara is resting."));
+        assert!(rows.iter().any(|r| r.trim() == "1. Wait"));
+        assert!(rows.iter().any(|r| r.trim() == "> 2. Quit"));
+    }
+
+    #[test]
+    fn test_render_ending_screen() {
+        let mut app = test_app();
+        app.game_state = crate::game::GameStateBuilder::new()
+            .at_node("ending_let_go")
+            .build();
+        app.screen = Screen::Game;
+        drive(&mut app);
+        assert_eq!(app.screen, Screen::Ending);
+
+        let title = app.story_data.endings["let_go"].title.get(Language::En);
+        let rows = render_rows(&app, 100, 50);
+        assert!(rows.iter().any(|r| r.contains(title)));
+        assert!(rows
+            .iter()
+            .any(|r| r.trim_start().starts_with("> ") && r.contains(&app.prompt_options[0])));
+    }
+
+    #[test]
+    fn test_render_french_accents_wrap_cleanly() {
+        let mut app = test_app();
+        app.game_state.language = Language::Fr;
+        app.screen = Screen::Game;
+        app.advance_story = false;
+        let text = "J'ai d\u{00e9}j\u{00e0} travers\u{00e9} l'h\u{00f4}pital, la fen\u{00ea}tre \u{00e9}tait bris\u{00e9}e et ma s\u{0153}ur \u{00e9}tait l\u{00e0}.";
+        app.chat.push(ChatEntry::Elara(text.to_string()));
+
+        let rows = render_rows(&app, 30, 20);
+        let body = rows.join(" ");
+        // Wrapping only ever breaks between words, and nothing is dropped
+        for word in text.split(' ') {
+            assert!(body.contains(word), "{:?} missing from {:?}", word, rows);
+        }
+        assert!(rows.iter().all(|r| r.chars().count() <= 30));
+
+        // Menu values line up against the right edge despite multi-byte labels
+        app.open_pause_menu();
+        let rows = render_rows(&app, 70, 40);
+        let value_ends: std::collections::BTreeSet<usize> = rows
+            .iter()
+            .filter(|r| r.contains("Vitesse du texte") || r.contains("Marqueurs de session"))
+            .map(|r| r.trim_end_matches('\u{2502}').trim_end().chars().count())
+            .collect();
+        assert_eq!(value_ends.len(), 1, "{:?}", rows);
+    }
+}